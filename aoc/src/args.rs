@@ -0,0 +1,752 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::synthetic::SEED;
+use crate::YEAR;
+
+pub const USAGE: &str =
+"Usage: aoc run [<year>] <day>... [--part <1|2>] [--input <path>] [--param <name>=<value>]... [--format <text|json>] [-v|-vv] [--log <filters>] [--threads <n>]
+
+  <year>     the year the days are from, 2024 by default
+  <day>      one or more puzzle days (1-25), or `all`
+  --part     only run the given part, both parts run by default
+  --input    puzzle input to read, instead of the usual places (single day only)
+  --param    override a puzzle parameter, e.g. `--param size=6` for day 18
+  --format   print answers as text, the default, or as JSON, one record a line:
+             {day, part, answer, elapsed_ns, input_hash}
+  -v, -vv    log the days' debug, or also trace, output to stderr
+  --log      log filters as for RUST_LOG, e.g. `--log year2024_day16=debug,year2024_day18=trace`
+  --threads  threads for the days' parallel loops, one per core by default (needs the
+             `parallel` feature)
+
+       aoc bench [<year>] <day>... [--size <n>] [--seed <n>] [--samples <n>] [--output <path>] [--baseline <path> [--threshold <percent>]] [--threads <n>]
+
+  <year>       the year the days are from, as for run
+  <day>        one or more puzzle days (1-25), or `all`
+  --size       how large to make each day's synthetic input, 100 by default
+  --seed       seed for the synthetic inputs, the same one every run by default
+  --samples    timed runs of each stage, 10 by default
+  --output     save the timings as a JSON report
+  --baseline   compare against a saved report, failing if any stage got slower
+  --threshold  percentage slowdown counted as a regression, 10 by default
+  --threads    threads for the days' parallel loops, as for run
+
+       aoc verify [<year>] [<day>...] [--answers <path>] [--threads <n>]
+
+  <year>     the year the days are from, as for run
+  <day>      puzzle days to check against their inputs, all of them by default
+  --answers  TOML file of accepted answers, answers.toml by default
+  --threads  threads for the days' parallel loops, as for run
+
+       aoc render [<year>] <day> --output <path> [--part <1|2>] [--input <path>] [--param <name>=<value>]... [--scale <n>] [--delay <ms>] [--last]
+
+  <year>     the year the day is from, as for run
+  <day>      a day with a render mode: 6, 14, 15, 16, 18 or 20
+  --output   image to write, as .png, .svg or an animated .gif; still images of several
+             frames are numbered, e.g. day06-0001.png
+  --part     the part to draw, 1 by default
+  --input    puzzle input to read, instead of the usual places
+  --param    override a puzzle parameter, as for run
+  --scale    pixels per grid cell, 4 by default
+  --delay    milliseconds between animation frames, 100 by default
+  --last     only write the finished picture
+
+       aoc step [<year>] <day> [--part <1|2>] [--input <path>] [--param <name>=<value>]...
+
+  <year>     the year the day is from, as for run
+  <day>      a day that runs tick by tick: 6, 14, 15 or 17
+  --part     the part to step through, 1 by default
+  --input    puzzle input to read, instead of the usual places
+  --param    override a puzzle parameter, as for run
+
+       aoc explain [<year>] <day> [--part <1|2>] [--input <path>] [--param <name>=<value>]... [--format <table|json>]
+
+  <year>     the year the day is from, as for run
+  <day>      a day that can show its working: 1, 2, 5, 7, 13, 20 or 23
+  --part     the part to explain, 1 by default
+  --input    puzzle input to read, instead of the usual places
+  --param    override a puzzle parameter, as for run
+  --format   print the working as a table, the default, or as JSON
+
+       aoc generate [<year>] <day> [--size <n>] [--seed <n>] [--output <path>]
+
+  <year>     the year the day is from, as for run
+  <day>      the day to make up an input for
+  --size     how large to make the input, as for bench, 100 by default
+  --seed     seed for the input, the same one as bench uses by default
+  --output   file to write the input to, instead of printing it
+
+       aoc scaffold <year> <day>
+
+  <year>     the year of the new day, e.g. 2025
+  <day>      the day to make a crate for, at yearYYYY/dayNN with an empty solution and tests";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Named values for the puzzles that take more than their input, like the grid size on day 18.
+#[derive(Debug, Default, PartialEq)]
+pub struct Params {
+    values: HashMap<String, String>,
+    /// Set when running several days, where a param only applies to some of them.
+    pub ignore_unknown: bool,
+}
+
+impl Params {
+    pub fn insert(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RunFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub parts: Vec<Part>,
+    pub input: Option<PathBuf>,
+    pub params: Params,
+    pub format: RunFormat,
+    /// How many `-v`s were given: 1 logs the days' debug output, 2 their trace output too.
+    pub verbosity: u8,
+    pub log: Option<String>,
+    /// Threads for the days' parallel loops, rather than one per core.
+    pub threads: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub size: usize,
+    pub samples: usize,
+    pub output: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+    pub threads: Option<usize>,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyOptions {
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub answers: PathBuf,
+    pub threads: Option<usize>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Svg,
+    Gif,
+}
+
+impl ImageFormat {
+    fn from_path(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("png") => Ok(ImageFormat::Png),
+            Some("svg") => Ok(ImageFormat::Svg),
+            Some("gif") => Ok(ImageFormat::Gif),
+            _ => Err(format!("Unknown image format: {}, expected a .png, .svg or .gif file", path.display())),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RenderOptions {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: Option<PathBuf>,
+    pub params: Params,
+    pub output: PathBuf,
+    pub format: ImageFormat,
+    pub scale: usize,
+    pub delay_ms: u32,
+    /// Only write the last frame, the finished picture.
+    pub last: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StepOptions {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: Option<PathBuf>,
+    pub params: Params,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExplainOptions {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: Option<PathBuf>,
+    pub params: Params,
+    pub format: ExplainFormat,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GenerateOptions {
+    pub year: u16,
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ScaffoldOptions {
+    pub year: u16,
+    pub day: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Verify(VerifyOptions),
+    Render(RenderOptions),
+    Step(StepOptions),
+    Explain(ExplainOptions),
+    Generate(GenerateOptions),
+    Scaffold(ScaffoldOptions),
+    Help,
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day: {value}, expected a number from 1 to 25")),
+    }
+}
+
+/// A four digit number is a year rather than a day.
+fn is_year(value: &str) -> bool {
+    value.len() == 4 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.parse::<u16>() {
+        Ok(year) if is_year(value) && year >= 2015 => Ok(year),
+        _ => Err(format!("Invalid year: {value}, expected a year from 2015 on")),
+    }
+}
+
+/// Sets the year a command is for, which can only be given once.
+fn set_year(year: &mut Option<u16>, value: &str) -> Result<(), String> {
+    if year.is_some() {
+        return Err("Only one year can be given at a time".to_string());
+    }
+    *year = Some(parse_year(value)?);
+    Ok(())
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part: {value}, expected 1 or 2")),
+    }
+}
+
+fn next_value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("Missing value for {flag}"))
+}
+
+fn parse_param(param: &str, params: &mut Params) -> Result<(), String> {
+    let Some((name, value)) = param.split_once('=') else {
+        return Err(format!("Invalid param: {param}, expected <name>=<value>"));
+    };
+    params.insert(name, value);
+    Ok(())
+}
+
+fn parse_run_format(value: &str) -> Result<RunFormat, String> {
+    match value {
+        "text" => Ok(RunFormat::Text),
+        "json" => Ok(RunFormat::Json),
+        _ => Err(format!("Invalid format: {value}, expected text or json")),
+    }
+}
+
+fn parse_run(args: &mut impl Iterator<Item = String>) -> Result<RunOptions, String> {
+    let mut year = None;
+    let mut days = Vec::new();
+    let mut parts = vec![Part::One, Part::Two];
+    let mut input = None;
+    let mut params = Params::default();
+    let mut format = RunFormat::Text;
+    let mut verbosity = 0;
+    let mut log = None;
+    let mut threads = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => { parts = vec![parse_part(&next_value(args, &arg)?)?]; },
+            "--input" | "-i" => { input = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--param" => { parse_param(&next_value(args, &arg)?, &mut params)?; },
+            "--format" | "-f" => { format = parse_run_format(&next_value(args, &arg)?)?; },
+            "--verbose" | "-v" => { verbosity += 1; },
+            "-vv" => { verbosity += 2; },
+            "--log" => { log = Some(next_value(args, &arg)?); },
+            "--threads" | "-j" => { threads = Some(parse_threads(&next_value(args, &arg)?, &arg)?); },
+            "all" => { days.extend(1..=25); },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            day => { days.push(parse_day(day)?); }
+        }
+    }
+
+    if days.is_empty() {
+        return Err("No day given to run".to_string());
+    }
+    if input.is_some() && days.len() > 1 {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+    params.ignore_unknown = days.len() > 1;
+    Ok(RunOptions{year: year.unwrap_or(YEAR), days, parts, input, params, format, verbosity, log, threads})
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid value for {flag}: {value}, expected a number"))
+}
+
+fn parse_threads(value: &str, flag: &str) -> Result<usize, String> {
+    match parse_number(value, flag)? {
+        0 => Err(format!("{flag} must be at least 1")),
+        threads => Ok(threads),
+    }
+}
+
+fn parse_bench(args: &mut impl Iterator<Item = String>) -> Result<BenchOptions, String> {
+    let mut year = None;
+    let mut options = BenchOptions{year: YEAR, days: Vec::new(), size: 100, samples: 10, output: None, baseline: None, threshold: 10.0, threads: None, seed: SEED};
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => { options.size = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--seed" => { options.seed = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--samples" => { options.samples = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--output" | "-o" => { options.output = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--baseline" => { options.baseline = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--threshold" => { options.threshold = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--threads" | "-j" => { options.threads = Some(parse_threads(&next_value(args, &arg)?, &arg)?); },
+            "all" => { options.days.extend(1..=25); },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            day => { options.days.push(parse_day(day)?); }
+        }
+    }
+
+    options.year = year.unwrap_or(YEAR);
+    if options.days.is_empty() {
+        return Err("No day given to bench".to_string());
+    }
+    if options.samples == 0 {
+        return Err("--samples must be at least 1".to_string());
+    }
+    Ok(options)
+}
+
+fn parse_verify(args: &mut impl Iterator<Item = String>) -> Result<VerifyOptions, String> {
+    let mut year = None;
+    let mut options = VerifyOptions{year: YEAR, days: Vec::new(), answers: PathBuf::from("answers.toml"), threads: None};
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" | "-a" => { options.answers = PathBuf::from(next_value(args, &arg)?); },
+            "--threads" | "-j" => { options.threads = Some(parse_threads(&next_value(args, &arg)?, &arg)?); },
+            "all" => { options.days.extend(1..=25); },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            day => { options.days.push(parse_day(day)?); }
+        }
+    }
+
+    options.year = year.unwrap_or(YEAR);
+    if options.days.is_empty() {
+        options.days.extend(1..=25);
+    }
+    Ok(options)
+}
+
+fn parse_render(args: &mut impl Iterator<Item = String>) -> Result<RenderOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = Part::One;
+    let mut input = None;
+    let mut params = Params::default();
+    let mut output = None;
+    let mut scale = 4;
+    let mut delay_ms = 100;
+    let mut last = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => { part = parse_part(&next_value(args, &arg)?)?; },
+            "--input" | "-i" => { input = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--param" => { parse_param(&next_value(args, &arg)?, &mut params)?; },
+            "--output" | "-o" => { output = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--scale" => { scale = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--delay" => { delay_ms = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--last" => { last = true; },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            _ if day.is_some() => { return Err("Only one day can be rendered at a time".to_string()); },
+            value => { day = Some(parse_day(value)?); }
+        }
+    }
+
+    let Some(day) = day else {
+        return Err("No day given to render".to_string());
+    };
+    let Some(output) = output else {
+        return Err("No --output given to render to".to_string());
+    };
+    if scale == 0 {
+        return Err("--scale must be at least 1".to_string());
+    }
+    let format = ImageFormat::from_path(&output)?;
+    Ok(RenderOptions{year: year.unwrap_or(YEAR), day, part, input, params, output, format, scale, delay_ms, last})
+}
+
+fn parse_step(args: &mut impl Iterator<Item = String>) -> Result<StepOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = Part::One;
+    let mut input = None;
+    let mut params = Params::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => { part = parse_part(&next_value(args, &arg)?)?; },
+            "--input" | "-i" => { input = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--param" => { parse_param(&next_value(args, &arg)?, &mut params)?; },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            _ if day.is_some() => { return Err("Only one day can be stepped through at a time".to_string()); },
+            value => { day = Some(parse_day(value)?); }
+        }
+    }
+
+    let Some(day) = day else {
+        return Err("No day given to step through".to_string());
+    };
+    Ok(StepOptions{year: year.unwrap_or(YEAR), day, part, input, params})
+}
+
+fn parse_explain_format(value: &str) -> Result<ExplainFormat, String> {
+    match value {
+        "table" => Ok(ExplainFormat::Table),
+        "json" => Ok(ExplainFormat::Json),
+        _ => Err(format!("Invalid format: {value}, expected table or json")),
+    }
+}
+
+fn parse_explain(args: &mut impl Iterator<Item = String>) -> Result<ExplainOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut part = Part::One;
+    let mut input = None;
+    let mut params = Params::default();
+    let mut format = ExplainFormat::Table;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => { part = parse_part(&next_value(args, &arg)?)?; },
+            "--input" | "-i" => { input = Some(PathBuf::from(next_value(args, &arg)?)); },
+            "--param" => { parse_param(&next_value(args, &arg)?, &mut params)?; },
+            "--format" | "-f" => { format = parse_explain_format(&next_value(args, &arg)?)?; },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            _ if day.is_some() => { return Err("Only one day can be explained at a time".to_string()); },
+            value => { day = Some(parse_day(value)?); }
+        }
+    }
+
+    let Some(day) = day else {
+        return Err("No day given to explain".to_string());
+    };
+    Ok(ExplainOptions{year: year.unwrap_or(YEAR), day, part, input, params, format})
+}
+
+fn parse_generate(args: &mut impl Iterator<Item = String>) -> Result<GenerateOptions, String> {
+    let mut year = None;
+    let mut day = None;
+    let mut size = 100;
+    let mut seed = SEED;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => { size = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--seed" => { seed = parse_number(&next_value(args, &arg)?, &arg)?; },
+            "--output" | "-o" => { output = Some(PathBuf::from(next_value(args, &arg)?)); },
+            flag if flag.starts_with('-') => { return Err(format!("Unknown option: {flag}")); },
+            value if is_year(value) => { set_year(&mut year, value)?; },
+            _ if day.is_some() => { return Err("Only one day can be generated at a time".to_string()); },
+            value => { day = Some(parse_day(value)?); }
+        }
+    }
+
+    let Some(day) = day else {
+        return Err("No day given to generate".to_string());
+    };
+    Ok(GenerateOptions{year: year.unwrap_or(YEAR), day, size, seed, output})
+}
+
+fn parse_scaffold(args: &mut impl Iterator<Item = String>) -> Result<ScaffoldOptions, String> {
+    let (Some(year), Some(day), None) = (args.next(), args.next(), args.next()) else {
+        return Err("Expected a year and a day to scaffold, e.g. `scaffold 2025 1`".to_string());
+    };
+    Ok(ScaffoldOptions{year: parse_year(&year)?, day: parse_day(&day)?})
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        None | Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+        Some("run") => Ok(Command::Run(parse_run(&mut args)?)),
+        Some("bench") => Ok(Command::Bench(parse_bench(&mut args)?)),
+        Some("verify") => Ok(Command::Verify(parse_verify(&mut args)?)),
+        Some("render") => Ok(Command::Render(parse_render(&mut args)?)),
+        Some("step") => Ok(Command::Step(parse_step(&mut args)?)),
+        Some("explain") => Ok(Command::Explain(parse_explain(&mut args)?)),
+        Some("generate") => Ok(Command::Generate(parse_generate(&mut args)?)),
+        Some("scaffold") => Ok(Command::Scaffold(parse_scaffold(&mut args)?)),
+        Some(other) => Err(format!("Unknown command: {other}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Command, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    fn parse_run_options(line: &str) -> RunOptions {
+        match parse(line) {
+            Ok(Command::Run(options)) => options,
+            other => panic!("Expected run options from {line}, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn run_single_day_defaults_to_both_parts() {
+        let options = parse_run_options("run 17");
+        assert_eq!(YEAR, options.year);
+        assert_eq!(vec![17], options.days);
+        assert_eq!(vec![Part::One, Part::Two], options.parts);
+        assert_eq!(None, options.input);
+        assert_eq!((0, None, None), (options.verbosity, options.log, options.threads));
+    }
+
+    #[test]
+    fn run_prints_text_unless_asked_for_json() {
+        assert_eq!(RunFormat::Text, parse_run_options("run 17").format);
+        assert_eq!(RunFormat::Json, parse_run_options("run all --format json").format);
+        assert!(parse("run 17 --format yaml").is_err());
+        assert!(parse("run 17 --format").is_err());
+    }
+
+    #[test]
+    fn thread_count_is_at_least_one() {
+        assert_eq!(Some(32), parse_run_options("run 6 --threads 32").threads);
+        assert!(parse("run 6 --threads 0").is_err());
+        assert!(parse("run 6 --threads many").is_err());
+        let Ok(Command::Bench(options)) = parse("bench 22 -j 8") else { panic!("Expected bench options") };
+        assert_eq!(Some(8), options.threads);
+    }
+
+    #[test]
+    fn run_with_logging() {
+        assert_eq!(1, parse_run_options("run 16 -v").verbosity);
+        assert_eq!(2, parse_run_options("run 16 -v --verbose").verbosity);
+        assert_eq!(2, parse_run_options("run 16 -vv").verbosity);
+        assert_eq!(Some("day16=debug".to_string()), parse_run_options("run 16 18 --log day16=debug").log);
+        assert!(parse("run 16 --log").is_err());
+    }
+
+    #[test]
+    fn run_with_part_input_and_params() {
+        let options = parse_run_options("run 18 --part 2 --input sample.txt --param size=6 --param bytes=12");
+        assert_eq!(vec![18], options.days);
+        assert_eq!(vec![Part::Two], options.parts);
+        assert_eq!(Some(PathBuf::from("sample.txt")), options.input);
+        let mut params: Vec<(&str, &str)> = options.params.iter().collect();
+        params.sort();
+        assert_eq!(vec![("bytes", "12"), ("size", "6")], params);
+        assert!(!options.params.ignore_unknown);
+    }
+
+    #[test]
+    fn run_several_days() {
+        assert_eq!(vec![1, 5, 17], parse_run_options("run 1 5 17").days);
+        assert!(parse_run_options("run 1 5 17").params.ignore_unknown);
+        assert_eq!((1..=25).collect::<Vec<u8>>(), parse_run_options("run all").days);
+    }
+
+    #[test]
+    fn run_takes_a_year_before_the_days() {
+        let options = parse_run_options("run 2025 1 2");
+        assert_eq!((2025, vec![1, 2]), (options.year, options.days));
+        assert!(parse("run 2014 1").is_err());
+        assert!(parse("run 2024 2025 1").is_err());
+        assert!(parse("run 2024").is_err());
+    }
+
+    #[test]
+    fn every_command_but_scaffold_takes_an_optional_year() {
+        let Ok(Command::Bench(options)) = parse("bench 2025 1 2") else { panic!("Expected bench options") };
+        assert_eq!((2025, vec![1, 2]), (options.year, options.days));
+        let Ok(Command::Verify(options)) = parse("verify 2023") else { panic!("Expected verify options") };
+        assert_eq!((2023, 25), (options.year, options.days.len()));
+        let Ok(Command::Render(options)) = parse("render 2023 6 --output route.png") else { panic!("Expected render options") };
+        assert_eq!((2023, 6), (options.year, options.day));
+        let Ok(Command::Step(options)) = parse("step 2023 17") else { panic!("Expected step options") };
+        assert_eq!((2023, 17), (options.year, options.day));
+        let Ok(Command::Explain(options)) = parse("explain 2023 1") else { panic!("Expected explain options") };
+        assert_eq!((2023, 1), (options.year, options.day));
+        let Ok(Command::Generate(options)) = parse("generate 2023 5") else { panic!("Expected generate options") };
+        assert_eq!((2023, 5), (options.year, options.day));
+        let Ok(Command::Explain(options)) = parse("explain 7") else { panic!("Expected explain options") };
+        assert_eq!(YEAR, options.year);
+        for line in ["bench 2024 2025 1", "verify 2014", "render 2024 2024 6 -o route.png", "step 2023 2024 6", "explain 2024 2023 1", "generate 2014 1"] {
+            assert!(parse(line).is_err(), "{line}");
+        }
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        assert!(parse("run").is_err());
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 3 --part 3").is_err());
+        assert!(parse("run 3 --param size").is_err());
+        assert!(parse("run 3 4 --input input.txt").is_err());
+        assert!(parse("run 3 --quiet").is_err());
+        assert!(parse("fly 3").is_err());
+    }
+
+    #[test]
+    fn bench_with_defaults_and_options() {
+        let Ok(Command::Bench(options)) = parse("bench 6 16") else { panic!("Expected bench options") };
+        assert_eq!(BenchOptions{year: YEAR, days: vec![6, 16], size: 100, samples: 10, output: None, baseline: None, threshold: 10.0, threads: None, seed: SEED}, options);
+        let Ok(Command::Bench(options)) = parse("bench all --size 50 --samples 3 --output new.json --baseline old.json --threshold 5") else {
+            panic!("Expected bench options")
+        };
+        assert_eq!(25, options.days.len());
+        assert_eq!((50, 3, 5.0), (options.size, options.samples, options.threshold));
+        assert_eq!(Some(PathBuf::from("new.json")), options.output);
+        assert_eq!(Some(PathBuf::from("old.json")), options.baseline);
+        assert!(parse("bench").is_err());
+        assert!(parse("bench 3 --size big").is_err());
+        assert!(parse("bench 3 --samples 0").is_err());
+        let Ok(Command::Bench(options)) = parse("bench 3 --seed 42") else { panic!("Expected bench options") };
+        assert_eq!(42, options.seed);
+    }
+
+    #[test]
+    fn verify_defaults_to_every_day() {
+        let Ok(Command::Verify(options)) = parse("verify") else { panic!("Expected verify options") };
+        assert_eq!(VerifyOptions{year: YEAR, days: (1..=25).collect(), answers: PathBuf::from("answers.toml"), threads: None}, options);
+        let Ok(Command::Verify(options)) = parse("verify 15 16 --answers accepted.toml -j 4") else { panic!("Expected verify options") };
+        assert_eq!(VerifyOptions{year: YEAR, days: vec![15, 16], answers: PathBuf::from("accepted.toml"), threads: Some(4)}, options);
+        assert!(parse("verify 0").is_err());
+        assert!(parse("verify --answers").is_err());
+    }
+
+    #[test]
+    fn render_picks_the_format_from_the_output() {
+        let Ok(Command::Render(options)) = parse("render 16 --output maze.gif") else { panic!("Expected render options") };
+        assert_eq!((16, Part::One, ImageFormat::Gif), (options.day, options.part, options.format));
+        assert_eq!((4, 100, false), (options.scale, options.delay_ms, options.last));
+        let Ok(Command::Render(options)) = parse("render 18 -p 2 --param size=6 -o bytes.PNG --scale 10 --delay 40 --last") else {
+            panic!("Expected render options")
+        };
+        assert_eq!((Part::Two, ImageFormat::Png), (options.part, options.format));
+        assert_eq!((10, 40, true), (options.scale, options.delay_ms, options.last));
+        assert_eq!(vec![("size", "6")], options.params.iter().collect::<Vec<_>>());
+        assert!(parse("render 16").is_err());
+        assert!(parse("render --output maze.svg").is_err());
+        assert!(parse("render 16 18 --output maze.svg").is_err());
+        assert!(parse("render 16 --output maze.jpg").is_err());
+        assert!(parse("render 16 --output maze.png --scale 0").is_err());
+    }
+
+    #[test]
+    fn step_takes_one_day() {
+        let Ok(Command::Step(options)) = parse("step 17") else { panic!("Expected step options") };
+        assert_eq!(StepOptions{year: YEAR, day: 17, part: Part::One, input: None, params: Params::default()}, options);
+        let Ok(Command::Step(options)) = parse("step 15 --part 2 --input small.txt") else { panic!("Expected step options") };
+        assert_eq!((15, Part::Two, Some(PathBuf::from("small.txt"))), (options.day, options.part, options.input));
+        assert!(parse("step").is_err());
+        assert!(parse("step 6 15").is_err());
+        assert!(parse("step 6 --last").is_err());
+    }
+
+    #[test]
+    fn explain_takes_one_day_and_a_format() {
+        let Ok(Command::Explain(options)) = parse("explain 13") else { panic!("Expected explain options") };
+        assert_eq!(ExplainOptions{year: YEAR, day: 13, part: Part::One, input: None, params: Params::default(), format: ExplainFormat::Table}, options);
+        let Ok(Command::Explain(options)) = parse("explain 20 -p 2 --param saves=50 --format json") else { panic!("Expected explain options") };
+        assert_eq!((20, Part::Two, ExplainFormat::Json), (options.day, options.part, options.format));
+        assert!(parse("explain").is_err());
+        assert!(parse("explain 2 5").is_err());
+        assert!(parse("explain 2 --format csv").is_err());
+    }
+
+    #[test]
+    fn generate_takes_one_day() {
+        let Ok(Command::Generate(options)) = parse("generate 24") else { panic!("Expected generate options") };
+        assert_eq!(GenerateOptions{year: YEAR, day: 24, size: 100, seed: SEED, output: None}, options);
+        let Ok(Command::Generate(options)) = parse("generate 16 --size 141 --seed 7 -o maze.txt") else { panic!("Expected generate options") };
+        assert_eq!(GenerateOptions{year: YEAR, day: 16, size: 141, seed: 7, output: Some(PathBuf::from("maze.txt"))}, options);
+        assert!(parse("generate").is_err());
+        assert!(parse("generate 1 2").is_err());
+        assert!(parse("generate 1 --seed x").is_err());
+    }
+
+    #[test]
+    fn scaffold_takes_a_year_and_a_day() {
+        assert_eq!(Ok(Command::Scaffold(ScaffoldOptions{year: 2025, day: 3})), parse("scaffold 2025 3"));
+        assert!(parse("scaffold 2025").is_err());
+        assert!(parse("scaffold 3 2025").is_err());
+        assert!(parse("scaffold 2025 3 4").is_err());
+    }
+
+    #[test]
+    fn no_arguments_is_help() {
+        assert_eq!(Ok(Command::Help), parse(""));
+    }
+}
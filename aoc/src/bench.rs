@@ -0,0 +1,228 @@
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::args::{BenchOptions, Params};
+use crate::days;
+use crate::synthetic;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::Part1, Stage::Part2];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+}
+
+/// How long one stage took over all its samples, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<u64>) -> Self {
+        samples.sort_unstable();
+        let middle = samples.len() / 2;
+        let median_ns = if samples.len().is_multiple_of(2) { (samples[middle - 1] + samples[middle]) / 2 } else { samples[middle] };
+        Timing{
+            min_ns: samples[0],
+            median_ns,
+            mean_ns: samples.iter().sum::<u64>() / samples.len() as u64,
+            max_ns: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Times `f` over `samples` runs, after one untimed run to warm up.
+pub fn measure<R>(samples: usize, mut f: impl FnMut() -> R) -> Timing {
+    black_box(f());
+    Timing::from_samples((0..samples.max(1)).map(|_| {
+        let start = Instant::now();
+        black_box(f());
+        start.elapsed().as_nanos() as u64
+    }).collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Benchmark {
+    pub day: u8,
+    pub stage: Stage,
+    #[serde(flatten)]
+    pub timing: Timing,
+}
+
+/// Everything one `aoc bench` run measured, as saved to and loaded from JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u16,
+    pub size: usize,
+    pub seed: u64,
+    pub samples: usize,
+    pub benchmarks: Vec<Benchmark>,
+}
+
+impl Report {
+    fn find(&self, day: u8, stage: Stage) -> Option<&Benchmark> {
+        self.benchmarks.iter().find(|benchmark| benchmark.day == day && benchmark.stage == stage)
+    }
+}
+
+/// A stage's median time against the same stage in a baseline report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    /// Percentage change in median time, positive when slower than the baseline.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Checks the baseline timed the same inputs, which it only did for the same year at the same
+/// size and seed.
+fn check_input(baseline: &Report, year: u16, size: usize, seed: u64) -> Result<(), String> {
+    if baseline.year != year {
+        return Err(format!("Baseline was measured on {}, not {year}", baseline.year));
+    }
+    if baseline.size != size {
+        return Err(format!("Baseline was measured at size {}, not {size}", baseline.size));
+    }
+    if baseline.seed != seed {
+        return Err(format!("Baseline was measured with seed {}, not {seed}", baseline.seed));
+    }
+    Ok(())
+}
+
+/// Compares a stage's median time against the baseline, if the baseline measured that stage.
+fn compare_one(baseline: &Report, benchmark: &Benchmark, threshold: f64) -> Option<Comparison> {
+    let before = baseline.find(benchmark.day, benchmark.stage)?.timing.median_ns.max(1) as f64;
+    let change = (benchmark.timing.median_ns as f64 - before) / before * 100.0;
+    Some(Comparison{day: benchmark.day, stage: benchmark.stage, change, regressed: change > threshold})
+}
+
+fn load_report(path: &std::path::Path) -> Result<Report, String> {
+    let json = fs::read_to_string(path).map_err(|e| format!("Error reading file: {}, {:?}", path.display(), e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Error reading report {}: {e}", path.display()))
+}
+
+/// Times every stage of `day` of `year` on a synthetic input of the given size and seed.
+pub fn measure_day(year: u16, day: u8, size: usize, seed: u64, samples: usize) -> Result<Vec<Benchmark>, String> {
+    let synthetic = synthetic::input(year, day, size, seed)?;
+    let mut params = Params::default();
+    for (name, value) in &synthetic.params {
+        params.insert(name, value);
+    }
+    let timings = days::bench(year, day, &synthetic.input, &params, samples)?;
+    Ok(Stage::ALL.into_iter().zip(timings).map(|(stage, timing)| Benchmark{day, stage, timing}).collect())
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.3?}", Duration::from_nanos(ns))
+}
+
+fn print_benchmark(benchmark: &Benchmark, baseline: Option<&Report>, threshold: f64) -> bool {
+    let timing = &benchmark.timing;
+    let mut line = format!("day{:02} {}  median {:>10}  min {:>10}  max {:>10}", benchmark.day, benchmark.stage.name(),
+                           format_ns(timing.median_ns), format_ns(timing.min_ns), format_ns(timing.max_ns));
+    let comparison = baseline.and_then(|baseline| compare_one(baseline, benchmark, threshold));
+    if let Some(comparison) = &comparison {
+        line += &format!("  {:+.1}%", comparison.change);
+        if comparison.regressed { line += " REGRESSION"; }
+    }
+    println!("{line}");
+    comparison.is_some_and(|comparison| comparison.regressed)
+}
+
+pub fn run(options: &BenchOptions) -> Result<(), String> {
+    let baseline = options.baseline.as_deref().map(load_report).transpose()?;
+    if let Some(baseline) = &baseline {
+        check_input(baseline, options.year, options.size, options.seed)?;
+    }
+
+    let mut report = Report{year: options.year, size: options.size, seed: options.seed, samples: options.samples, benchmarks: Vec::new()};
+    let mut regressions = 0;
+    for day in &options.days {
+        for benchmark in measure_day(options.year, *day, options.size, options.seed, options.samples)? {
+            if print_benchmark(&benchmark, baseline.as_ref(), options.threshold) { regressions += 1; }
+            report.benchmarks.push(benchmark);
+        }
+    }
+
+    if let Some(path) = &options.output {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| format!("Error writing report: {e}"))?;
+        fs::write(path, json + "\n").map_err(|e| format!("Error writing file: {}, {:?}", path.display(), e))?;
+    }
+
+    if regressions > 0 {
+        return Err(format!("{regressions} benchmark(s) more than {}% slower than the baseline", options.threshold));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(medians: &[(u8, Stage, u64)]) -> Report {
+        Report{year: 2024, size: 10, seed: 1, samples: 3, benchmarks: medians.iter().map(|(day, stage, median_ns)| Benchmark{
+            day: *day,
+            stage: *stage,
+            timing: Timing{min_ns: *median_ns, median_ns: *median_ns, mean_ns: *median_ns, max_ns: *median_ns},
+        }).collect()}
+    }
+
+    #[test]
+    fn timing_summarises_samples() {
+        assert_eq!(Timing{min_ns: 1, median_ns: 3, mean_ns: 4, max_ns: 10}, Timing::from_samples(vec![10, 1, 3, 2, 4]));
+        assert_eq!(2, Timing::from_samples(vec![4, 1, 3, 1]).median_ns);
+    }
+
+    #[test]
+    fn compare_flags_stages_slower_than_the_threshold() {
+        let baseline = report(&[(1, Stage::Parse, 100), (1, Stage::Part1, 1000), (1, Stage::Part2, 1000)]);
+        let current = report(&[(1, Stage::Parse, 105), (1, Stage::Part1, 1200), (1, Stage::Part2, 500), (2, Stage::Parse, 50)]);
+        let comparisons: Vec<Comparison> = current.benchmarks.iter().filter_map(|benchmark| compare_one(&baseline, benchmark, 10.0)).collect();
+        let flagged: Vec<(u8, Stage, bool)> = comparisons.iter().map(|c| (c.day, c.stage, c.regressed)).collect();
+        assert_eq!(vec![(1, Stage::Parse, false), (1, Stage::Part1, true), (1, Stage::Part2, false)], flagged);
+        assert_eq!(-50.0, comparisons[2].change);
+    }
+
+    #[test]
+    fn compare_needs_the_same_input() {
+        assert_eq!(Ok(()), check_input(&report(&[]), 2024, 10, 1));
+        assert!(check_input(&report(&[]), 2023, 10, 1).is_err());
+        assert!(check_input(&report(&[]), 2024, 20, 1).is_err());
+        assert!(check_input(&report(&[]), 2024, 10, 2).is_err());
+    }
+
+    #[test]
+    fn reports_round_trip_through_json() {
+        let mut benchmarks = measure_day(2024, 1, 10, synthetic::SEED, 2).unwrap();
+        benchmarks.extend(measure_day(2024, 2, 10, synthetic::SEED, 2).unwrap());
+        let report = Report{year: 2024, size: 10, seed: synthetic::SEED, samples: 2, benchmarks};
+        assert_eq!(6, report.benchmarks.len());
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"stage\":\"part1\""));
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+        // a report has to say which seed it was measured with
+        let unseeded = json.replace(&format!("\"seed\":{},", synthetic::SEED), "");
+        assert!(serde_json::from_str::<Report>(&unseeded).is_err());
+    }
+}
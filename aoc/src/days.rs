@@ -0,0 +1,225 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use common::{Answer, Explain, Explanation, FromReader, ParamError, ReadError, Solution};
+use render::{Frame, Render, Simulation};
+
+use crate::args::{Params, Part};
+use crate::bench::{self, Timing};
+use crate::tui;
+
+/// Calls `$f::<DayNN>($args)` for the solution type of that day of the year, through one of the
+/// year's dispatch macros: `with_day` for any day, or `with_render_day` and the like for the days
+/// that can do more than answer.
+macro_rules! for_year {
+    ($year:expr, $dispatch:ident!($day:expr, $f:ident($($args:expr),*))) => {
+        match $year {
+            2024 => year2024::$dispatch!($day, $f($($args),*)),
+            year => Err(format!("No solutions for {year}")),
+        }
+    };
+}
+
+fn prepare<'a, S: Solution<'a>>(input: &'a str, params: &Params) -> Result<S, String> {
+    let day = S::parse(input).map_err(|e| format!("Error parsing input: {e}"))?;
+    configure(day, params)
+}
+
+fn configure<'a, S: Solution<'a>>(mut day: S, params: &Params) -> Result<S, String> {
+    for (name, value) in params.iter() {
+        match day.configure(name, value) {
+            Ok(()) => {},
+            Err(ParamError::Unknown(_)) if params.ignore_unknown => {},
+            Err(e) => { return Err(e.to_string()); }
+        }
+    }
+    Ok(day)
+}
+
+/// The answer to `part`, or an error if the day couldn't give one.
+fn answer<'a, S: Solution<'a>>(day: &S, part: Part) -> Result<Answer, String> {
+    match part {
+        Part::One => day.part1(),
+        Part::Two => day.part2(),
+    }.into_result()
+}
+
+fn solve_with<'a, S: Solution<'a>>(input: &'a str, part: Part, params: &Params) -> Result<Answer, String> {
+    let day = prepare::<S>(input, params)?;
+    answer(&day, part)
+}
+
+/// Parses the file a line at a time as it's read, for the days that can, rather than reading it all first.
+fn solve_file_with<S: for<'a> Solution<'a> + FromReader>(path: &Path, part: Part, params: &Params) -> Result<Answer, String> {
+    let read_error = |e| format!("Error reading file: {}, {:?}", path.display(), e);
+    let file = File::open(path).map_err(read_error)?;
+    let day = match S::from_reader(BufReader::new(file)) {
+        Ok(day) => day,
+        Err(ReadError::Io(e)) => { return Err(read_error(e)); },
+        Err(ReadError::Parse(e)) => { return Err(format!("Error parsing input: {e}")); },
+    };
+    let day = configure(day, params)?;
+    answer(&day, part)
+}
+
+/// Times parsing and both parts, in that order, once both parts are known to have answers.
+fn bench_with<'a, S: Solution<'a>>(input: &'a str, params: &Params, samples: usize) -> Result<[Timing; 3], String> {
+    let parse = bench::measure(samples, || S::parse(input));
+    let day = prepare::<S>(input, params)?;
+    answer(&day, Part::One)?;
+    answer(&day, Part::Two)?;
+    Ok([parse, bench::measure(samples, || day.part1()), bench::measure(samples, || day.part2())])
+}
+
+fn render_with<'a, S: Solution<'a> + Render>(input: &'a str, part: Part, params: &Params) -> Result<Vec<Frame>, String> {
+    let day = prepare::<S>(input, params)?;
+    Ok(day.render(part.number()))
+}
+
+fn explain_with<'a, S: Solution<'a> + Explain>(input: &'a str, part: Part, params: &Params) -> Result<(Answer, Explanation), String> {
+    let day = prepare::<S>(input, params)?;
+    Ok((answer(&day, part)?, day.explain(part.number())))
+}
+
+fn step_with<'a, S: Solution<'a> + Simulation>(day: u8, input: &'a str, part: Part, params: &Params) -> Result<(), String> {
+    let simulation = prepare::<S>(input, params)?;
+    tui::run(&simulation, part.number(), &format!("day{day:02} part{}", part.number()))
+}
+
+pub fn solve(year: u16, day: u8, part: Part, input: &str, params: &Params) -> Result<Answer, String> {
+    for_year!(year, with_day!(day, solve_with(input, part, params)))
+}
+
+pub fn solve_file(year: u16, day: u8, part: Part, path: &Path, params: &Params) -> Result<Answer, String> {
+    for_year!(year, with_day!(day, solve_file_with(path, part, params)))
+}
+
+pub fn bench(year: u16, day: u8, input: &str, params: &Params, samples: usize) -> Result<[Timing; 3], String> {
+    for_year!(year, with_day!(day, bench_with(input, params, samples)))
+}
+
+pub fn render(year: u16, day: u8, part: Part, input: &str, params: &Params) -> Result<Vec<Frame>, String> {
+    for_year!(year, with_render_day!(day, render_with(input, part, params)))
+}
+
+/// The answer to `part` along with the working that got it.
+pub fn explain(year: u16, day: u8, part: Part, input: &str, params: &Params) -> Result<(Answer, Explanation), String> {
+    for_year!(year, with_explain_day!(day, explain_with(input, part, params)))
+}
+
+pub fn step(year: u16, day: u8, part: Part, input: &str, params: &Params) -> Result<(), String> {
+    for_year!(year, with_step_day!(day, step_with(day, input, part, params)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YEAR;
+
+    #[test]
+    fn solves_day01_sample() {
+        const SAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+        assert_eq!(Ok(Answer::Int(11)), solve(YEAR, 1, Part::One, SAMPLE, &Params::default()));
+        assert_eq!(Ok(Answer::Int(31)), solve(YEAR, 1, Part::Two, SAMPLE, &Params::default()));
+    }
+
+    #[test]
+    fn day08_part2_considers_harmonics() {
+        const SAMPLE: &str = "T.........\n...T......\n.T........\n..........\n..........\n..........\n..........\n..........\n..........\n..........";
+        assert_eq!(Ok(Answer::Int(9)), solve(YEAR, 8, Part::Two, SAMPLE, &Params::default()));
+    }
+
+    const DAY18_SAMPLE: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+
+    #[test]
+    fn day18_uses_params_for_grid_size() {
+        let mut params = Params::default();
+        params.insert("size", "6");
+        params.insert("bytes", "12");
+        assert_eq!(Ok(Answer::Int(22)), solve(YEAR, 18, Part::One, DAY18_SAMPLE, &params));
+        assert_eq!(Ok(Answer::Point(6, 1)), solve(YEAR, 18, Part::Two, DAY18_SAMPLE, &params));
+    }
+
+    #[test]
+    fn unknown_params_are_rejected_unless_ignored() {
+        let mut params = Params::default();
+        params.insert("size", "6");
+        params.insert("bytes", "12");
+        params.insert("saves", "100");
+        assert!(solve(YEAR, 18, Part::One, DAY18_SAMPLE, &params).is_err());
+        params.ignore_unknown = true;
+        assert_eq!(Ok(Answer::Int(22)), solve(YEAR, 18, Part::One, DAY18_SAMPLE, &params));
+    }
+
+    #[test]
+    fn parse_errors_say_where_the_input_is_wrong() {
+        assert_eq!(Err("Error parsing input: line 2, column 5: expected a location ID, found \"x3\"".to_string()),
+                   solve(YEAR, 1, Part::One, "3   4\n4   x3", &Params::default()));
+    }
+
+    #[test]
+    fn every_day_solves_its_synthetic_input() {
+        for day in 1..=25 {
+            let synthetic = crate::synthetic::input(YEAR, day, 4, crate::synthetic::SEED).unwrap();
+            let mut params = Params::default();
+            for (name, value) in &synthetic.params {
+                params.insert(name, value);
+            }
+            for part in [Part::One, Part::Two] {
+                // day 11's part 2 takes about a minute in a debug build, whatever the input
+                if (day, part) == (11, Part::Two) { continue; }
+                assert!(solve(YEAR, day, part, &synthetic.input, &params).is_ok(), "day {day} part {}", part.number());
+            }
+        }
+    }
+
+    #[test]
+    fn only_2024_has_solutions() {
+        assert_eq!(Err("No solutions for 2023".to_string()), solve(2023, 1, Part::One, "3   4", &Params::default()));
+        assert_eq!(Err("No solution for day 26".to_string()), solve(YEAR, 26, Part::One, "3   4", &Params::default()));
+    }
+
+    #[test]
+    fn an_overflow_is_an_error_not_a_crash() {
+        let input = "-9223372036854775808   9223372036854775807\n".repeat(3);
+        let mut params = Params::default();
+        params.insert("metric", "squared");
+        let overflow = Some("the total distance overflowed".to_string());
+        assert_eq!(overflow, solve(YEAR, 1, Part::One, &input, &params).err());
+        assert_eq!(Ok(Answer::Int(0)), solve(YEAR, 1, Part::Two, &input, &params));
+        let path = std::env::temp_dir().join(format!("aoc-overflow-{}.txt", std::process::id()));
+        std::fs::write(&path, &input).unwrap();
+        assert_eq!(overflow, solve_file(YEAR, 1, Part::One, &path, &params).err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(overflow, bench(YEAR, 1, &input, &params, 1).err());
+        assert_eq!(overflow, explain(YEAR, 1, Part::One, &input, &params).err());
+    }
+
+    #[test]
+    fn day25_has_no_part2() {
+        assert_eq!(Ok(Answer::None), solve(YEAR, 25, Part::Two, "", &Params::default()));
+    }
+
+    #[test]
+    fn files_give_the_same_answers_as_their_contents() {
+        let path = std::env::temp_dir().join(format!("aoc-days-{}.txt", std::process::id()));
+        for day in 1..=25 {
+            let synthetic = crate::synthetic::input(YEAR, day, 4, crate::synthetic::SEED).unwrap();
+            std::fs::write(&path, &synthetic.input).unwrap();
+            let mut params = Params::default();
+            for (name, value) in &synthetic.params {
+                params.insert(name, value);
+            }
+            // day 11's part 2 is slow, see above, and a made-up network on day 23 can have several
+            // largest sets, of which hashing picks one
+            let part = if day == 11 || day == 23 { Part::One } else { Part::Two };
+            assert_eq!(solve(YEAR, day, part, &synthetic.input, &params), solve_file(YEAR, day, part, &path, &params), "day {day}");
+        }
+        std::fs::write(&path, "3   4\n4   x3").unwrap();
+        assert_eq!(Err("Error parsing input: line 2, column 5: expected a location ID, found \"x3\"".to_string()),
+                   solve_file(YEAR, 1, Part::One, &path, &Params::default()));
+        std::fs::remove_file(&path).unwrap();
+        assert!(solve_file(YEAR, 1, Part::One, &path, &Params::default()).unwrap_err().starts_with("Error reading file: "));
+    }
+}
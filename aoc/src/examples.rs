@@ -0,0 +1,114 @@
+//! Runs the puzzle examples kept in `year2024/examples/dayNN/`, so a new case is a file rather than a test.
+//!
+//! Each `<name>.txt` in a day's directory is an input, and `expected.toml` has a table for each
+//! with the answers expected from it, any params it needs, or the parse error it should give:
+//!
+//! ```toml
+//! [sample]
+//! params = { size = 6, bytes = 12 }
+//! part1 = 22
+//! part2 = "6,1"
+//!
+//! [bad-location]
+//! error = "line 2, column 5: expected a location ID, found \"x3\""
+//! ```
+//!
+//! Parts without an expected answer aren't run, since some only make sense on the real input.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::args::{Params, Part};
+use crate::days;
+use crate::verify::Accepted;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    #[serde(default)]
+    params: BTreeMap<String, Accepted>,
+    part1: Option<Accepted>,
+    part2: Option<Accepted>,
+    error: Option<String>,
+}
+
+fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("year2024").join("examples")
+}
+
+/// Runs one example, describing each way it differs from what's expected.
+fn check_example(day: u8, name: &str, input: &str, expected: &Expected) -> Vec<String> {
+    let mut params = Params::default();
+    for (param, value) in &expected.params {
+        params.insert(param, &value.to_string());
+    }
+    let mut failures = Vec::new();
+    if let Some(error) = &expected.error {
+        match days::solve(crate::YEAR, day, Part::One, input, &params) {
+            Err(e) if e == format!("Error parsing input: {error}") => {},
+            other => failures.push(format!("day{day:02}/{name}: expected error {error:?}, got {other:?}")),
+        }
+    }
+    for (part, answer) in [(Part::One, &expected.part1), (Part::Two, &expected.part2)] {
+        let Some(answer) = answer else { continue; };
+        match days::solve(crate::YEAR, day, part, input, &params) {
+            Ok(actual) if actual.to_string() == answer.to_string() => {},
+            other => failures.push(format!("day{day:02}/{name} part{}: expected {answer}, got {other:?}", part.number())),
+        }
+    }
+    if expected.error.is_none() && expected.part1.is_none() && expected.part2.is_none() {
+        failures.push(format!("day{day:02}/{name}: nothing expected, give part1, part2 or error"));
+    }
+    failures
+}
+
+/// Checks every example of `day`, and that examples and expectations come in pairs.
+fn check_day(day: u8, dir: &Path) -> Vec<String> {
+    let manifest = fs::read_to_string(dir.join("expected.toml")).unwrap_or_default();
+    let expectations: BTreeMap<String, Expected> = match toml::from_str(&manifest) {
+        Ok(expectations) => expectations,
+        Err(e) => { return vec![format!("day{day:02}/expected.toml: {e}")]; }
+    };
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt")).collect();
+    inputs.sort();
+
+    let mut failures = Vec::new();
+    for path in &inputs {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        match expectations.get(&name) {
+            Some(expected) => failures.extend(check_example(day, &name, &fs::read_to_string(path).unwrap(), expected)),
+            None => failures.push(format!("day{day:02}/{name}.txt: no [{name}] table in expected.toml")),
+        }
+    }
+    for name in expectations.keys() {
+        if !inputs.iter().any(|path| path.file_stem().is_some_and(|stem| stem.to_string_lossy() == *name)) {
+            failures.push(format!("day{day:02}/expected.toml: [{name}] has no {name}.txt"));
+        }
+    }
+    failures
+}
+
+#[test]
+fn every_example_gives_its_expected_answers() {
+    let mut failures = Vec::new();
+    for day in 1..=25 {
+        let dir = examples_dir().join(format!("day{:02}", day));
+        if dir.is_dir() {
+            failures.extend(check_day(day, &dir));
+        } else {
+            failures.push(format!("day{day:02}: no examples in {}", dir.display()));
+        }
+    }
+    assert!(failures.is_empty(), "{} example check(s) failed:\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn mismatches_are_described() {
+    let expected: Expected = toml::from_str("part1 = 12\npart2 = \"31\"").unwrap();
+    let failures = check_example(1, "sample", "3   4\n4   3\n2   5\n1   3\n3   9\n3   3", &expected);
+    assert_eq!(vec!["day01/sample part1: expected 12, got Ok(Int(11))".to_string()], failures);
+}
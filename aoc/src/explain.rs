@@ -0,0 +1,66 @@
+//! Prints the working behind an answer, from the days that can show it, so the answer can be
+//! checked row by row rather than trusted as a total.
+
+use common::{Answer, Explanation};
+use serde::Serialize;
+
+use crate::args::{ExplainFormat, ExplainOptions};
+use crate::days;
+use crate::input;
+
+/// An explanation as JSON, the rows as lists of cells in the same order as the columns.
+#[derive(Debug, Serialize)]
+struct Report<'a> {
+    day: u8,
+    part: u8,
+    answer: String,
+    columns: &'a [&'static str],
+    rows: &'a [Vec<String>],
+}
+
+fn to_json(day: u8, part: u8, answer: &Answer, explanation: &Explanation) -> Result<String, String> {
+    let report = Report{day, part, answer: answer.to_string(), columns: &explanation.columns, rows: &explanation.rows};
+    serde_json::to_string_pretty(&report).map_err(|e| format!("Error writing explanation: {e}"))
+}
+
+pub fn run(options: &ExplainOptions) -> Result<(), String> {
+    let input = input::read(options.input.as_deref(), options.year, options.day)?;
+    let (answer, explanation) = days::explain(options.year, options.day, options.part, &input, &options.params)?;
+    let part = options.part.number();
+    match options.format {
+        ExplainFormat::Table => {
+            println!("day{:02} part{}: {}\n", options.day, part, answer);
+            print!("{explanation}");
+        },
+        ExplainFormat::Json => println!("{}", to_json(options.day, part, &answer, &explanation)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::{Params, Part};
+    use crate::YEAR;
+
+    const DAY13_SAMPLE: &str = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+
+    #[test]
+    fn json_keeps_the_columns_in_order() {
+        let (answer, explanation) = days::explain(YEAR, 13, Part::One, DAY13_SAMPLE, &Params::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&to_json(13, 1, &answer, &explanation).unwrap()).unwrap();
+        assert_eq!("280", json["answer"]);
+        assert_eq!(serde_json::json!(["machine", "prize", "A presses", "B presses", "tokens"]), json["columns"]);
+        assert_eq!(serde_json::json!([["1", "8400,5400", "80", "40", "280"]]), json["rows"]);
+    }
+
+    #[test]
+    fn only_some_days_explain() {
+        assert!(days::explain(YEAR, 3, Part::One, "mul(2,4)", &Params::default()).is_err());
+        let mut params = Params::default();
+        params.insert("saves", "20");
+        let maze = "#####\n#S#E#\n#...#\n#####\n";
+        let (answer, explanation) = days::explain(YEAR, 20, Part::One, maze, &params).unwrap();
+        assert_eq!((Answer::Int(0), 0), (answer, explanation.rows.len()));
+    }
+}
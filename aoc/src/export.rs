@@ -0,0 +1,90 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use render::Frame;
+
+use crate::args::{ImageFormat, RenderOptions};
+use crate::days;
+use crate::input;
+
+/// `day06.png` becomes `day06-0001.png` for the first frame, and so on.
+fn numbered_path(path: &Path, index: usize) -> PathBuf {
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+    let extension = path.extension().map(|extension| extension.to_string_lossy()).unwrap_or_default();
+    path.with_file_name(format!("{stem}-{:04}.{extension}", index + 1))
+}
+
+fn write_frames(frames: &[Frame], options: &RenderOptions, path: &Path) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("Error writing file: {}, {:?}", path.display(), e))?;
+    let out = BufWriter::new(file);
+    match options.format {
+        ImageFormat::Png => render::write_png(&frames[0], options.scale, out),
+        ImageFormat::Svg => render::write_svg(&frames[0], options.scale, out),
+        ImageFormat::Gif => render::write_gif(frames, options.scale, options.delay_ms, out),
+    }
+}
+
+pub fn run(options: &RenderOptions) -> Result<(), String> {
+    let input = input::read(options.input.as_deref(), options.year, options.day)?;
+    let mut frames = days::render(options.year, options.day, options.part, &input, &options.params)?;
+    if options.last {
+        frames = frames.split_off(frames.len().saturating_sub(1));
+    }
+    if frames.is_empty() {
+        return Err(format!("Nothing to draw for day {}", options.day));
+    }
+
+    if options.format == ImageFormat::Gif || frames.len() == 1 {
+        write_frames(&frames, options, &options.output)?;
+        println!("Wrote {} frame(s) to {}", frames.len(), options.output.display());
+    } else {
+        for (index, frame) in frames.iter().enumerate() {
+            write_frames(std::slice::from_ref(frame), options, &numbered_path(&options.output, index))?;
+        }
+        println!("Wrote {} frames to {} to {}", frames.len(),
+                 numbered_path(&options.output, 0).display(), numbered_path(&options.output, frames.len() - 1).display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::args::{Params, Part};
+    use std::fs;
+
+    #[test]
+    fn frames_are_numbered_from_one() {
+        assert_eq!(PathBuf::from("out/day06-0001.png"), numbered_path(Path::new("out/day06.png"), 0));
+        assert_eq!(PathBuf::from("maze-0120.svg"), numbered_path(Path::new("maze.svg"), 119));
+    }
+
+    #[test]
+    fn renders_an_animation_and_numbered_stills() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        fs::write(&input, "#####\n#S.E#\n#####\n").unwrap();
+        let mut options = RenderOptions{year: 2024, day: 16, part: Part::One, input: Some(input), params: Params::default(),
+                                        output: dir.join("maze.gif"), format: ImageFormat::Gif, scale: 2, delay_ms: 50, last: false};
+        run(&options).unwrap();
+        assert!(fs::read(dir.join("maze.gif")).unwrap().starts_with(b"GIF89a"));
+
+        options.output = dir.join("maze.svg");
+        options.format = ImageFormat::Svg;
+        run(&options).unwrap();
+        assert!(dir.join("maze-0003.svg").exists());
+        assert!(!dir.join("maze-0004.svg").exists());
+
+        options.output = dir.join("last.png");
+        options.format = ImageFormat::Png;
+        options.last = true;
+        run(&options).unwrap();
+        assert!(dir.join("last.png").exists());
+
+        options.day = 17;
+        assert!(run(&options).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
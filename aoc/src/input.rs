@@ -0,0 +1,275 @@
+use std::env;
+use std::fs;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USER_AGENT: &str = "aoc-2024 input fetcher (caches every input, one request per 3s at most)";
+
+/// Somewhere puzzle inputs can be found.
+pub trait InputProvider {
+    /// The input for `day` of `year`, or `None` if this provider doesn't have it.
+    fn input(&mut self, year: u16, day: u8) -> Result<Option<String>, String>;
+}
+
+fn read_if_present(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Error reading file: {}, {:?}", path.display(), e)),
+    }
+}
+
+/// `yearYYYY/dayNN/input.txt` under the working directory, next to the day's code.
+pub struct DayDirectories;
+
+impl InputProvider for DayDirectories {
+    fn input(&mut self, year: u16, day: u8) -> Result<Option<String>, String> {
+        read_if_present(&PathBuf::from(format!("year{year}")).join(format!("day{:02}", day)).join("input.txt"))
+    }
+}
+
+/// Downloads inputs from the puzzle site with a session cookie, waiting at least
+/// `min_interval` between requests.
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, min_interval: Duration) -> Self {
+        let agent = ureq::Agent::config_builder().timeout_global(Some(Duration::from_secs(30))).build().into();
+        Fetcher{agent, base_url: base_url.trim_end_matches('/').to_string(), session: session.trim().to_string(), min_interval, last_request: None}
+    }
+
+    fn fetch(&mut self, year: u16, day: u8) -> Result<String, String> {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.min_interval.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let download_error = |e: ureq::Error| format!("Error downloading {url}: {e}");
+        let mut response = self.agent.get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call().map_err(download_error)?;
+        response.body_mut().read_to_string().map_err(download_error)
+    }
+}
+
+/// Inputs saved as `<dir>/<year>/dayNN.txt`, downloaded on first use when there's a fetcher.
+/// A saved input is never downloaded again.
+pub struct Cache {
+    dir: PathBuf,
+    fetcher: Option<Fetcher>,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, fetcher: Option<Fetcher>) -> Self {
+        Cache{dir: dir.into(), fetcher}
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+    }
+}
+
+impl InputProvider for Cache {
+    fn input(&mut self, year: u16, day: u8) -> Result<Option<String>, String> {
+        let path = self.path(year, day);
+        if let Some(input) = read_if_present(&path)? {
+            return Ok(Some(input));
+        }
+        let Some(fetcher) = &mut self.fetcher else {
+            return Ok(None);
+        };
+        let input = fetcher.fetch(year, day)?;
+        let write_error = |e: std::io::Error| format!("Error writing file: {}, {:?}", path.display(), e);
+        fs::create_dir_all(path.parent().unwrap()).map_err(write_error)?;
+        fs::write(&path, &input).map_err(write_error)?;
+        Ok(Some(input))
+    }
+}
+
+/// The providers to try for each input, in order.
+pub struct Inputs {
+    providers: Vec<Box<dyn InputProvider>>,
+}
+
+impl Inputs {
+    pub fn new(providers: Vec<Box<dyn InputProvider>>) -> Self {
+        Inputs{providers}
+    }
+
+    /// `yearYYYY/dayNN/input.txt`, then the cache in `AOC_CACHE_DIR` (`inputs` by default), downloading
+    /// from `AOC_BASE_URL` (the puzzle site by default) when `AOC_SESSION` holds a session token.
+    pub fn from_env() -> Self {
+        let cache_dir = env::var("AOC_CACHE_DIR").unwrap_or_else(|_| "inputs".to_string());
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| "https://adventofcode.com".to_string());
+        let fetcher = env::var("AOC_SESSION").ok().filter(|session| !session.trim().is_empty())
+            .map(|session| Fetcher::new(&base_url, &session, Duration::from_secs(3)));
+        Inputs::new(vec![Box::new(DayDirectories), Box::new(Cache::new(cache_dir, fetcher))])
+    }
+
+    pub fn get(&mut self, year: u16, day: u8) -> Result<String, String> {
+        for provider in &mut self.providers {
+            if let Some(input) = provider.input(year, day)? {
+                return Ok(input);
+            }
+        }
+        Err(format!("No input for {year} day {day}, set AOC_SESSION to download it"))
+    }
+}
+
+/// A fingerprint of an input, 64-bit FNV-1a as hex, so results from the same input can be
+/// matched up without passing the input itself around.
+#[derive(Debug, Clone)]
+pub struct InputHash(u64);
+
+impl InputHash {
+    fn new() -> Self {
+        InputHash(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+
+    pub fn of(input: &str) -> String {
+        let mut hash = InputHash::new();
+        hash.update(input.as_bytes());
+        hash.finish()
+    }
+
+    /// The hash of the file at `path`, read a piece at a time like the days that stream it.
+    pub fn of_file(path: &Path) -> Result<String, String> {
+        let read_error = |e| format!("Error reading file: {}, {:?}", path.display(), e);
+        let mut file = fs::File::open(path).map_err(read_error)?;
+        let mut hash = InputHash::new();
+        let mut buffer = [0; 64 * 1024];
+        loop {
+            match file.read(&mut buffer).map_err(read_error)? {
+                0 => { return Ok(hash.finish()); },
+                read => hash.update(&buffer[..read]),
+            }
+        }
+    }
+}
+
+/// The input at `path` when one is given, otherwise the input for `day` of `year` from wherever
+/// `Inputs::from_env` finds it.
+pub fn read(path: Option<&Path>, year: u16, day: u8) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("Error reading file: {}, {:?}", path.display(), e)),
+        None => Inputs::from_env().get(year, day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    /// Serves one canned response per connection, then stops, handing back the requests it got.
+    fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            responses.into_iter().map(|(status, body)| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = String::new();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                while reader.read_line(&mut request).unwrap() > 2 {}
+                write!(stream, "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+                request
+            }).collect()
+        });
+        (base_url, handle)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn cache_downloads_each_input_once() {
+        let (base_url, server) = stub_server(vec![(200, "3   4\n4   3\n")]);
+        let dir = temp_dir("once");
+        let mut cache = Cache::new(&dir, Some(Fetcher::new(&base_url, "c0ffee\n", Duration::ZERO)));
+        assert_eq!(Ok(Some("3   4\n4   3\n".to_string())), cache.input(2024, 1));
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=c0ffee\r\n"));
+        assert_eq!("3   4\n4   3\n", fs::read_to_string(dir.join("2024").join("day01.txt")).unwrap());
+        // the stub has stopped, so this can only come from the cache
+        assert_eq!(Ok(Some("3   4\n4   3\n".to_string())), cache.input(2024, 1));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let (base_url, server) = stub_server(vec![(400, "Please log in")]);
+        let dir = temp_dir("failed");
+        let mut cache = Cache::new(&dir, Some(Fetcher::new(&base_url, "expired", Duration::ZERO)));
+        assert!(cache.input(2024, 2).is_err());
+        server.join().unwrap();
+        assert!(!cache.path(2024, 2).exists());
+    }
+
+    #[test]
+    fn cache_without_a_fetcher_only_reads() {
+        let mut cache = Cache::new(temp_dir("offline"), None);
+        assert_eq!(Ok(None), cache.input(2024, 3));
+    }
+
+    #[test]
+    fn fetcher_waits_between_requests() {
+        let (base_url, server) = stub_server(vec![(200, "1"), (200, "2")]);
+        let mut fetcher = Fetcher::new(&base_url, "c0ffee", Duration::from_millis(200));
+        let start = Instant::now();
+        assert_eq!(Ok("1".to_string()), fetcher.fetch(2024, 4));
+        assert_eq!(Ok("2".to_string()), fetcher.fetch(2024, 5));
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+
+    struct Fixed(Option<&'static str>);
+
+    impl InputProvider for Fixed {
+        fn input(&mut self, _year: u16, _day: u8) -> Result<Option<String>, String> {
+            Ok(self.0.map(String::from))
+        }
+    }
+
+    #[test]
+    fn inputs_come_from_the_first_provider_that_has_them() {
+        let mut inputs = Inputs::new(vec![Box::new(Fixed(None)), Box::new(Fixed(Some("first"))), Box::new(Fixed(Some("second")))]);
+        assert_eq!(Ok("first".to_string()), inputs.get(2024, 6));
+        assert!(Inputs::new(vec![Box::new(Fixed(None))]).get(2024, 6).is_err());
+    }
+
+    #[test]
+    fn files_hash_the_same_as_their_contents() {
+        assert_eq!("cbf29ce484222325", InputHash::of(""));
+        assert_eq!("af63dc4c8601ec8c", InputHash::of("a"));
+        let path = temp_dir("hash").with_extension("txt");
+        let input = "3   4\n".repeat(20000);
+        fs::write(&path, &input).unwrap();
+        assert_eq!(Ok(InputHash::of(&input)), InputHash::of_file(&path));
+        fs::remove_file(&path).unwrap();
+        assert!(InputHash::of_file(&path).is_err());
+    }
+}
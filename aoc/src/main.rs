@@ -0,0 +1,167 @@
+use std::process::ExitCode;
+use std::time::Instant;
+
+use serde::Serialize;
+
+mod args;
+mod bench;
+mod days;
+#[cfg(test)]
+mod examples;
+mod explain;
+mod export;
+mod input;
+mod scaffold;
+mod synthetic;
+mod tui;
+mod verify;
+
+use args::{Command, RunFormat, RunOptions};
+use input::InputHash;
+
+/// The year run when no year is given.
+const YEAR: u16 = 2024;
+
+/// The module a day logs from, which is its crate, e.g. `year2024_day17`.
+fn day_module(year: u16, day: u8) -> String {
+    format!("year{year}_day{day:02}")
+}
+
+/// Logs warnings, or whatever `RUST_LOG` asks for, plus the debug (or, with `verbosity` 2,
+/// trace) output of the `modules` and anything `filters` turns on. Logs go to stderr, leaving
+/// stdout to the answers.
+fn init_logging(modules: &[String], verbosity: u8, filters: Option<&str>) {
+    let mut builder = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"));
+    let level = match verbosity {
+        0 => None,
+        1 => Some(log::LevelFilter::Debug),
+        _ => Some(log::LevelFilter::Trace),
+    };
+    if let Some(level) = level {
+        for module in modules {
+            builder.filter_module(module, level);
+        }
+    }
+    if let Some(filters) = filters {
+        builder.parse_filters(filters);
+    }
+    builder.format_timestamp(None).target(env_logger::Target::Stderr).init();
+}
+
+/// One answer as `--format json` prints it, a line to itself.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: String,
+    /// How long parsing and solving the part took.
+    elapsed_ns: u64,
+    input_hash: String,
+}
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    let mut inputs = input::Inputs::from_env();
+    for day in &options.days {
+        // a file named on the command line is streamed, so it can be bigger than memory allows
+        let input = match &options.input {
+            Some(_) => None,
+            None => Some(inputs.get(options.year, *day)?),
+        };
+        let input_hash = match (options.format, &input, &options.input) {
+            (RunFormat::Text, _, _) => String::new(),
+            (RunFormat::Json, Some(input), _) => InputHash::of(input),
+            (RunFormat::Json, None, Some(path)) => InputHash::of_file(path)?,
+            (RunFormat::Json, None, None) => unreachable!("there's an input or a file for every day"),
+        };
+        for part in &options.parts {
+            let start = Instant::now();
+            let answer = match (&input, &options.input) {
+                (Some(input), _) => days::solve(options.year, *day, *part, input, &options.params)?,
+                (None, Some(path)) => days::solve_file(options.year, *day, *part, path, &options.params)?,
+                (None, None) => unreachable!("there's an input or a file for every day"),
+            };
+            let elapsed_ns = start.elapsed().as_nanos() as u64;
+            match options.format {
+                RunFormat::Text => println!("day{:02} part{}: {}", day, part.number(), answer),
+                RunFormat::Json => {
+                    let record = Record{day: *day, part: part.number(), answer: answer.to_string(), elapsed_ns, input_hash: input_hash.clone()};
+                    println!("{}", serde_json::to_string(&record).map_err(|e| format!("Error writing record: {e}"))?);
+                },
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sizes the pool the days' parallel loops run on, when a thread count was given.
+fn set_threads(threads: Option<usize>) -> Result<(), String> {
+    threads.map_or(Ok(()), common::parallel::set_threads)
+}
+
+fn finish(result: Result<(), String>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match args::parse_args(std::env::args().skip(1)) {
+        Ok(Command::Help) => {
+            println!("{}", args::USAGE);
+            ExitCode::SUCCESS
+        },
+        Ok(Command::Run(options)) => {
+            let modules: Vec<String> = options.days.iter().map(|day| day_module(options.year, *day)).collect();
+            init_logging(&modules, options.verbosity, options.log.as_deref());
+            finish(set_threads(options.threads).and_then(|()| run(&options)))
+        },
+        Ok(Command::Bench(options)) => {
+            init_logging(&[], 0, None);
+            finish(set_threads(options.threads).and_then(|()| bench::run(&options)))
+        },
+        Ok(Command::Verify(options)) => {
+            init_logging(&[], 0, None);
+            finish(set_threads(options.threads).and_then(|()| verify::run(&options)))
+        },
+        Ok(Command::Render(options)) => {
+            init_logging(&[], 0, None);
+            finish(export::run(&options))
+        },
+        Ok(Command::Step(options)) => {
+            init_logging(&[], 0, None);
+            finish(input::read(options.input.as_deref(), options.year, options.day)
+                .and_then(|input| days::step(options.year, options.day, options.part, &input, &options.params)))
+        },
+        Ok(Command::Explain(options)) => {
+            init_logging(&[], 0, None);
+            finish(explain::run(&options))
+        },
+        Ok(Command::Generate(options)) => {
+            init_logging(&[], 0, None);
+            finish(synthetic::run(&options))
+        },
+        Ok(Command::Scaffold(options)) => finish(scaffold::run(&options)),
+        Err(e) => {
+            eprintln!("{e}\n\n{}", args::USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_one_line_of_json() {
+        let record = Record{day: 1, part: 2, answer: "31".to_string(), elapsed_ns: 1500, input_hash: InputHash::of("3   4\n")};
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(!json.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::json!({"day": 1, "part": 2, "answer": "31", "elapsed_ns": 1500, "input_hash": InputHash::of("3   4\n")}), value);
+    }
+}
@@ -0,0 +1,182 @@
+//! Starts a new day as its own crate at `yearYYYY/dayNN`, which the workspace picks up as it is,
+//! and gathers it into its year's crate for the runner to dispatch to.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::args::ScaffoldOptions;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const YEAR_CARGO_TOML: &str = include_str!("../templates/year_Cargo.toml.in");
+const YEAR_LIB_RS: &str = include_str!("../templates/year_lib.rs.in");
+
+/// How a year's crate written by scaffold says so, and so can be written again.
+const WRITTEN_BY_SCAFFOLD: &str = "Written by `aoc scaffold`";
+
+fn read_error(path: &Path, e: std::io::Error) -> String {
+    format!("Error reading file: {}, {:?}", path.display(), e)
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> String {
+    format!("Error writing file: {}, {:?}", path.display(), e)
+}
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template.replace("{year}", &year.to_string()).replace("{dd}", &format!("{day:02}"))
+}
+
+/// Writes the new day's crate under the workspace at `root`, giving its directory. An existing
+/// day is never overwritten.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    let dir = root.join(format!("year{year}")).join(format!("day{day:02}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    fs::create_dir_all(dir.join("src")).map_err(|e| write_error(&dir, e))?;
+    for (path, template) in [(dir.join("Cargo.toml"), CARGO_TOML), (dir.join("src").join("lib.rs"), LIB_RS)] {
+        fs::write(&path, fill(template, year, day)).map_err(|e| write_error(&path, e))?;
+    }
+    Ok(dir)
+}
+
+/// The days there are crates for under the year's directory, in order.
+fn days_of(year_dir: &Path) -> Result<Vec<u8>, String> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(year_dir).map_err(|e| read_error(year_dir, e))? {
+        let path = entry.map_err(|e| read_error(year_dir, e))?.path();
+        let day = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.strip_prefix("day"))
+            .and_then(|day| day.parse::<u8>().ok());
+        if let Some(day) = day.filter(|_| path.join("Cargo.toml").is_file()) {
+            days.push(day);
+        }
+    }
+    days.sort();
+    Ok(days)
+}
+
+/// Writes the `yearYYYY` crate with every day of the year so far, unless it was written by hand,
+/// like `year2024`, giving whether it did.
+pub fn write_year(root: &Path, year: u16) -> Result<bool, String> {
+    let dir = root.join(format!("year{year}"));
+    let lib = dir.join("src").join("lib.rs");
+    match fs::read_to_string(&lib) {
+        Ok(text) if !text.contains(WRITTEN_BY_SCAFFOLD) => { return Ok(false); },
+        Ok(_) => {},
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {},
+        Err(e) => { return Err(read_error(&lib, e)); },
+    }
+    let days = days_of(&dir)?;
+    let dependencies: Vec<String> = days.iter()
+        .map(|day| format!("day{day:02} = {{ path = \"day{day:02}\", package = \"year{year}-day{day:02}\" }}"))
+        .collect();
+    let uses: String = days.iter().map(|day| format!("pub use day{day:02};\n")).collect();
+    let arms: String = days.iter().map(|day| format!("            {day} => $f::<$crate::day{day:02}::Day{day:02}>($($args),*),\n")).collect();
+    fs::create_dir_all(dir.join("src")).map_err(|e| write_error(&dir, e))?;
+    let cargo_toml = dir.join("Cargo.toml");
+    fs::write(&cargo_toml, YEAR_CARGO_TOML.replace("{year}", &year.to_string()).replace("{dependencies}", &dependencies.join("\n")))
+        .map_err(|e| write_error(&cargo_toml, e))?;
+    fs::write(&lib, YEAR_LIB_RS.replace("{year}", &year.to_string()).replace("{uses}", &uses).replace("{arms}", &arms))
+        .map_err(|e| write_error(&lib, e))?;
+    Ok(true)
+}
+
+/// Puts `line` into the file at `path` after the last of the lines `follows` picks out, unless
+/// it's there already, giving whether it wasn't.
+fn insert_after(path: &Path, follows: impl Fn(&str) -> bool, line: &str) -> Result<bool, String> {
+    let text = fs::read_to_string(path).map_err(|e| read_error(path, e))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return Ok(false);
+    }
+    let Some(index) = lines.iter().rposition(|existing| follows(existing)) else {
+        return Err(format!("Nowhere to add `{}` to {}", line.trim(), path.display()));
+    };
+    lines.insert(index + 1, line);
+    fs::write(path, lines.join("\n") + "\n").map_err(|e| write_error(path, e))?;
+    Ok(true)
+}
+
+/// Makes the year's crate a dependency of the runner, and gives it an arm in `for_year!`, if
+/// they aren't already.
+pub fn wire_year(root: &Path, year: u16) -> Result<(), String> {
+    insert_after(&root.join("Cargo.toml"), |line| line.starts_with("year") && line.contains("= { path = \"year"),
+                 &format!("year{year} = {{ path = \"year{year}\" }}"))?;
+    insert_after(&root.join("aoc").join("Cargo.toml"), |line| line.starts_with("year") && line.ends_with(".workspace = true"),
+                 &format!("year{year}.workspace = true"))?;
+    insert_after(&root.join("aoc").join("src").join("days.rs"), |line| line.contains("::$dispatch!($day, $f($($args),*)),"),
+                 &format!("            {year} => year{year}::$dispatch!($day, $f($($args),*)),"))?;
+    Ok(())
+}
+
+pub fn run(options: &ScaffoldOptions) -> Result<(), String> {
+    let root = Path::new("");
+    if !root.join("Cargo.toml").is_file() {
+        return Err("Run scaffold from the top of the workspace, where Cargo.toml is".to_string());
+    }
+    let (year, day) = (options.year, options.day);
+    let dir = create(root, year, day)?;
+    println!("Created {}, test it with `cargo test -p year{year}-day{day:02}`", dir.display());
+    if write_year(root, year)? {
+        wire_year(root, year)?;
+        println!("Added it to the year{year} crate, so `aoc run {year} {day}` runs it");
+    } else {
+        println!("To run it with `aoc run {year} {day}`, add it to year{year}/Cargo.toml, `pub use` it in year{year}/src/lib.rs \
+                  and give it an arm in that crate's `with_day!`");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_a_day_once() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let dir = create(&root, 2025, 7).unwrap();
+        assert_eq!(root.join("year2025").join("day07"), dir);
+        assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"year2025-day07\""));
+        let lib = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day07 {") && lib.contains("impl Solution<'_> for Day07 {") && lib.contains("#[cfg(test)]"));
+        assert!(!lib.contains("{dd}"));
+        assert!(create(&root, 2025, 7).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn gathers_the_days_into_a_year_the_runner_dispatches_to() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-year-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), include_str!("../../Cargo.toml")).unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), include_str!("../Cargo.toml")).unwrap();
+        fs::write(root.join("aoc").join("src").join("days.rs"), include_str!("days.rs")).unwrap();
+        for day in [7, 3] {
+            create(&root, 2025, day).unwrap();
+            assert!(write_year(&root, 2025).unwrap());
+            wire_year(&root, 2025).unwrap();
+        }
+
+        let lib = fs::read_to_string(root.join("year2025").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub use day03;\npub use day07;\n"));
+        assert!(lib.contains("            3 => $f::<$crate::day03::Day03>($($args),*),\n            7 => $f::<$crate::day07::Day07>"));
+        assert!(lib.contains("macro_rules! with_render_day") && !lib.contains("{arms}"));
+        let cargo_toml = fs::read_to_string(root.join("year2025").join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("name = \"year2025\"") && cargo_toml.contains("day07 = { path = \"day07\", package = \"year2025-day07\" }"));
+
+        // the runner's files get one line each, however many days are added
+        let count = |path: PathBuf, line: &str| fs::read_to_string(path).unwrap().lines().filter(|existing| *existing == line).count();
+        assert_eq!(1, count(root.join("Cargo.toml"), "year2025 = { path = \"year2025\" }"));
+        assert_eq!(1, count(root.join("aoc").join("Cargo.toml"), "year2025.workspace = true"));
+        let days = fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap();
+        assert!(days.contains("            2024 => year2024::$dispatch!($day, $f($($args),*)),\n            2025 => year2025::$dispatch!($day, $f($($args),*)),\n            year => Err"));
+
+        // a year's crate written by hand is left alone
+        create(&root, 2024, 1).unwrap();
+        fs::create_dir_all(root.join("year2024").join("src")).unwrap();
+        fs::write(root.join("year2024").join("src").join("lib.rs"), "pub use day01;\n").unwrap();
+        assert!(!write_year(&root, 2024).unwrap());
+        assert_eq!("pub use day01;\n", fs::read_to_string(root.join("year2024").join("src").join("lib.rs")).unwrap());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
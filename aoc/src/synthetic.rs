@@ -0,0 +1,106 @@
+//! Made-up puzzle inputs of any size, from each day's `generate` module, for timing the days
+//! on more than the one real input and for practice puzzles.
+//!
+//! `size` scales each input in whatever way suits the puzzle: the number of lines for list
+//! puzzles, the side of the map for grid puzzles. The same seed and size always give the same
+//! input, so timings of the same size are comparable between runs.
+
+use std::fs;
+
+use common::synthetic::Rng;
+pub use common::synthetic::Synthetic;
+
+use crate::args::GenerateOptions;
+
+/// The seed benchmarks use unless told otherwise.
+pub const SEED: u64 = 0x5eed_2024_0a0c_1225;
+
+pub fn input(year: u16, day: u8, size: usize, seed: u64) -> Result<Synthetic, String> {
+    if year != 2024 {
+        return Err(format!("No synthetic inputs for {year}"));
+    }
+    // each day gets its own stream, so one day's generator changing doesn't move another's input
+    let rng = &mut Rng::new(seed ^ day as u64);
+    let size = size.max(1);
+    Ok(match day {
+        1 => year2024::day01::generate::generate(rng, size),
+        2 => year2024::day02::generate::generate(rng, size),
+        3 => year2024::day03::generate::generate(rng, size),
+        4 => year2024::day04::generate::generate(rng, size),
+        5 => year2024::day05::generate::generate(rng, size),
+        6 => year2024::day06::generate::generate(rng, size),
+        7 => year2024::day07::generate::generate(rng, size),
+        8 => year2024::day08::generate::generate(rng, size),
+        9 => year2024::day09::generate::generate(rng, size),
+        10 => year2024::day10::generate::generate(rng, size),
+        11 => year2024::day11::generate::generate(rng, size),
+        12 => year2024::day12::generate::generate(rng, size),
+        13 => year2024::day13::generate::generate(rng, size),
+        14 => year2024::day14::generate::generate(rng, size),
+        15 => year2024::day15::generate::generate(rng, size),
+        16 => year2024::day16::generate::generate(rng, size),
+        17 => year2024::day17::generate::generate(rng, size),
+        18 => year2024::day18::generate::generate(rng, size),
+        19 => year2024::day19::generate::generate(rng, size),
+        20 => year2024::day20::generate::generate(rng, size),
+        21 => year2024::day21::generate::generate(rng, size),
+        22 => year2024::day22::generate::generate(rng, size),
+        23 => year2024::day23::generate::generate(rng, size),
+        24 => year2024::day24::generate::generate(rng, size),
+        25 => year2024::day25::generate::generate(rng, size),
+        day => { return Err(format!("No synthetic input for day {day}")); }
+    })
+}
+
+/// The params to pass with `--param` to run a synthetic input, like `size=6 bytes=12`.
+pub fn param_flags(synthetic: &Synthetic) -> String {
+    synthetic.params.iter().map(|(name, value)| format!("--param {name}={value}")).collect::<Vec<_>>().join(" ")
+}
+
+/// Writes a made-up input to the output file, or prints it, along with any params it needs.
+pub fn run(options: &GenerateOptions) -> Result<(), String> {
+    let synthetic = input(options.year, options.day, options.size, options.seed)?;
+    let flags = param_flags(&synthetic);
+    match &options.output {
+        Some(path) => {
+            fs::write(path, &synthetic.input).map_err(|e| format!("Error writing file: {}, {:?}", path.display(), e))?;
+            println!("Wrote day{:02} input to {}", options.day, path.display());
+            if !flags.is_empty() { println!("Run it with {flags}"); }
+        },
+        None => {
+            print!("{}", synthetic.input);
+            // on stderr, so the printed input can be piped straight into a file
+            if !flags.is_empty() { eprintln!("Run it with {flags}"); }
+        },
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_picks_the_input() {
+        assert_eq!(input(2024, 16, 20, 1), input(2024, 16, 20, 1));
+        assert_ne!(input(2024, 16, 20, 1), input(2024, 16, 20, 2));
+        assert_ne!(input(2024, 16, 20, 1).unwrap().input, input(2024, 15, 20, 1).unwrap().input);
+        assert!(input(2024, 26, 20, 1).is_err());
+        assert!(input(2023, 1, 20, 1).is_err());
+    }
+
+    #[test]
+    fn params_are_given_as_flags() {
+        let synthetic = input(2024, 18, 7, SEED).unwrap();
+        assert_eq!("--param size=6 --param bytes=18", param_flags(&synthetic));
+        assert_eq!("", param_flags(&input(2024, 1, 7, SEED).unwrap()));
+    }
+
+    #[test]
+    fn writes_the_input_to_a_file() {
+        let path = std::env::temp_dir().join(format!("aoc-generate-{}.txt", std::process::id()));
+        run(&GenerateOptions{year: 2024, day: 9, size: 20, seed: 3, output: Some(path.clone())}).unwrap();
+        assert_eq!(input(2024, 9, 20, 3).unwrap().input, fs::read_to_string(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+    }
+}
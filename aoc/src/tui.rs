@@ -0,0 +1,167 @@
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use render::{Simulation, Timeline, View};
+
+const HELP: &str = "→/l/space step  ←/h back  PgDn/PgUp ±100  g start  G end  <n> Enter jump to tick n  q quit";
+
+/// How far one press of page up or page down moves.
+const PAGE: usize = 100;
+
+#[derive(Debug, PartialEq)]
+enum Action {
+    Forward(usize),
+    Back(usize),
+    Seek(usize),
+    End,
+    Quit,
+    Nothing,
+}
+
+/// What `key` asks for, with digits collecting in `goto` until Enter jumps to that tick.
+fn action(key: KeyCode, goto: &mut String) -> Action {
+    match key {
+        KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => Action::Forward(1),
+        KeyCode::Left | KeyCode::Char('h') => Action::Back(1),
+        KeyCode::PageDown => Action::Forward(PAGE),
+        KeyCode::PageUp => Action::Back(PAGE),
+        KeyCode::Home | KeyCode::Char('g') => Action::Seek(0),
+        KeyCode::End | KeyCode::Char('G') => Action::End,
+        KeyCode::Char(digit) if digit.is_ascii_digit() => {
+            goto.push(digit);
+            Action::Nothing
+        },
+        KeyCode::Backspace => {
+            goto.pop();
+            Action::Nothing
+        },
+        KeyCode::Enter => match std::mem::take(goto).parse() {
+            Ok(tick) => Action::Seek(tick),
+            Err(_) => Action::Nothing,
+        },
+        KeyCode::Esc | KeyCode::Char('q') => Action::Quit,
+        _ => Action::Nothing,
+    }
+}
+
+/// The first of `window` rows (or columns) to show out of `len`, keeping `focus` in the middle where it can.
+fn viewport_start(len: usize, window: usize, focus: usize) -> usize {
+    focus.saturating_sub(window / 2).min(len.saturating_sub(window))
+}
+
+/// Raw mode on an alternate screen, put back however stepping ends.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(out: &mut impl Write, title: &str, tick: usize, end: Option<usize>, view: &View, goto: &str) -> io::Result<()> {
+    // some terminals (and pseudo-terminals) don't report a size
+    let (columns, rows) = match terminal::size()? {
+        (0, _) | (_, 0) => (80, 24),
+        (columns, rows) => (columns as usize, rows as usize),
+    };
+    let end = end.map_or("?".to_string(), |end| end.to_string());
+    let mut header = vec![format!("{title}  tick {tick} of {end}")];
+    header.extend(view.panel.iter().map(|(label, value)| format!("  {label}: {value}")));
+    if !goto.is_empty() {
+        header.push(format!("  jump to: {goto}"));
+    }
+    header.push(String::new());
+
+    queue!(out, Clear(ClearType::All))?;
+    for (row, line) in header.iter().enumerate().take(rows) {
+        queue!(out, MoveTo(0, row as u16), Print(line.chars().take(columns).collect::<String>()))?;
+    }
+
+    let map_rows = rows.saturating_sub(header.len() + 1);
+    let width = view.lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+    let top = viewport_start(view.lines.len(), map_rows, view.actor.map_or(0, |actor| actor.y));
+    let left = viewport_start(width, columns, view.actor.map_or(0, |actor| actor.x));
+    for (row, (y, line)) in view.lines.iter().enumerate().skip(top).take(map_rows).enumerate() {
+        queue!(out, MoveTo(0, (header.len() + row) as u16))?;
+        for (x, tile) in line.chars().enumerate().skip(left).take(columns) {
+            if view.actor.is_some_and(|actor| (actor.x, actor.y) == (x, y)) {
+                queue!(out, SetAttribute(Attribute::Reverse), Print(tile), SetAttribute(Attribute::Reset))?;
+            } else {
+                queue!(out, Print(tile))?;
+            }
+        }
+    }
+    queue!(out, MoveTo(0, rows.saturating_sub(1) as u16), Print(HELP.chars().take(columns).collect::<String>()))?;
+    out.flush()
+}
+
+/// Steps through `simulation` in the terminal until the user quits.
+pub fn run<S: Simulation>(simulation: &S, part: u8, title: &str) -> Result<(), String> {
+    if !io::stdout().is_terminal() {
+        return Err("Stepping needs a terminal to draw in".to_string());
+    }
+    let terminal_error = |e: io::Error| format!("Terminal error: {e}");
+    let mut timeline = Timeline::new(simulation, part);
+    let mut goto = String::new();
+    let _terminal = RawTerminal::enter().map_err(terminal_error)?;
+    let mut out = io::stdout();
+    loop {
+        draw(&mut out, title, timeline.tick(), timeline.end(), &timeline.view(), &goto).map_err(terminal_error)?;
+        let Event::Key(key) = event::read().map_err(terminal_error)? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match action(key.code, &mut goto) {
+            Action::Forward(ticks) => timeline.seek(timeline.tick() + ticks),
+            Action::Back(ticks) => timeline.seek(timeline.tick().saturating_sub(ticks)),
+            Action::Seek(tick) => timeline.seek(tick),
+            Action::End => timeline.seek(usize::MAX),
+            Action::Quit => { return Ok(()); },
+            Action::Nothing => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_step_and_jump() {
+        let mut goto = String::new();
+        assert_eq!(Action::Forward(1), action(KeyCode::Char(' '), &mut goto));
+        assert_eq!(Action::Back(PAGE), action(KeyCode::PageUp, &mut goto));
+        assert_eq!(Action::Nothing, action(KeyCode::Char('4'), &mut goto));
+        assert_eq!(Action::Nothing, action(KeyCode::Char('2'), &mut goto));
+        assert_eq!(Action::Nothing, action(KeyCode::Char('7'), &mut goto));
+        assert_eq!(Action::Nothing, action(KeyCode::Backspace, &mut goto));
+        assert_eq!("42", goto);
+        assert_eq!(Action::Seek(42), action(KeyCode::Enter, &mut goto));
+        assert_eq!("", goto);
+        assert_eq!(Action::Nothing, action(KeyCode::Enter, &mut goto));
+        assert_eq!(Action::Quit, action(KeyCode::Char('q'), &mut goto));
+    }
+
+    #[test]
+    fn viewport_follows_the_actor() {
+        assert_eq!(0, viewport_start(10, 20, 7));
+        assert_eq!(0, viewport_start(100, 20, 5));
+        assert_eq!(40, viewport_start(100, 20, 50));
+        assert_eq!(80, viewport_start(100, 20, 99));
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use common::Answer;
+use serde::Deserialize;
+
+use crate::args::{Params, Part, VerifyOptions};
+use crate::days;
+use crate::input::Inputs;
+
+/// An accepted answer as written in the answers file, either a bare number or a string.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Accepted {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Accepted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Accepted::Int(value) => write!(f, "{value}"),
+            Accepted::Str(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The answers accepted so far, read from a TOML file with a table per year and day:
+///
+/// ```toml
+/// [2024.day17]
+/// part1 = "4,6,3,5,6,3,5,2,1,0"
+/// part2 = 117440
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u16, u8, u8), String>);
+
+type Days = BTreeMap<String, BTreeMap<String, Accepted>>;
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let years: BTreeMap<String, Days> = toml::from_str(text).map_err(|e| e.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (year_key, days) in years {
+            let year = year_key.parse::<u16>().ok().filter(|year| (2015..=9999).contains(year))
+                .ok_or_else(|| format!("Unknown table [{year_key}], expected a year like [2024.day01]"))?;
+            for (day_key, parts) in days {
+                let day = day_key.strip_prefix("day").and_then(|day| day.parse::<u8>().ok()).filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("Unknown table [{year_key}.{day_key}], expected [{year_key}.day01] to [{year_key}.day25]"))?;
+                for (part_key, accepted) in parts {
+                    let part = match part_key.as_str() {
+                        "part1" => 1,
+                        "part2" => 2,
+                        _ => { return Err(format!("Unknown key {part_key} in [{year_key}.{day_key}], expected part1 or part2")); }
+                    };
+                    answers.insert((year, day, part), accepted.to_string());
+                }
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Reads the answers at `path`, or none at all if there's no such file yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("Error reading answers {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                eprintln!("No answers file at {}, every answer is unknown", path.display());
+                Ok(Answers::default())
+            },
+            Err(e) => Err(format!("Error reading file: {}, {:?}", path.display(), e)),
+        }
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(year, day, part.number())).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Match,
+    Mismatch(String),
+    Unknown,
+}
+
+pub fn check(answer: &Answer, accepted: Option<&str>) -> Outcome {
+    match accepted {
+        None => Outcome::Unknown,
+        Some(accepted) if answer.to_string() == accepted => Outcome::Match,
+        Some(accepted) => Outcome::Mismatch(accepted.to_string()),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    matched: usize,
+    mismatched: usize,
+    unknown: usize,
+    failed: usize,
+    /// Days skipped for having no input.
+    missing: usize,
+}
+
+fn verify_day(year: u16, day: u8, inputs: &mut Inputs, answers: &Answers, tally: &mut Tally) {
+    let input = match inputs.get(year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("day{day:02}        {e}");
+            tally.missing += 1;
+            return;
+        }
+    };
+    for part in [Part::One, Part::Two] {
+        let start = Instant::now();
+        let answer = days::solve(year, day, part, &input, &Params::default());
+        let elapsed = format!("{:.3?}", start.elapsed());
+        let label = format!("day{day:02} part{}", part.number());
+        match answer {
+            Ok(Answer::None) => {},
+            Ok(answer) => match check(&answer, answers.get(year, day, part)) {
+                Outcome::Match => {
+                    println!("{label}  match     {answer}  ({elapsed})");
+                    tally.matched += 1;
+                },
+                Outcome::Mismatch(accepted) => {
+                    println!("{label}  MISMATCH  {answer}, accepted {accepted}  ({elapsed})");
+                    tally.mismatched += 1;
+                },
+                Outcome::Unknown => {
+                    println!("{label}  unknown   {answer}  ({elapsed})");
+                    tally.unknown += 1;
+                }
+            },
+            Err(e) => {
+                println!("{label}  FAILED    {e}");
+                tally.failed += 1;
+            }
+        }
+    }
+}
+
+pub fn run(options: &VerifyOptions) -> Result<(), String> {
+    let answers = Answers::load(&options.answers)?;
+    let mut inputs = Inputs::from_env();
+    let start = Instant::now();
+    let mut tally = Tally::default();
+    for day in &options.days {
+        verify_day(options.year, *day, &mut inputs, &answers, &mut tally);
+    }
+    println!("{} matched, {} mismatched, {} unknown, {} failed, {} day(s) without input, in {:.3?}",
+             tally.matched, tally.mismatched, tally.unknown, tally.failed, tally.missing, start.elapsed());
+    if tally.mismatched + tally.failed > 0 {
+        return Err(format!("{} answer(s) no longer match", tally.mismatched + tally.failed));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "
+[2024.day01]
+part1 = 11
+part2 = \"31\"
+
+[2024.day17]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+
+[2023.day01]
+part1 = 142
+";
+
+    #[test]
+    fn answers_are_keyed_by_year_day_and_part() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(Some("11"), answers.get(2024, 1, Part::One));
+        assert_eq!(Some("31"), answers.get(2024, 1, Part::Two));
+        assert_eq!(Some("4,6,3,5,6,3,5,2,1,0"), answers.get(2024, 17, Part::One));
+        assert_eq!(None, answers.get(2024, 17, Part::Two));
+        assert_eq!(None, answers.get(2024, 2, Part::One));
+        assert_eq!(Some("142"), answers.get(2023, 1, Part::One));
+        assert_eq!(None, answers.get(2023, 1, Part::Two));
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(Answers::parse("[2024.day26]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2024.day1x]\npart1 = 1").is_err());
+        assert!(Answers::parse("[2024.day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[2024.day01]\npart1 = [1]").is_err());
+        // a day needs its year
+        assert!(Answers::parse("[day01]\npart1 = 1").is_err());
+        assert!(Answers::parse("[1999.day01]\npart1 = 1").is_err());
+    }
+
+    #[test]
+    fn answers_are_checked_against_their_display() {
+        assert_eq!(Outcome::Match, check(&Answer::Int(11), Some("11")));
+        assert_eq!(Outcome::Match, check(&Answer::Point(6, 1), Some("6,1")));
+        assert_eq!(Outcome::Mismatch("12".to_string()), check(&Answer::Int(11), Some("12")));
+        assert_eq!(Outcome::Unknown, check(&Answer::Int(11), None));
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle, in the shape the puzzle asks for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// A location given as x,y, like the first byte to cut off the exit on day 18.
+    Point(i64, i64),
+    /// The part has no puzzle to solve, like the second half of day 25.
+    None,
+    /// The part couldn't be answered from this input, like a total too big to hold, and why.
+    Error(String),
+}
+
+impl Answer {
+    /// The answer, or why there isn't one as an error for the caller to report.
+    pub fn into_result(self) -> Result<Answer, String> {
+        match self {
+            Answer::Error(e) => Err(e),
+            answer => Ok(answer),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Str(value) => write!(f, "{value}"),
+            Answer::Point(x, y) => write!(f, "{x},{y}"),
+            Answer::None => write!(f, "none"),
+            Answer::Error(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_display_as_the_puzzle_expects() {
+        assert_eq!("11387", Answer::from(11387_u64).to_string());
+        assert_eq!("-12", Answer::from(-12_i64).to_string());
+        assert_eq!("co,de,ka,ta", Answer::from("co,de,ka,ta").to_string());
+        assert_eq!("6,1", Answer::Point(6, 1).to_string());
+        assert_eq!("none", Answer::None.to_string());
+        assert_eq!("error: the total distance overflowed", Answer::Error("the total distance overflowed".to_string()).to_string());
+    }
+
+    #[test]
+    fn an_error_answer_is_an_error() {
+        assert_eq!(Ok(Answer::Int(11)), Answer::Int(11).into_result());
+        assert_eq!(Err("the total distance overflowed".to_string()), Answer::Error("the total distance overflowed".to_string()).into_result());
+    }
+
+    #[test]
+    fn large_unsigned_answers_are_not_truncated() {
+        assert_eq!(Answer::Int(u64::MAX as i128), Answer::from(u64::MAX));
+    }
+}
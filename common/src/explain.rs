@@ -0,0 +1,67 @@
+use std::fmt;
+
+/// The evidence behind an answer, as a table: one row per thing the answer was added up from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Explanation {
+    pub fn new(columns: &[&'static str]) -> Self {
+        Explanation{columns: columns.to_vec(), rows: Vec::new()}
+    }
+
+    /// Adds a row, which must have a cell for every column.
+    pub fn row(&mut self, cells: Vec<String>) {
+        assert_eq!(self.columns.len(), cells.len(), "a row needs a cell for every column");
+        self.rows.push(cells);
+    }
+}
+
+/// Lines the columns up, with a rule under the header.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut widths: Vec<usize> = self.columns.iter().map(|column| column.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let write_row = |f: &mut fmt::Formatter<'_>, cells: Vec<&str>| {
+            let line: Vec<String> = cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+            writeln!(f, "{}", line.join("  ").trim_end())
+        };
+        write_row(f, self.columns.clone())?;
+        write_row(f, widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>().iter().map(String::as_str).collect())?;
+        for row in &self.rows {
+            write_row(f, row.iter().map(String::as_str).collect())?;
+        }
+        Ok(())
+    }
+}
+
+/// A puzzle that can show how it got its answer, so the answer can be checked rather than trusted.
+pub trait Explain {
+    /// The rows `part`'s answer was worked out from.
+    fn explain(&self, part: u8) -> Explanation;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_line_up() {
+        let mut explanation = Explanation::new(&["machine", "presses"]);
+        explanation.row(vec!["1".to_string(), "80 A, 40 B".to_string()]);
+        explanation.row(vec!["20".to_string(), "".to_string()]);
+        assert_eq!("machine  presses\n-------  ----------\n1        80 A, 40 B\n20\n", explanation.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn rows_fill_every_column() {
+        Explanation::new(&["machine", "presses"]).row(vec!["1".to_string()]);
+    }
+}
@@ -0,0 +1,13 @@
+mod answer;
+mod explain;
+pub mod parallel;
+mod parse;
+mod read;
+mod solution;
+pub mod synthetic;
+
+pub use answer::Answer;
+pub use explain::{Explain, Explanation};
+pub use parse::{InputLines, Line, ParseError};
+pub use read::{for_each_line, read_input, FromReader, ReadError};
+pub use solution::{parse_param, ParamError, Solution};
@@ -0,0 +1,69 @@
+//! Loops over independent items that run across threads when the `parallel` feature is on, and
+//! one item at a time otherwise. Either way the results come out the same.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// `f` of every item, in the items' order.
+#[cfg(feature = "parallel")]
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    items.par_iter().map(f).collect()
+}
+
+/// `f` of every item, in the items' order.
+#[cfg(not(feature = "parallel"))]
+pub fn par_map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+/// Folds the items into accumulators made by `init`, then combines those with `reduce`. Threads
+/// each fold their own share of the items, so `fold` and `reduce` have to give the same answer
+/// however the items are split and in whatever order the shares are combined.
+#[cfg(feature = "parallel")]
+pub fn par_fold<T: Sync, A: Send>(items: &[T], init: impl Fn() -> A + Sync + Send, fold: impl Fn(A, &T) -> A + Sync + Send,
+                                  reduce: impl Fn(A, A) -> A + Sync + Send) -> A {
+    items.par_iter().fold(&init, fold).reduce(&init, reduce)
+}
+
+/// Folds the items into accumulators made by `init`, then combines those with `reduce`. Threads
+/// each fold their own share of the items, so `fold` and `reduce` have to give the same answer
+/// however the items are split and in whatever order the shares are combined.
+#[cfg(not(feature = "parallel"))]
+pub fn par_fold<T: Sync, A: Send>(items: &[T], init: impl Fn() -> A + Sync + Send, fold: impl Fn(A, &T) -> A + Sync + Send,
+                                  _reduce: impl Fn(A, A) -> A + Sync + Send) -> A {
+    items.iter().fold(init(), fold)
+}
+
+/// Runs the parallel loops on `threads` threads, rather than one per core.
+#[cfg(feature = "parallel")]
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().map_err(|e| format!("Error starting {threads} threads: {e}"))
+}
+
+/// Runs the parallel loops on `threads` threads, which without the `parallel` feature can only be one.
+#[cfg(not(feature = "parallel"))]
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    match threads {
+        1 => Ok(()),
+        _ => Err(format!("Can't run on {threads} threads without the `parallel` feature, build with `--features parallel`")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_keeps_the_items_in_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        assert_eq!(items.iter().map(|item| item * item).collect::<Vec<_>>(), par_map(&items, |item| item * item));
+    }
+
+    #[test]
+    fn fold_combines_every_share() {
+        let items: Vec<usize> = (0..1000).collect();
+        let counts = par_fold(&items, || vec![0; 10], |mut counts, item| { counts[item % 10] += 1; counts },
+                              |a, b| a.iter().zip(&b).map(|(a, b)| a + b).collect());
+        assert_eq!(vec![100; 10], counts);
+    }
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+/// Why a puzzle input could not be read, and where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input the problem was found on.
+    pub line: usize,
+    /// 1-based column, counted in characters, where the offending text starts.
+    pub column: usize,
+    /// What the parser was looking for, like "a number" or "`Register A: <n>`".
+    pub expected: String,
+    /// The text found instead, empty when the line or the input ended first.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        ParseError{line, column, expected: expected.into(), found: found.into()}
+    }
+
+    /// The input ran out at `line` while `expected` was still to come.
+    pub fn end_of_input(line: usize, expected: impl Into<String>) -> Self {
+        ParseError::new(line, 1, expected, "")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// One line of puzzle input, remembering where it came from so errors can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    /// The line with any trailing whitespace, like a `\r` from Windows line endings, removed.
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// 1-based column of `token`, which should be a slice of this line's text.
+    fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// An error pointing at `token`, a slice of this line's text that isn't what was expected.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(token), expected, token)
+    }
+
+    /// An error for a line that ended before `expected` was read.
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, expected, "")
+    }
+
+    /// An error pointing at the character starting at byte `index` of this line's text.
+    pub fn char_error(&self, index: usize, expected: impl Into<String>) -> ParseError {
+        let token = self.text[index..].chars().next().map_or("", |c| &self.text[index..index + c.len_utf8()]);
+        self.error(token, expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: impl Into<String>) -> Result<T, ParseError> {
+        token.parse::<T>().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parses the next of `fields`, split from this line's text.
+    pub fn field<T: FromStr>(&self, fields: &mut impl Iterator<Item = &'a str>, expected: impl Into<String>) -> Result<T, ParseError> {
+        match fields.next() {
+            Some(token) => self.parse(token, expected),
+            None => Err(self.missing(expected)),
+        }
+    }
+
+    /// Checks nothing is left of `fields` once a line has been read.
+    pub fn end(&self, fields: &mut impl Iterator<Item = &'a str>) -> Result<(), ParseError> {
+        match fields.next() {
+            Some(token) => Err(self.error(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a row of a grid has `width` characters.
+    pub fn expect_width(&self, width: usize) -> Result<(), ParseError> {
+        match self.text.char_indices().nth(width) {
+            Some((index, _)) => Err(self.error(&self.text[index..], "end of row")),
+            None if self.text.chars().count() < width => Err(self.missing(format!("a row {width} wide"))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The lines of a puzzle input, numbered for error reporting.
+pub struct InputLines<'a> {
+    lines: Peekable<std::str::Lines<'a>>,
+    number: usize,
+}
+
+impl<'a> InputLines<'a> {
+    pub fn new(lines: std::str::Lines<'a>) -> Self {
+        InputLines{lines: lines.peekable(), number: 0}
+    }
+
+    /// 1-based number of the line `next` will return.
+    pub fn next_number(&self) -> usize {
+        self.number + 1
+    }
+
+    /// Skips any blank lines, then says whether there's any input left.
+    pub fn has_more(&mut self) -> bool {
+        while self.lines.next_if(|line| line.trim().is_empty()).is_some() {
+            self.number += 1;
+        }
+        self.lines.peek().is_some()
+    }
+
+    /// Whether the next line is blank, or there are no lines left.
+    pub fn at_blank(&mut self) -> bool {
+        self.lines.peek().is_none_or(|line| line.trim().is_empty())
+    }
+
+    /// The next line, or an error if the input has ended while `expected` was still to come.
+    pub fn expect(&mut self, expected: impl Into<String>) -> Result<Line<'a>, ParseError> {
+        let number = self.next_number();
+        self.next().ok_or_else(|| ParseError::end_of_input(number, expected))
+    }
+
+    /// The next line that isn't blank, or an error as for `expect`.
+    pub fn expect_non_blank(&mut self, expected: impl Into<String>) -> Result<Line<'a>, ParseError> {
+        let expected = expected.into();
+        loop {
+            let line = self.expect(expected.as_str())?;
+            if !line.is_blank() { return Ok(line); }
+        }
+    }
+
+    /// Checks only blank lines are left.
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.find(|line| !line.is_blank()) {
+            Some(line) => Err(line.error(line.text, "end of input")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for InputLines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Line<'a>> {
+        let text = self.lines.next()?.trim_end();
+        self.number += 1;
+        Some(Line{number: self.number, text})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        let mut lines = InputLines::new("3   4\n3   x4\r\n".lines());
+        let first = lines.next().unwrap();
+        let second = lines.next().unwrap();
+        assert_eq!("3   x4", second.text);
+        let mut fields = second.text.split_whitespace();
+        assert_eq!(Ok(3), second.field::<i32>(&mut fields, "a number"));
+        let error = second.field::<i32>(&mut fields, "a number").unwrap_err();
+        assert_eq!(ParseError::new(2, 5, "a number", "x4"), error);
+        assert_eq!("line 2, column 5: expected a number, found \"x4\"", error.to_string());
+        assert_eq!(ParseError::new(1, 6, "a number", ""), first.missing("a number"));
+        assert_eq!(ParseError::end_of_input(3, "a number"), lines.expect("a number").unwrap_err());
+    }
+
+    #[test]
+    fn line_endings_and_trailing_whitespace_are_dropped() {
+        // each input with its lines that aren't blank, as numbered and trimmed
+        let cases: [(&str, &[(usize, &str)]); 7] = [
+            ("3   4\n4   3", &[(1, "3   4"), (2, "4   3")]),
+            ("3   4\r\n4   3\r\n", &[(1, "3   4"), (2, "4   3")]),
+            ("3   4  \r\n4   3\t\n", &[(1, "3   4"), (2, "4   3")]),
+            ("\r\n3   4\r\n\r\n  \r\n4   3\r\n\r\n", &[(2, "3   4"), (5, "4   3")]),
+            ("  3   4 \r", &[(1, "  3   4")]),
+            ("", &[]),
+            ("\r\n \n\t\r\n", &[]),
+        ];
+        for (input, expected) in cases {
+            let lines: Vec<(usize, &str)> = InputLines::new(input.lines()).filter(|line| !line.is_blank())
+                .map(|line| (line.number, line.text)).collect();
+            assert_eq!(expected, lines, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn blank_lines_at_the_end_are_no_more_input() {
+        for input in ["7", "7\n", "7\r\n\r\n", "\r\n7  \n  \n\t\r\n"] {
+            let mut lines = InputLines::new(input.lines());
+            assert_eq!("7", lines.expect_non_blank("a number").unwrap().text, "{input:?}");
+            assert!(!lines.has_more(), "{input:?}");
+            let mut lines = InputLines::new(input.lines());
+            lines.expect_non_blank("a number").unwrap();
+            assert_eq!(Ok(()), lines.end(), "{input:?}");
+        }
+        let mut lines = InputLines::new("7\r\n\r\n8 \r\n".lines());
+        lines.next();
+        assert_eq!(Err(ParseError::new(3, 1, "end of input", "8")), lines.end());
+    }
+
+    #[test]
+    fn rows_must_match_the_grid_width() {
+        let row = Line{number: 4, text: "#..#"};
+        assert_eq!(Ok(()), row.expect_width(4));
+        assert_eq!(ParseError::new(4, 4, "end of row", "#"), row.expect_width(3).unwrap_err());
+        assert_eq!(ParseError::new(4, 5, "a row 5 wide", ""), row.expect_width(5).unwrap_err());
+    }
+}
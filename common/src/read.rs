@@ -0,0 +1,103 @@
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::{Line, ParseError};
+
+/// Why a puzzle input could not be read from a reader: either the reading or the parsing failed.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// A day built straight from a reader, owning everything it parsed, so the input never has to be
+/// held in memory all at once when the day doesn't need it to be.
+pub trait FromReader: Sized {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError>;
+}
+
+/// The whole input, for days that need all of it before they can parse any of it.
+pub fn read_input(mut reader: impl BufRead) -> Result<String, ReadError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Calls `f` with each line of the input that isn't blank, one at a time, numbered and trimmed
+/// the same as `InputLines` would.
+pub fn for_each_line(mut reader: impl BufRead, mut f: impl FnMut(Line<'_>) -> Result<(), ParseError>) -> Result<(), ReadError> {
+    let mut text = String::new();
+    let mut number = 0;
+    loop {
+        text.clear();
+        if reader.read_line(&mut text)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let line = Line{number, text: text.trim_end()};
+        if !line.is_blank() {
+            f(line)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputLines;
+
+    #[test]
+    fn lines_match_input_lines() {
+        const INPUT: &str = "first\r\n\n  second  \n\nthird";
+        let mut lines = Vec::new();
+        for_each_line(INPUT.as_bytes(), |line| {
+            lines.push((line.number, line.text.to_string()));
+            Ok(())
+        }).unwrap();
+        let expected: Vec<_> = InputLines::new(INPUT.lines()).filter(|line| !line.is_blank())
+            .map(|line| (line.number, line.text.to_string())).collect();
+        assert_eq!(expected, lines);
+    }
+
+    #[test]
+    fn parse_errors_stop_the_reading() {
+        let mut seen = 0;
+        let result = for_each_line("1\nx\n3\n".as_bytes(), |line| {
+            seen += 1;
+            line.parse::<u32>(line.text, "a number").map(|_| ())
+        });
+        assert_eq!(2, seen);
+        match result {
+            Err(ReadError::Parse(e)) => assert_eq!(ParseError::new(2, 1, "a number", "x"), e),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_io_error() {
+        assert!(matches!(read_input(&b"ok\n\xff\n"[..]), Err(ReadError::Io(_))));
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Answer, ParseError};
+
+/// Why a puzzle parameter could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// The puzzle has no parameter with this name.
+    Unknown(String),
+    Invalid { name: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "unknown param: {name}"),
+            ParamError::Invalid{name, value} => write!(f, "invalid value for param {name}: {value}"),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Parses a parameter value for `Solution::configure`.
+pub fn parse_param<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value.parse::<T>().map_err(|_| ParamError::Invalid{name: name.to_string(), value: value.to_string()})
+}
+
+/// A day's puzzle: built from the puzzle input, then asked for the answer to either part.
+///
+/// The lifetime lets a day borrow from its input, though every day here owns what it parses so it
+/// can be built from a reader with `FromReader` as well.
+pub trait Solution<'a>: Sized {
+    fn parse(input: &'a str) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+
+    /// Overrides one of the values a puzzle takes besides its input, like the size of the
+    /// memory space on day 18. `parse` sets these to the real puzzle's values.
+    fn configure(&mut self, name: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(name.to_string()))
+    }
+}
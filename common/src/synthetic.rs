@@ -0,0 +1,100 @@
+//! Building blocks for the days' `generate` modules, which make up puzzle inputs of any size.
+//!
+//! Generators only draw from the `Rng` they're given, so the same seed always gives the same
+//! input.
+
+/// A puzzle input, with any params the puzzle needs to make sense of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Synthetic {
+    pub input: String,
+    pub params: Vec<(&'static str, String)>,
+}
+
+impl From<String> for Synthetic {
+    fn from(input: String) -> Self {
+        Synthetic{input, params: Vec::new()}
+    }
+}
+
+/// A small xorshift generator, plenty random enough for puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number from `low` to `high`, both included.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    /// An index into something `len` long, which mustn't be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(1, 100) <= percent
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.index(index + 1));
+        }
+    }
+}
+
+/// The lines joined into an input, each ending in a newline.
+pub fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// A square grid `side` tiles across of letters picked at random.
+pub fn letter_grid(rng: &mut Rng, side: usize, letters: &str) -> String {
+    let letters: Vec<char> = letters.chars().collect();
+    lines((0..side).map(|_| (0..side).map(|_| rng.pick(&letters)).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_the_same_numbers() {
+        let (mut first, mut second) = (Rng::new(2024), Rng::new(2024));
+        let numbers: Vec<u64> = (0..100).map(|_| first.range(3, 9)).collect();
+        assert_eq!(numbers, (0..100).map(|_| second.range(3, 9)).collect::<Vec<_>>());
+        assert!(numbers.iter().all(|number| (3..=9).contains(number)));
+        let mut other = Rng::new(2025);
+        assert_ne!(numbers, (0..100).map(|_| other.range(3, 9)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn shuffle_keeps_every_item() {
+        let mut items: Vec<usize> = (0..50).collect();
+        Rng::new(7).shuffle(&mut items);
+        assert_ne!((0..50).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..50).collect::<Vec<_>>(), items);
+    }
+
+    #[test]
+    fn grids_are_square() {
+        let grid = letter_grid(&mut Rng::new(1), 5, "AB");
+        assert_eq!(5, grid.lines().count());
+        assert!(grid.lines().all(|line| line.len() == 5 && line.chars().all(|c| c == 'A' || c == 'B')));
+    }
+}
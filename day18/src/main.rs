@@ -1,16 +0,0 @@
-use std::fs;
-use day18::Day18;
-
-fn main() {
-    const DATAFILE: &str = "input.txt";
-    let file_content = fs::read_to_string(DATAFILE);
-    match file_content {
-        Ok(line_content) => {
-            let day = Day18::new(line_content.lines(), 70, 70);
-            println!("part1: {}", day.part1(1024));
-            println!("part2: {:?}", day.part2(1024));
-            //println!("part2: {}", day.part2());
-        },
-        Err(e) => { println!("Error reading file: {}, {:?}", DATAFILE, e); }
-    }
-}
@@ -0,0 +1,95 @@
+/// A compass direction on a grid, where north is up the page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions along the grid lines, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+    ];
+
+    /// How far one step in this direction moves, as (dx, dy).
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North     => ( 0, -1),
+            Direction::NorthEast => ( 1, -1),
+            Direction::East      => ( 1,  0),
+            Direction::SouthEast => ( 1,  1),
+            Direction::South     => ( 0,  1),
+            Direction::SouthWest => (-1,  1),
+            Direction::West      => (-1,  0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    fn turn(&self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|direction| direction == self).unwrap();
+        Direction::ALL[(index + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(&self) -> Direction {
+        self.turn(2)
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(&self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn reverse(&self) -> Direction {
+        self.turn(4)
+    }
+
+    /// The direction an arrow like `^`, `>`, `v` or `<` points.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// The arrow pointing this way, for the four cardinal directions.
+    pub fn to_arrow(&self) -> Option<char> {
+        match self {
+            Direction::North => Some('^'),
+            Direction::East => Some('>'),
+            Direction::South => Some('v'),
+            Direction::West => Some('<'),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_go_round_the_compass() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::NorthWest, Direction::SouthEast.reverse());
+        assert_eq!(Direction::North, Direction::West.turn_right());
+        assert_eq!(Some(Direction::South), Direction::from_arrow('v'));
+        assert_eq!(None, Direction::from_arrow('x'));
+        assert_eq!(Some('<'), Direction::West.to_arrow());
+        assert_eq!(None, Direction::SouthWest.to_arrow());
+    }
+}
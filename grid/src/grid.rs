@@ -0,0 +1,240 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::{InputLines, ParseError};
+
+use crate::{Direction, Point};
+
+/// A rectangular grid of cells, like the map in many of the puzzles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid{width, height, cells: vec![fill; width * height]}
+    }
+
+    /// Builds a grid from its rows, or `None` if they aren't all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid{width, height, cells: rows.into_iter().flatten().collect()})
+    }
+
+    /// Reads rows of a grid until a blank line or the end of the input, turning each character
+    /// into a cell with `cell`. Rows must all be as wide as the first, and any character `cell`
+    /// returns `None` for is reported as not being `expected`.
+    pub fn read(lines: &mut InputLines<'_>, expected: &str, mut cell: impl FnMut(Point, char) -> Option<T>) -> Result<Self, ParseError> {
+        if !lines.has_more() {
+            return Err(ParseError::end_of_input(lines.next_number(), "a row of the grid"));
+        }
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        while !lines.at_blank() {
+            let line = lines.expect("a row of the grid")?;
+            if height == 0 {
+                width = line.text.chars().count();
+            }
+            line.expect_width(width)?;
+            for (x, (index, a_char)) in line.text.char_indices().enumerate() {
+                match cell(Point::new(x, height), a_char) {
+                    Some(value) => { cells.push(value); },
+                    None => { return Err(line.char_error(index, expected)); }
+                }
+            }
+            height += 1;
+        }
+        Ok(Grid{width, height, cells})
+    }
+
+    /// Reads a grid that makes up the whole of the input, as for `read`.
+    pub fn parse(lines: std::str::Lines<'_>, expected: &str, cell: impl FnMut(Point, char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut lines = InputLines::new(lines);
+        let grid = Self::read(&mut lines, expected, cell)?;
+        lines.end()?;
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) { Some(&self.cells[point.y * self.width + point.x]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) { Some(&mut self.cells[point.y * self.width + point.x]) } else { None }
+    }
+
+    pub fn swap(&mut self, a: Point, b: Point) {
+        assert!(self.contains(a) && self.contains(b), "{a} or {b} is outside the grid");
+        self.cells.swap(a.y * self.width + a.x, b.y * self.width + b.x);
+    }
+
+    /// The point `dx` across and `dy` down from `point`, if that's still on the grid.
+    pub fn offset(&self, point: Point, dx: isize, dy: isize) -> Option<Point> {
+        point.offset(dx, dy).filter(|point| self.contains(*point))
+    }
+
+    /// The next point in `direction`, if that's still on the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.offset();
+        self.offset(point, dx, dy)
+    }
+
+    /// The points above, right of, below and left of `point` that are on the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = (Direction, Point)> + '_ {
+        Direction::CARDINAL.into_iter().filter_map(move |direction| Some((direction, self.step(point, direction)?)))
+    }
+
+    /// The points around `point`, including diagonals, that are on the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The points from `point`, not included, to the edge of the grid in `direction`.
+    pub fn ray(&self, point: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.step(point, direction), move |point| self.step(*point, direction))
+    }
+
+    /// Every point of the grid, a row at a time from the top.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn positions<'a>(&'a self, mut predicate: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = Point> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(point, _)| point)
+    }
+
+    /// The first point, in reading order, whose cell matches `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.positions(predicate).next()
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the {}x{} grid", self.width, self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid{width: self.width, height: self.height, cells: self.cells.iter().map(f).collect()}
+    }
+}
+
+impl Grid<char> {
+    /// Reads a grid of characters that makes up the whole input, allowing only those in `allowed`.
+    pub fn parse_chars(lines: std::str::Lines<'_>, allowed: &str) -> Result<Self, ParseError> {
+        let expected = format!("one of {allowed:?}");
+        Self::parse(lines, &expected, |_, a_char| allowed.contains(a_char).then_some(a_char))
+    }
+
+    pub fn find_char(&self, a_char: char) -> Option<Point> {
+        self.find(|cell| *cell == a_char)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// Draws the grid a row per line, the way the puzzles show their maps.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.S.\n..E";
+
+    #[test]
+    fn parses_and_displays_a_map() {
+        let grid = Grid::parse_chars(MAP.lines(), "#.SE").unwrap();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!(Some(Point::new(1, 1)), grid.find_char('S'));
+        assert_eq!('E', grid[Point::new(2, 2)]);
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(format!("{MAP}\n"), grid.to_string());
+        assert_eq!(&['.', 'S', '.'], grid.row(1));
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_cell() {
+        assert_eq!(Some(ParseError::new(2, 3, "one of \"#.SE\"", "x")), Grid::parse_chars("#..\n.Sx".lines(), "#.SE").err());
+        assert_eq!(Some(ParseError::new(2, 3, "a row 3 wide", "")), Grid::parse_chars("#..\n.S".lines(), "#.SE").err());
+        assert_eq!(Some(ParseError::end_of_input(2, "a row of the grid")), Grid::parse_chars("\n".lines(), "#.SE").err());
+    }
+
+    #[test]
+    fn read_stops_at_a_blank_line() {
+        let mut lines = InputLines::new("\n#.\n.#\n\n>>".lines());
+        let grid = Grid::read(&mut lines, "a wall or floor", |_, a_char| Some(a_char == '#')).unwrap();
+        assert_eq!(Some(Point::new(0, 0)), grid.find(|wall| *wall));
+        assert_eq!(2, grid.positions(|wall| !*wall).count());
+        assert_eq!(Some(">>"), lines.find(|line| !line.is_blank()).map(|line| line.text));
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = Grid::new(3, 2, 0);
+        let corner: Vec<Point> = grid.neighbours(Point::new(0, 0)).map(|(_, point)| point).collect();
+        assert_eq!(vec![Point::new(1, 0), Point::new(0, 1)], corner);
+        assert_eq!(5, grid.neighbours8(Point::new(1, 0)).count());
+        assert_eq!(8, Grid::new(3, 3, 0).neighbours8(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn rays_run_to_the_edge() {
+        let grid = Grid::new(4, 4, '.');
+        let ray: Vec<Point> = grid.ray(Point::new(1, 1), Direction::SouthEast).collect();
+        assert_eq!(vec![Point::new(2, 2), Point::new(3, 3)], ray);
+        assert_eq!(0, grid.ray(Point::new(0, 1), Direction::West).count());
+    }
+}
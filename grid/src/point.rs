@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// A cell of a grid, counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point{x, y}
+    }
+
+    /// The point `dx` across and `dy` down from this one, if that doesn't go past the top or left edge.
+    pub fn offset(&self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point{x: self.x.checked_add_signed(dx)?, y: self.y.checked_add_signed(dy)?})
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offsets_stop_at_the_top_left_edges() {
+        assert_eq!(Some(Point::new(2, 0)), Point::new(1, 1).offset(1, -1));
+        assert_eq!(None, Point::new(0, 1).offset(-1, 0));
+        assert_eq!(None, Point::new(1, 0).offset(0, -1));
+        assert_eq!(4, Point::new(1, 3).manhattan_distance(&Point::new(3, 1)));
+    }
+}
@@ -0,0 +1,103 @@
+//! The days behind a few functions that need nothing but memory, so they build for
+//! `wasm32-unknown-unknown`. With the `wasm` feature they're exported to JavaScript for the page
+//! in `www/`.
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use common::{Answer, Solution};
+use render::{Frame, Render};
+
+/// The days that can draw their map, the same as the runner's.
+pub use year2024::RENDER_DAYS;
+
+fn check_part(part: u8) -> Result<(), String> {
+    match part {
+        1 | 2 => Ok(()),
+        part => Err(format!("No part {part}, only 1 or 2")),
+    }
+}
+
+/// Parses `input` and applies `params`, written like `size=6 bytes=12`.
+fn prepare<'a, S: Solution<'a>>(input: &'a str, params: &str) -> Result<S, String> {
+    let mut day = S::parse(input).map_err(|e| format!("Error parsing input: {e}"))?;
+    for param in params.split_whitespace() {
+        let Some((name, value)) = param.split_once('=') else {
+            return Err(format!("Expected a param like `size=6`, found {param:?}"));
+        };
+        day.configure(name, value).map_err(|e| e.to_string())?;
+    }
+    Ok(day)
+}
+
+fn solve_with<'a, S: Solution<'a>>(input: &'a str, part: u8, params: &str) -> Result<Answer, String> {
+    check_part(part)?;
+    let day = prepare::<S>(input, params)?;
+    if part == 1 { day.part1() } else { day.part2() }.into_result()
+}
+
+fn render_with<'a, S: Solution<'a> + Render>(input: &'a str, part: u8, params: &str) -> Result<Vec<Frame>, String> {
+    check_part(part)?;
+    let day = prepare::<S>(input, params)?;
+    Ok(day.render(part))
+}
+
+/// `day`'s answer to `part` of `input`, with `params` for the puzzles that take them.
+pub fn solve(day: u8, part: u8, input: &str, params: &str) -> Result<Answer, String> {
+    year2024::with_day!(day, solve_with(input, part, params))
+}
+
+/// The finished picture of how `day` solves `part` of `input`, as an SVG `scale` pixels to a tile.
+pub fn render_svg(day: u8, part: u8, input: &str, params: &str, scale: usize) -> Result<String, String> {
+    let frames = year2024::with_render_day!(day, render_with(input, part, params))?;
+    let Some(frame) = frames.last() else {
+        return Err(format!("Nothing to draw for day {day}"));
+    };
+    let mut svg = Vec::new();
+    render::write_svg(frame, scale, &mut svg)?;
+    Ok(String::from_utf8(svg).expect("SVG is written as text"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::synthetic::Rng;
+
+    const DAY18_SAMPLE: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0";
+
+    #[test]
+    fn solves_with_params() {
+        assert_eq!(Ok(Answer::Int(11)), solve(1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3", ""));
+        assert_eq!(Ok(Answer::Point(6, 1)), solve(18, 2, DAY18_SAMPLE, "size=6 bytes=12"));
+        assert_eq!(Err("unknown param: saves".to_string()), solve(18, 1, DAY18_SAMPLE, "saves=100"));
+        assert!(solve(18, 1, DAY18_SAMPLE, "size").is_err());
+        assert!(solve(1, 3, "3   4", "").is_err());
+        let overflowing = "-9223372036854775808   9223372036854775807\n".repeat(3);
+        assert_eq!(Err("the total distance overflowed".to_string()), solve(1, 1, &overflowing, "metric=squared"));
+        assert!(solve(26, 1, "", "").is_err());
+    }
+
+    #[test]
+    fn draws_the_map_days() {
+        let svg = render_svg(16, 1, "#####\n#S.E#\n#####\n", "", 4).unwrap();
+        assert!(svg.starts_with("<svg ") && svg.contains("width=\"20\" height=\"12\""));
+        assert!(render_svg(1, 1, "3   4", "", 4).is_err());
+    }
+
+    #[test]
+    fn every_map_day_draws_a_made_up_input() {
+        for day in RENDER_DAYS {
+            let rng = &mut Rng::new(day as u64);
+            let synthetic = match day {
+                6 => year2024::day06::generate::generate(rng, 8),
+                14 => year2024::day14::generate::generate(rng, 8),
+                15 => year2024::day15::generate::generate(rng, 8),
+                16 => year2024::day16::generate::generate(rng, 8),
+                18 => year2024::day18::generate::generate(rng, 8),
+                _ => year2024::day20::generate::generate(rng, 8),
+            };
+            let params: Vec<String> = synthetic.params.iter().map(|(name, value)| format!("{name}={value}")).collect();
+            assert!(render_svg(day, 1, &synthetic.input, &params.join(" "), 1).is_ok(), "day {day}");
+        }
+    }
+}
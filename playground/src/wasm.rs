@@ -0,0 +1,20 @@
+//! The playground's functions as JavaScript sees them. Errors are thrown as strings.
+
+use wasm_bindgen::prelude::*;
+
+/// The answer to `part` of `day` for `input`, as text.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str, params: &str) -> Result<String, String> {
+    crate::solve(day, part, input, params).map(|answer| answer.to_string())
+}
+
+#[wasm_bindgen(js_name = renderSvg)]
+pub fn render_svg(day: u8, part: u8, input: &str, params: &str, scale: usize) -> Result<String, String> {
+    crate::render_svg(day, part, input, params, scale)
+}
+
+/// The days `renderSvg` can draw.
+#[wasm_bindgen(js_name = renderDays)]
+pub fn render_days() -> Vec<u8> {
+    crate::RENDER_DAYS.to_vec()
+}
@@ -0,0 +1,20 @@
+//! The playground built for `wasm32-unknown-unknown`, run under Node with
+//! `wasm-pack test --node playground -- --features wasm`.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn solves_a_sample() {
+    assert_eq!(Ok("11".to_string()), playground::wasm::solve(1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3", ""));
+    assert_eq!(Ok("31".to_string()), playground::wasm::solve(1, 2, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3", ""));
+    assert!(playground::wasm::solve(1, 1, "3   x4", "").is_err());
+}
+
+#[wasm_bindgen_test]
+fn draws_a_map() {
+    let svg = playground::wasm::render_svg(16, 1, "#####\n#S.E#\n#####\n", "", 4).unwrap();
+    assert!(svg.starts_with("<svg "));
+    assert_eq!(vec![6, 14, 15, 16, 18, 20], playground::wasm::render_days());
+}
@@ -0,0 +1,49 @@
+// Wires the page to the wasm build in `pkg/`, made by
+// `wasm-pack build playground --target web --out-dir www/pkg -- --features wasm`.
+import init, { solve, renderSvg, renderDays } from "./pkg/playground.js";
+
+const input = document.getElementById("input");
+const day = document.getElementById("day");
+const params = document.getElementById("params");
+const solveButton = document.getElementById("solve");
+const drawButton = document.getElementById("draw");
+const drawPart = document.getElementById("part");
+const answers = document.getElementById("answers");
+const picture = document.getElementById("picture");
+
+function showError(element, error) {
+  element.className = "error";
+  element.textContent = String(error);
+}
+
+await init();
+const drawable = new Set(renderDays());
+
+for (let number = 1; number <= 25; number++) {
+  day.add(new Option(`Day ${number}`, number));
+}
+day.addEventListener("change", () => {
+  drawButton.disabled = !drawable.has(Number(day.value));
+  picture.replaceChildren();
+});
+
+solveButton.addEventListener("click", () => {
+  answers.className = "";
+  answers.textContent = "";
+  try {
+    for (const part of [1, 2]) {
+      answers.textContent += `part ${part}: ${solve(Number(day.value), part, input.value, params.value)}\n`;
+    }
+  } catch (error) {
+    showError(answers, error);
+  }
+});
+
+drawButton.addEventListener("click", () => {
+  picture.className = "";
+  try {
+    picture.innerHTML = renderSvg(Number(day.value), Number(drawPart.value), input.value, params.value, 4);
+  } catch (error) {
+    showError(picture, error);
+  }
+});
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::{Colour, Frame};
+
+/// The frame's pixels row by row, each cell a `scale` by `scale` square.
+fn pixels(frame: &Frame, scale: usize) -> Vec<Colour> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * scale * scale);
+    for row in frame.rows() {
+        let scaled_row: Vec<Colour> = row.iter().flat_map(|colour| std::iter::repeat_n(*colour, scale)).collect();
+        for _ in 0..scale {
+            pixels.extend_from_slice(&scaled_row);
+        }
+    }
+    pixels
+}
+
+pub fn write_png(frame: &Frame, scale: usize, out: impl Write) -> Result<(), String> {
+    let scale = scale.max(1);
+    let encoding_error = |e: png::EncodingError| format!("Error writing PNG: {e}");
+    let mut encoder = png::Encoder::new(out, (frame.width() * scale) as u32, (frame.height() * scale) as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(encoding_error)?;
+    let data: Vec<u8> = pixels(frame, scale).iter().flat_map(|colour| [colour.r, colour.g, colour.b]).collect();
+    writer.write_image_data(&data).map_err(encoding_error)?;
+    writer.finish().map_err(encoding_error)
+}
+
+fn hex(colour: Colour) -> String {
+    format!("#{:02x}{:02x}{:02x}", colour.r, colour.g, colour.b)
+}
+
+/// Writes the frame as one rectangle per run of same-coloured cells along each row.
+pub fn write_svg(frame: &Frame, scale: usize, mut out: impl Write) -> Result<(), String> {
+    let scale = scale.max(1);
+    let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n",
+                          frame.width() * scale, frame.height() * scale, frame.width(), frame.height());
+    for (y, row) in frame.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let run = row[x..].iter().take_while(|colour| **colour == row[x]).count();
+            svg += &format!("<rect x=\"{x}\" y=\"{y}\" width=\"{run}\" height=\"1\" fill=\"{}\"/>\n", hex(row[x]));
+            x += run;
+        }
+    }
+    svg += "</svg>\n";
+    out.write_all(svg.as_bytes()).map_err(|e| format!("Error writing SVG: {e}"))
+}
+
+/// Writes the frames as a looping animation, `delay_ms` apart. Every frame has to be the size
+/// of the first, and together they can use at most 256 colours.
+pub fn write_gif(frames: &[Frame], scale: usize, delay_ms: u32, out: impl Write) -> Result<(), String> {
+    let scale = scale.max(1);
+    let Some(first) = frames.first() else {
+        return Err("No frames to write".to_string());
+    };
+    if frames.iter().any(|frame| (frame.width(), frame.height()) != (first.width(), first.height())) {
+        return Err("Every frame of an animation needs the same size".to_string());
+    }
+    let (width, height) = (first.width() * scale, first.height() * scale);
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(format!("{width}x{height} is too big for a GIF, try a smaller --scale"));
+    };
+
+    let mut palette: Vec<Colour> = Vec::new();
+    let mut indices: HashMap<Colour, u8> = HashMap::new();
+    for colour in frames.iter().flat_map(|frame| frame.rows().flatten()) {
+        if !indices.contains_key(colour) {
+            if palette.len() == 256 {
+                return Err("More than 256 colours in the animation".to_string());
+            }
+            indices.insert(*colour, palette.len() as u8);
+            palette.push(*colour);
+        }
+    }
+
+    let encoding_error = |e: gif::EncodingError| format!("Error writing GIF: {e}");
+    let global_palette: Vec<u8> = palette.iter().flat_map(|colour| [colour.r, colour.g, colour.b]).collect();
+    let mut encoder = gif::Encoder::new(out, gif_width, gif_height, &global_palette).map_err(encoding_error)?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(encoding_error)?;
+    for frame in frames {
+        let indexed: Vec<u8> = pixels(frame, scale).iter().map(|colour| indices[colour]).collect();
+        let mut gif_frame = gif::Frame::from_indexed_pixels(gif_width, gif_height, indexed, None);
+        gif_frame.delay = (delay_ms / 10).min(u16::MAX as u32) as u16;
+        encoder.write_frame(&gif_frame).map_err(encoding_error)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::Point;
+
+    fn checkers() -> Frame {
+        let mut frame = Frame::new(3, 2, Colour::OPEN);
+        frame.set(Point::new(0, 0), Colour::WALL);
+        frame.set(Point::new(2, 1), Colour::PATH);
+        frame
+    }
+
+    #[test]
+    fn png_is_scaled_up() {
+        let mut png = Vec::new();
+        write_png(&checkers(), 4, &mut png).unwrap();
+        assert_eq!(b"\x89PNG", &png[..4]);
+        let info = png::Decoder::new(std::io::Cursor::new(png)).read_info().unwrap().info().clone();
+        assert_eq!((12, 8), (info.width, info.height));
+    }
+
+    #[test]
+    fn svg_merges_runs_of_one_colour() {
+        let mut svg = Vec::new();
+        write_svg(&checkers(), 10, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("width=\"30\" height=\"20\" viewBox=\"0 0 3 2\""));
+        assert_eq!(4, svg.matches("<rect").count());
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"2\" height=\"1\" fill=\"#1e1e37\"/>"));
+    }
+
+    #[test]
+    fn gif_holds_every_frame() {
+        let mut gif = Vec::new();
+        write_gif(&[checkers(), Frame::new(3, 2, Colour::ROBOT)], 2, 100, &mut gif).unwrap();
+        assert_eq!(b"GIF89a", &gif[..6]);
+        let mut decoder = gif::DecodeOptions::new().read_info(std::io::Cursor::new(gif)).unwrap();
+        let mut delays = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((6, 4), (frame.width, frame.height));
+            delays.push(frame.delay);
+        }
+        assert_eq!(vec![10, 10], delays);
+    }
+
+    #[test]
+    fn gif_frames_must_match_in_size() {
+        assert!(write_gif(&[], 1, 100, Vec::new()).is_err());
+        assert!(write_gif(&[checkers(), Frame::new(2, 2, Colour::OPEN)], 1, 100, Vec::new()).is_err());
+        assert!(write_gif(&[Frame::new(1000, 1, Colour::OPEN)], 100, 100, Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use grid::{Grid, Point};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const BACKGROUND: Colour = Colour::rgb(15, 15, 35);
+    pub const WALL: Colour = Colour::rgb(90, 90, 110);
+    pub const OPEN: Colour = Colour::rgb(30, 30, 55);
+    pub const VISITED: Colour = Colour::rgb(60, 120, 200);
+    pub const PATH: Colour = Colour::rgb(255, 255, 102);
+    pub const HIGHLIGHT: Colour = Colour::rgb(230, 60, 60);
+    pub const ROBOT: Colour = Colour::rgb(0, 204, 0);
+    pub const BOX: Colour = Colour::rgb(190, 130, 60);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Colour{r, g, b}
+    }
+
+    /// The colour `fraction` of the way from `self` to `other`.
+    pub fn blend(&self, other: Colour, fraction: f64) -> Colour {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * fraction).round() as u8;
+        Colour::rgb(mix(self.r, other.r), mix(self.g, other.g), mix(self.b, other.b))
+    }
+}
+
+/// A picture of a grid with one colour per cell, scaled up when it's written out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Colour>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Colour) -> Self {
+        Frame{width, height, cells: vec![background; width * height]}
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, mut colour: impl FnMut(&T) -> Colour) -> Self {
+        Frame{width: grid.width(), height: grid.height(), cells: grid.rows().flatten().map(&mut colour).collect()}
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Colours the cell at `point`, ignoring points outside the frame.
+    pub fn set(&mut self, point: Point, colour: Colour) {
+        if point.x < self.width && point.y < self.height {
+            self.cells[point.y * self.width + point.x] = colour;
+        }
+    }
+
+    pub fn get(&self, point: Point) -> Option<Colour> {
+        if point.x < self.width && point.y < self.height { Some(self.cells[point.y * self.width + point.x]) } else { None }
+    }
+
+    pub fn row(&self, y: usize) -> &[Colour] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Colour]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_follow_their_grid() {
+        let grid = Grid::parse_chars("#.\n.#".lines(), "#.").unwrap();
+        let mut frame = Frame::from_grid(&grid, |cell| if *cell == '#' { Colour::WALL } else { Colour::OPEN });
+        assert_eq!((2, 2), (frame.width(), frame.height()));
+        assert_eq!(&[Colour::OPEN, Colour::WALL], frame.row(1));
+        frame.set(Point::new(1, 0), Colour::PATH);
+        frame.set(Point::new(5, 5), Colour::PATH);
+        assert_eq!(Some(Colour::PATH), frame.get(Point::new(1, 0)));
+        assert_eq!(None, frame.get(Point::new(5, 5)));
+    }
+
+    #[test]
+    fn blending_moves_between_colours() {
+        let black = Colour::rgb(0, 0, 0);
+        let white = Colour::rgb(255, 255, 255);
+        assert_eq!(black, black.blend(white, 0.0));
+        assert_eq!(Colour::rgb(128, 128, 128), black.blend(white, 0.5));
+        assert_eq!(white, black.blend(white, 2.0));
+    }
+}
@@ -0,0 +1,34 @@
+mod encode;
+mod frame;
+mod simulation;
+
+pub use encode::{write_gif, write_png, write_svg};
+pub use frame::{Colour, Frame};
+pub use simulation::{Simulation, Timeline, View};
+
+/// The most frames a render should produce, so animations stay a reasonable size.
+pub const MAX_FRAMES: usize = 200;
+
+/// A puzzle that can draw how it gets its answer.
+pub trait Render {
+    /// Frames showing how `part` is solved, in order. The last one is the finished picture.
+    fn render(&self, part: u8) -> Vec<Frame>;
+}
+
+/// How many steps apart frames should be taken so `steps` steps give at most `MAX_FRAMES` frames.
+pub fn frame_interval(steps: usize) -> usize {
+    steps.div_ceil(MAX_FRAMES).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_interval_caps_the_frame_count() {
+        assert_eq!(1, frame_interval(0));
+        assert_eq!(1, frame_interval(MAX_FRAMES));
+        assert_eq!(2, frame_interval(MAX_FRAMES + 1));
+        assert_eq!(50, frame_interval(10_000));
+    }
+}
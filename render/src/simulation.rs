@@ -0,0 +1,145 @@
+use grid::Point;
+
+/// What a simulation shows at one tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct View {
+    /// The map, or program listing, one line per row.
+    pub lines: Vec<String>,
+    /// Where the current actor is in `lines`, to highlight it.
+    pub actor: Option<Point>,
+    /// Labelled values for the state panel, e.g. `("registers", "A=729 B=0 C=0")`.
+    pub panel: Vec<(String, String)>,
+}
+
+/// A puzzle that runs tick by tick, for stepping through in the runner.
+pub trait Simulation {
+    type State: Clone;
+
+    /// The state before the first tick of `part`.
+    fn start(&self, part: u8) -> Self::State;
+
+    /// Advances one tick, returning false (and leaving the state alone) once the simulation has finished.
+    fn step(&self, state: &mut Self::State) -> bool;
+
+    fn view(&self, state: &Self::State) -> View;
+}
+
+/// How many ticks apart the timeline keeps copies of the state to step back from.
+const CHECKPOINT_INTERVAL: usize = 256;
+
+/// A simulation that can be stepped backwards as well as forwards. Stepping back replays from
+/// the nearest earlier checkpoint, so only every `CHECKPOINT_INTERVAL`th state is kept.
+pub struct Timeline<'a, S: Simulation> {
+    simulation: &'a S,
+    checkpoints: Vec<S::State>,
+    state: S::State,
+    tick: usize,
+    /// The last tick, once the simulation has been stepped that far.
+    end: Option<usize>,
+}
+
+impl<'a, S: Simulation> Timeline<'a, S> {
+    pub fn new(simulation: &'a S, part: u8) -> Self {
+        let state = simulation.start(part);
+        Timeline{simulation, checkpoints: vec![state.clone()], state, tick: 0, end: None}
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn end(&self) -> Option<usize> {
+        self.end
+    }
+
+    pub fn view(&self) -> View {
+        self.simulation.view(&self.state)
+    }
+
+    /// Steps forward one tick, returning false at the end.
+    pub fn forward(&mut self) -> bool {
+        if self.end == Some(self.tick) {
+            return false;
+        }
+        if !self.simulation.step(&mut self.state) {
+            self.end = Some(self.tick);
+            return false;
+        }
+        self.tick += 1;
+        if self.tick.is_multiple_of(CHECKPOINT_INTERVAL) && self.checkpoints.len() == self.tick / CHECKPOINT_INTERVAL {
+            self.checkpoints.push(self.state.clone());
+        }
+        true
+    }
+
+    /// Moves to `tick`, or as close as the simulation gets if it finishes first.
+    pub fn seek(&mut self, tick: usize) {
+        if tick < self.tick {
+            let checkpoint = (tick / CHECKPOINT_INTERVAL).min(self.checkpoints.len() - 1);
+            self.state = self.checkpoints[checkpoint].clone();
+            self.tick = checkpoint * CHECKPOINT_INTERVAL;
+        }
+        while self.tick < tick && self.forward() {}
+    }
+
+    pub fn back(&mut self) {
+        self.seek(self.tick.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to a limit, showing the count.
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        type State = usize;
+
+        fn start(&self, part: u8) -> usize {
+            part as usize * 1000
+        }
+
+        fn step(&self, state: &mut usize) -> bool {
+            if *state % 1000 == self.0 { return false; }
+            *state += 1;
+            true
+        }
+
+        fn view(&self, state: &usize) -> View {
+            View{lines: vec![state.to_string()], actor: None, panel: Vec::new()}
+        }
+    }
+
+    #[test]
+    fn timeline_steps_both_ways() {
+        let counter = Counter(600);
+        let mut timeline = Timeline::new(&counter, 1);
+        assert!(timeline.forward());
+        assert_eq!(vec!["1001".to_string()], timeline.view().lines);
+        timeline.seek(550);
+        assert_eq!((550, vec!["1550".to_string()]), (timeline.tick(), timeline.view().lines));
+        timeline.back();
+        assert_eq!((549, vec!["1549".to_string()]), (timeline.tick(), timeline.view().lines));
+        timeline.seek(3);
+        assert_eq!(vec!["1003".to_string()], timeline.view().lines);
+        timeline.back();
+        timeline.back();
+        timeline.back();
+        timeline.back();
+        assert_eq!((0, vec!["1000".to_string()]), (timeline.tick(), timeline.view().lines));
+    }
+
+    #[test]
+    fn timeline_stops_at_the_end() {
+        let counter = Counter(10);
+        let mut timeline = Timeline::new(&counter, 2);
+        assert_eq!(None, timeline.end());
+        timeline.seek(100);
+        assert_eq!((10, Some(10)), (timeline.tick(), timeline.end()));
+        assert!(!timeline.forward());
+        timeline.back();
+        assert_eq!(vec!["2009".to_string()], timeline.view().lines);
+    }
+}
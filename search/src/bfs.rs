@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use crate::path::{reconstruct, Path};
+
+/// The fewest steps from `start` to a state `is_goal` accepts, where every step costs one.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::<S, S>::new();
+    let mut steps_to_go = VecDeque::<(S, usize)>::new();
+    let mut seen = HashSet::<S>::new();
+    seen.insert(start.clone());
+    steps_to_go.push_back((start, 0));
+
+    while let Some((state, steps)) = steps_to_go.pop_front() {
+        if is_goal(&state) {
+            return Some(Path{cost: steps, states: reconstruct(&parents, state)});
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                steps_to_go.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// The fewest steps from `start` to every state reachable from it.
+pub fn bfs_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::<S, usize>::new();
+    let mut steps_to_go = VecDeque::<S>::new();
+    distances.insert(start.clone(), 0);
+    steps_to_go.push_back(start);
+
+    while let Some(state) = steps_to_go.pop_front() {
+        let steps = distances[&state];
+        for next in successors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                steps_to_go.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A number line where each state can step one up, or jump to double itself.
+    fn up_or_double(state: &u32) -> Vec<u32> {
+        if *state > 100 { vec![] } else { vec![state + 1, state * 2] }
+    }
+
+    #[test]
+    fn finds_the_fewest_steps() {
+        let path = bfs(1, up_or_double, |state| *state == 10).unwrap();
+        assert_eq!(4, path.cost);
+        assert_eq!(vec![1, 2, 4, 5, 10], path.states);
+        assert_eq!(None, bfs(1, |_| Vec::new(), |state| *state == 10));
+    }
+
+    #[test]
+    fn measures_distance_to_everything_reachable() {
+        let distances = bfs_distances(1, up_or_double);
+        assert_eq!(Some(&0), distances.get(&1));
+        assert_eq!(Some(&4), distances.get(&10));
+        assert_eq!(None, distances.get(&0));
+    }
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::path::{reconstruct, Path};
+
+/// A state waiting to be searched from, ordered so the heap pops the lowest priority first.
+struct Frontier<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Frontier<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Frontier<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Frontier<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Frontier<S, C> {}
+
+/// The cheapest path from `start` to a state `is_goal` accepts, where `successors` gives each
+/// next state along with the cost of stepping to it.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// As `dijkstra`, but searching first from the states `heuristic` estimates are closest to a
+/// goal. The estimate must never be more than the real remaining cost for the path to be cheapest.
+pub fn astar<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, mut heuristic: impl FnMut(&S) -> C, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best_costs = HashMap::<S, C>::new();
+    let mut parents = HashMap::<S, S>::new();
+    let mut frontier = BinaryHeap::<Frontier<S, C>>::new();
+    best_costs.insert(start.clone(), C::default());
+    frontier.push(Frontier{priority: heuristic(&start), cost: C::default(), state: start});
+
+    while let Some(Frontier{cost, state, ..}) = frontier.pop() {
+        if cost > best_costs[&state] {
+            continue; // a cheaper way here was already searched from
+        }
+        if is_goal(&state) {
+            return Some(Path{cost, states: reconstruct(&parents, state)});
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if best_costs.get(&next).is_none_or(|best| next_cost < *best) {
+                best_costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                frontier.push(Frontier{priority: next_cost + heuristic(&next), cost: next_cost, state: next});
+            }
+        }
+    }
+    None
+}
+
+/// Every state on at least one of the cheapest paths to a goal, and what those paths cost.
+#[derive(Debug, Clone)]
+pub struct OptimalStates<S, C> {
+    pub cost: C,
+    pub states: HashSet<S>,
+}
+
+/// Like `dijkstra`, but rather than one cheapest path, finds all the states that any of the
+/// equally cheapest paths pass through, the start and goals included.
+pub fn all_optimal_states<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<OptimalStates<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut best_costs = HashMap::<S, C>::new();
+    // every state a cheapest way into each state comes from
+    let mut parents = HashMap::<S, Vec<S>>::new();
+    let mut frontier = BinaryHeap::<Frontier<S, C>>::new();
+    let mut goal_cost: Option<C> = None;
+    let mut goals = Vec::<S>::new();
+    best_costs.insert(start.clone(), C::default());
+    frontier.push(Frontier{priority: C::default(), cost: C::default(), state: start});
+
+    while let Some(Frontier{cost, state, ..}) = frontier.pop() {
+        if cost > best_costs[&state] {
+            continue;
+        }
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break; // everything left is more expensive than the goals already reached
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match best_costs.get(&next) {
+                Some(best) if next_cost > *best => {},
+                Some(best) if next_cost == *best => { parents.entry(next).or_default().push(state.clone()); },
+                _ => {
+                    best_costs.insert(next.clone(), next_cost);
+                    parents.insert(next.clone(), vec![state.clone()]);
+                    frontier.push(Frontier{priority: next_cost, cost: next_cost, state: next});
+                }
+            }
+        }
+    }
+
+    let cost = goal_cost?;
+    let mut states = HashSet::<S>::new();
+    while let Some(state) = goals.pop() {
+        if let Some(state_parents) = parents.get(&state) {
+            goals.extend(state_parents.iter().filter(|parent| !states.contains(*parent)).cloned());
+        }
+        states.insert(state);
+    }
+    Some(OptimalStates{cost, states})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Points on a 5x5 grid where moving right or down costs 1, except moving right into the
+    // middle column below the top row, which costs 10.
+    fn moves(&(x, y): &(u32, u32)) -> Vec<((u32, u32), u32)> {
+        let mut next = Vec::new();
+        if x < 4 { next.push(((x + 1, y), if x + 1 == 2 && y > 0 { 10 } else { 1 })); }
+        if y < 4 { next.push(((x, y + 1), 1)); }
+        next
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let path = dijkstra((0, 0), moves, |state| *state == (4, 4)).unwrap();
+        assert_eq!(8, path.cost);
+        assert_eq!((&(0, 0), &(4, 4)), (path.start(), path.goal()));
+        assert!(path.states.contains(&(2, 0)), "the only cheap way past the wall is along the top: {:?}", path.states);
+        assert_eq!(None, dijkstra((4, 4), moves, |state| *state == (0, 0)));
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let manhattan = |&(x, y): &(u32, u32)| (4 - x) + (4 - y);
+        let path = astar((0, 0), moves, manhattan, |state| *state == (4, 4)).unwrap();
+        assert_eq!(8, path.cost);
+        assert_eq!(9, path.states.len());
+    }
+
+    #[test]
+    fn optimal_states_cover_every_cheapest_path() {
+        let optimal = all_optimal_states((0, 0), moves, |state| *state == (4, 4)).unwrap();
+        assert_eq!(8, optimal.cost);
+        // right along the top past the wall, then any of the ways through the block beyond it
+        let expected: HashSet<(u32, u32)> = [(0, 0), (1, 0)].into_iter()
+            .chain((2..5).flat_map(|x| (0..5).map(move |y| (x, y))))
+            .collect();
+        assert_eq!(expected, optimal.states);
+    }
+}
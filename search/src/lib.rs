@@ -0,0 +1,9 @@
+//! Searches over any state type, given a function listing the states reachable from each one.
+
+mod bfs;
+mod dijkstra;
+mod path;
+
+pub use bfs::{bfs, bfs_distances};
+pub use dijkstra::{all_optimal_states, astar, dijkstra, OptimalStates};
+pub use path::{count_paths, Path};
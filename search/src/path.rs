@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A route found by a search, from its start state to the goal it reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state along the way, the start first and the goal last.
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        self.states.first().expect("a path has at least its start")
+    }
+
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("a path has at least its start")
+    }
+}
+
+/// Walks back from `goal` to the state with no parent, the start of the search.
+pub(crate) fn reconstruct<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(parent) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+/// How many distinct paths lead from `start` to a goal. Only meant for graphs without cycles,
+/// like trails that always climb; a goal state ends a path rather than being passed through.
+pub fn count_paths<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> usize
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    fn count_from<S: Clone + Eq + Hash, I: IntoIterator<Item = S>>(
+        state: &S,
+        successors: &mut impl FnMut(&S) -> I,
+        is_goal: &mut impl FnMut(&S) -> bool,
+        counted: &mut HashMap<S, usize>,
+    ) -> usize {
+        if is_goal(state) {
+            return 1;
+        }
+        if let Some(count) = counted.get(state) {
+            return *count;
+        }
+        let mut count = 0;
+        for next in successors(state) {
+            count += count_from(&next, successors, is_goal, counted);
+        }
+        counted.insert(state.clone(), count);
+        count
+    }
+
+    count_from(&start, &mut successors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstructs_from_the_goal_back_to_the_start() {
+        let parents = HashMap::from([(3, 2), (2, 1)]);
+        let path = Path{cost: 2, states: reconstruct(&parents, 3)};
+        assert_eq!(vec![1, 2, 3], path.states);
+        assert_eq!((&1, &3), (path.start(), path.goal()));
+    }
+
+    #[test]
+    fn counts_every_way_through_a_diamond() {
+        // 0 splits to 1 and 2, which both join at 3, which splits again to the goals 4 and 5
+        let edges = |state: &u8| -> Vec<u8> {
+            match state {
+                0 => vec![1, 2],
+                1 | 2 => vec![3],
+                3 => vec![4, 5],
+                _ => vec![],
+            }
+        };
+        assert_eq!(4, count_paths(0, edges, |state| *state >= 4));
+        assert_eq!(2, count_paths(0, edges, |state| *state == 4));
+        assert_eq!(0, count_paths(4, edges, |state| *state == 0));
+    }
+}
@@ -0,0 +1,8 @@
+//! Everything a day needs besides its own code, for any year, behind one dependency: the
+//! `Solution` trait and input parsing from `common`, plus the `grid`, `search` and `render`
+//! crates as `utils::grid` and so on. New days depend on this rather than on each of them.
+
+pub use common::*;
+pub use grid;
+pub use render;
+pub use search;
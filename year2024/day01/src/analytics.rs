@@ -0,0 +1,161 @@
+//! More than the one similarity score from the counts of each ID in the two lists: which IDs
+//! they share and which they don't, how alike they are, and how the counts changed between two
+//! snapshots of the lists.
+
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::{Day01p2, Overflow};
+
+/// An ID on both lists, and how much it adds to the similarity score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shared {
+    pub id: i64,
+    pub left: u64,
+    pub right: u64,
+    pub similarity: i128,
+}
+
+/// How many times an ID is on each list, either of which may be none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    pub id: i64,
+    pub left: u64,
+    pub right: u64,
+}
+
+/// An ID whose counts differ between two snapshots of the lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    pub before: Counts,
+    pub after: Counts,
+}
+
+impl Day01p2 {
+    fn left_count(&self, id: i64) -> u64 {
+        self.left.get(&id).copied().unwrap_or(0)
+    }
+
+    fn right_count(&self, id: i64) -> u64 {
+        self.right.get(&id).copied().unwrap_or(0)
+    }
+
+    fn ids(&self) -> BTreeSet<i64> {
+        self.left.keys().chain(self.right.keys()).copied().collect()
+    }
+
+    pub fn counts(&self, id: i64) -> Counts {
+        Counts{id, left: self.left_count(id), right: self.right_count(id)}
+    }
+
+    /// The `k` IDs adding the most to the similarity score, the smaller ID first on a tie.
+    pub fn top_shared(&self, k: usize) -> Result<Vec<Shared>, Overflow> {
+        let mut shared = Vec::new();
+        for (id, left) in &self.left {
+            if let Some(right) = self.right.get(id) {
+                shared.push(Shared{id: *id, left: *left, right: *right, similarity: Self::similarity(*id, *left, *right)?});
+            }
+        }
+        shared.sort_by_key(|shared| (std::cmp::Reverse(shared.similarity), shared.id));
+        shared.truncate(k);
+        Ok(shared)
+    }
+
+    /// The IDs only on the left list, smallest first.
+    pub fn only_left(&self) -> Vec<i64> {
+        let mut ids: Vec<i64> = self.left.keys().filter(|id| !self.right.contains_key(id)).copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// The IDs only on the right list, smallest first.
+    pub fn only_right(&self) -> Vec<i64> {
+        let mut ids: Vec<i64> = self.right.keys().filter(|id| !self.left.contains_key(id)).copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// The IDs on both lists over the IDs on either, ignoring how many times each is listed.
+    /// Two empty lists are the same, so 1.
+    pub fn jaccard(&self) -> f64 {
+        let union = self.ids().len();
+        if union == 0 {
+            return 1.0;
+        }
+        let intersection = self.left.keys().filter(|id| self.right.contains_key(id)).count();
+        intersection as f64 / union as f64
+    }
+
+    /// How many entries the lists have in common, an ID listed twice on one side and three
+    /// times on the other counting twice.
+    pub fn multiset_intersection(&self) -> u64 {
+        self.left.iter().map(|(id, left)| (*left).min(self.right_count(*id))).sum()
+    }
+
+    /// Every ID on either list with its counts, smallest first.
+    pub fn histogram(&self) -> Vec<Counts> {
+        self.ids().into_iter().map(|id| self.counts(id)).collect()
+    }
+
+    /// The histogram as CSV, with an `id,left,right` header.
+    pub fn write_histogram(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "id,left,right")?;
+        for counts in self.histogram() {
+            writeln!(out, "{},{},{}", counts.id, counts.left, counts.right)?;
+        }
+        out.flush()
+    }
+
+    /// The IDs whose counts are different in `later`, a newer snapshot of the lists, smallest first.
+    pub fn diff(&self, later: &Day01p2) -> Vec<Change> {
+        let ids: BTreeSet<i64> = self.ids().into_iter().chain(later.ids()).collect();
+        ids.into_iter()
+            .map(|id| Change{before: self.counts(id), after: later.counts(id)})
+            .filter(|change| change.before != change.after)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+    #[test]
+    fn shared_and_unshared_ids() {
+        let day = Day01p2::new(SAMPLE.lines()).unwrap();
+        assert_eq!(Ok(vec![Shared{id: 3, left: 3, right: 3, similarity: 27}, Shared{id: 4, left: 1, right: 1, similarity: 4}]), day.top_shared(5));
+        assert_eq!(1, day.top_shared(1).unwrap().len());
+        assert_eq!(day.run(), Ok(day.top_shared(usize::MAX).unwrap().iter().map(|shared| shared.similarity).sum()));
+        assert_eq!(vec![1, 2], day.only_left());
+        assert_eq!(vec![5, 9], day.only_right());
+    }
+
+    #[test]
+    fn how_alike_the_lists_are() {
+        let day = Day01p2::new(SAMPLE.lines()).unwrap();
+        // 3 and 4 of 1, 2, 3, 4, 5 and 9
+        assert_eq!(2.0 / 6.0, day.jaccard());
+        assert_eq!(4, day.multiset_intersection());
+        assert_eq!(1.0, Day01p2::new("".lines()).unwrap().jaccard());
+        assert_eq!(1.0, Day01p2::new("7 7\n8 8".lines()).unwrap().jaccard());
+    }
+
+    #[test]
+    fn exports_the_histogram() {
+        let day = Day01p2::new(SAMPLE.lines()).unwrap();
+        let mut csv = Vec::new();
+        day.write_histogram(&mut csv).unwrap();
+        assert_eq!("id,left,right\n1,1,0\n2,1,0\n3,3,3\n4,1,1\n5,0,1\n9,0,1\n", String::from_utf8(csv).unwrap());
+    }
+
+    #[test]
+    fn diffs_two_snapshots() {
+        let before = Day01p2::new(SAMPLE.lines()).unwrap();
+        let after = Day01p2::new("3   4\n4   3\n2   5\n1   3\n3   9\n6   3\n".lines()).unwrap();
+        assert_eq!(vec![Change{before: Counts{id: 3, left: 3, right: 3}, after: Counts{id: 3, left: 2, right: 3}},
+                        Change{before: Counts{id: 6, left: 0, right: 0}, after: Counts{id: 6, left: 1, right: 0}}], before.diff(&after));
+        assert!(before.diff(&before).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+//! Lists of location IDs side by side, however many there are, one column per list.
+
+use std::io::BufRead;
+
+use common::{for_each_line, InputLines, Line, ParseError, ReadError};
+
+/// Whether the first line names the columns rather than holding the first IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Header {
+    Absent,
+    Present,
+    /// The first line names the columns if there are no IDs on it.
+    Detect,
+}
+
+/// Every column of IDs, in the order they were read down the input. The first line sets how
+/// many columns there are, at least two, and every line after has to have the same number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    names: Vec<String>,
+    values: Vec<Vec<i64>>,
+    header: Header,
+    // set once the names have come from a line of the input
+    named: bool,
+}
+
+impl Columns {
+    fn empty(header: Header) -> Self {
+        Columns{names: Vec::new(), values: Vec::new(), header, named: false}
+    }
+
+    pub fn parse(lines: std::str::Lines<'_>, header: Header) -> Result<Self, ParseError> {
+        let mut columns = Columns::empty(header);
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            columns.add_line(&line)?;
+        }
+        Ok(columns)
+    }
+
+    pub fn from_reader(reader: impl BufRead, header: Header) -> Result<Self, ReadError> {
+        let mut columns = Columns::empty(header);
+        for_each_line(reader, |line| columns.add_line(&line))?;
+        Ok(columns)
+    }
+
+    pub fn add_line(&mut self, line: &Line<'_>) -> Result<(), ParseError> {
+        let awaiting_header = self.names.is_empty() && match self.header {
+            Header::Absent => false,
+            Header::Present => true,
+            Header::Detect => line.text.split_whitespace().all(|field| field.parse::<i64>().is_err()),
+        };
+        if awaiting_header {
+            self.named = true;
+            self.names = line.text.split_whitespace().map(String::from).collect();
+            if self.names.len() < 2 {
+                return Err(line.missing("a name for each of at least two columns"));
+            }
+            self.values = vec![Vec::new(); self.names.len()];
+            return Ok(());
+        }
+        let mut fields = line.text.split_whitespace();
+        if self.values.is_empty() {
+            // the first line of IDs sets the number of columns, named 1, 2 and so on
+            let ids = fields.map(|field| line.parse(field, "a location ID")).collect::<Result<Vec<i64>, _>>()?;
+            if ids.len() < 2 {
+                return Err(line.missing("a location ID"));
+            }
+            self.names = (1..=ids.len()).map(|number| number.to_string()).collect();
+            self.values = ids.into_iter().map(|id| vec![id]).collect();
+            return Ok(());
+        }
+        let mut ids = Vec::with_capacity(self.values.len());
+        for _ in 0..self.values.len() {
+            ids.push(line.field(&mut fields, "a location ID")?);
+        }
+        line.end(&mut fields)?;
+        for (column, id) in self.values.iter_mut().zip(ids) {
+            column.push(id);
+        }
+        Ok(())
+    }
+
+    /// How many columns there are, or none before any line has been read.
+    pub fn width(&self) -> usize {
+        self.values.len()
+    }
+
+    /// How many IDs are in each column.
+    pub fn len(&self) -> usize {
+        self.values.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn name(&self, column: usize) -> &str {
+        &self.names[column]
+    }
+
+    /// The IDs in `column`, or `None` if there aren't that many columns.
+    pub fn column(&self, column: usize) -> Option<&[i64]> {
+        self.values.get(column).map(Vec::as_slice)
+    }
+
+    /// Each column in turn.
+    pub fn iter(&self) -> impl Iterator<Item = &[i64]> {
+        self.values.iter().map(Vec::as_slice)
+    }
+
+    /// Whether the names came from a line of the input rather than being numbered.
+    pub fn has_header(&self) -> bool {
+        self.named
+    }
+
+    /// Takes the first line of IDs as the columns' names instead, for a header line of numbers.
+    /// Nothing changes if the names already came from the input.
+    pub fn first_line_as_header(&mut self) {
+        if self.named || self.is_empty() {
+            return;
+        }
+        self.names = self.values.iter_mut().map(|column| column.remove(0).to_string()).collect();
+        self.named = true;
+    }
+}
+
+impl Default for Columns {
+    fn default() -> Self {
+        Columns::empty(Header::Absent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_first_line_sets_the_width() {
+        let columns = Columns::parse("3 4 5\n4 3 1\n\n2 5 7".lines(), Header::Absent).unwrap();
+        assert_eq!((3, 3), (columns.width(), columns.len()));
+        assert_eq!(Some([5, 1, 7].as_slice()), columns.column(2));
+        assert_eq!(None, columns.column(3));
+        assert_eq!("2", columns.name(1));
+        assert_eq!(Some(ParseError::new(2, 4, "a location ID", "")), Columns::parse("3 4 5\n4 3".lines(), Header::Absent).err());
+        assert_eq!(Some(ParseError::new(2, 7, "end of line", "9")), Columns::parse("3 4 5\n4 3 1 9".lines(), Header::Absent).err());
+        assert_eq!(Some(ParseError::new(1, 2, "a location ID", "")), Columns::parse("3\n4".lines(), Header::Absent).err());
+    }
+
+    #[test]
+    fn a_header_names_the_columns() {
+        let input = "north  south  east\n3 4 5\n4 3 1\n";
+        let columns = Columns::from_reader(input.as_bytes(), Header::Present).unwrap();
+        assert_eq!(("east", 2), (columns.name(2), columns.len()));
+        assert_eq!(Columns::parse(input.lines(), Header::Present).unwrap(), columns);
+        assert_eq!(Some(ParseError::new(1, 1, "a location ID", "north")), Columns::parse(input.lines(), Header::Absent).err());
+        assert_eq!(Some(ParseError::new(1, 6, "a name for each of at least two columns", "")), Columns::parse("north\n3".lines(), Header::Present).err());
+    }
+
+    #[test]
+    fn a_line_without_ids_is_taken_for_a_header() {
+        let columns = Columns::parse("north  south\n3 4\n".lines(), Header::Detect).unwrap();
+        assert_eq!(("south", 1, true), (columns.name(1), columns.len(), columns.has_header()));
+        // a line with an ID on it is read as IDs, so a typo is still an error
+        assert_eq!(Some(ParseError::new(1, 3, "a location ID", "x4")), Columns::parse("3 x4\n".lines(), Header::Detect).err());
+        let mut columns = Columns::parse("2023 2024\n3 4\n".lines(), Header::Detect).unwrap();
+        assert!(!columns.has_header());
+        columns.first_line_as_header();
+        assert_eq!(("2024", Some([4].as_slice())), (columns.name(1), columns.column(1)));
+    }
+}
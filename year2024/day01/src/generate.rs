@@ -0,0 +1,25 @@
+//! Made-up inputs for day 1: two columns of location IDs, some shared between the lists.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` pairs of location IDs, with about a third of the right hand IDs also on the left.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let left: Vec<u64> = (0..size.max(1)).map(|_| rng.range(10000, 99999)).collect();
+    lines(left.iter().map(|a| {
+        let b = if rng.chance(30) { rng.pick(&left) } else { rng.range(10000, 99999) };
+        format!("{a}   {b}")
+    })).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day01p2;
+
+    #[test]
+    fn generates_a_pair_per_line() {
+        let synthetic = generate(&mut Rng::new(1), 50);
+        assert_eq!(50, synthetic.input.lines().count());
+        assert!(Day01p2::new(synthetic.input.lines()).unwrap().run().unwrap() > 0);
+    }
+}
@@ -0,0 +1,332 @@
+pub mod analytics;
+pub mod columns;
+pub mod generate;
+pub mod metric;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::BufRead;
+
+use common::{parse_param, Answer, Explain, Explanation, FromReader, ParamError, ParseError, ReadError, Solution};
+
+use columns::{Columns, Header};
+use metric::Metric;
+
+/// A total too big for even an `i128`, which IDs far enough apart, or listed often enough, can
+/// add up to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow(pub &'static str);
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the {} overflowed", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// A total as the answer to a part, or the overflow that stopped it being worked out.
+fn total_or_error(total: Result<i128, Overflow>) -> Answer {
+    total.map_or_else(|e| Answer::Error(e.to_string()), Answer::Int)
+}
+
+/// How far apart two of the columns are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub left: usize,
+    pub right: usize,
+    pub distance: i128,
+}
+
+#[derive(Debug, Default)]
+pub struct Day01 {
+    columns: Columns,
+    // how part 1 compares the columns
+    metric: Metric,
+}
+
+impl Day01 {
+    /// A first line with no IDs on it names the columns; the `header` param can say a line of
+    /// numbers does.
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        Ok(Day01{columns: Columns::parse(lines, Header::Detect)?, metric: Metric::default()})
+    }
+
+    pub fn columns(&self) -> &Columns {
+        &self.columns
+    }
+
+    /// Every pair of columns measured with `metric`: the first with the second, the first with
+    /// the third, and so on, then the second with the third.
+    pub fn compare(&self, metric: Metric) -> Result<Vec<Comparison>, Overflow> {
+        let mut comparisons = Vec::new();
+        for (left, first) in self.columns.iter().enumerate() {
+            for (right, second) in self.columns.iter().enumerate().skip(left + 1) {
+                let distance = metric.distance(first, second)?;
+                comparisons.push(Comparison{left, right, distance});
+            }
+        }
+        Ok(comparisons)
+    }
+
+    /// The distances between every pair of columns added up. With the puzzle's two columns and
+    /// the default metric, that's the total distance between the lists.
+    pub fn run(&self) -> Result<i128, Overflow> {
+        self.compare(self.metric)?.iter()
+            .try_fold(0i128, |total, comparison| total.checked_add(comparison.distance))
+            .ok_or(Overflow("total distance"))
+    }
+}
+
+pub struct Day01p2 {
+    left: HashMap<i64, u64>,
+    right: HashMap<i64, u64>,
+}
+
+impl Day01p2 {
+    fn update_count(map: &mut HashMap<i64, u64>, value: i64) {
+        let stat = map.entry(value).or_insert(0);
+        *stat += 1;
+    }
+
+    /// Reads the columns as part 1 does, header and all.
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        Ok(Self::from_day01(&Day01::new(lines)?))
+    }
+
+    /// The similarity of the first two columns, with nothing to compare if there aren't two.
+    pub fn from_day01(day: &Day01) -> Self {
+        let mut left = HashMap::<i64, u64>::new();
+        let mut right = HashMap::<i64, u64>::new();
+        if let (Some(first), Some(second)) = (day.columns.column(0), day.columns.column(1)) {
+            for a in first {
+                Self::update_count(&mut left, *a);
+            }
+            for b in second {
+                Self::update_count(&mut right, *b);
+            }
+        }
+        Day01p2{left, right}
+    }
+
+    /// How much an ID listed `left` and `right` times adds to the similarity score.
+    fn similarity(id: i64, left: u64, right: u64) -> Result<i128, Overflow> {
+        (id as i128).checked_mul(left as i128).and_then(|score| score.checked_mul(right as i128)).ok_or(Overflow("similarity score"))
+    }
+
+    pub fn run(&self) -> Result<i128, Overflow> {
+        let mut similarity_score: i128 = 0;
+        for (key, value) in &self.left {
+            if let Some(a) = self.right.get(key) {
+                similarity_score = similarity_score.checked_add(Self::similarity(*key, *value, *a)?).ok_or(Overflow("similarity score"))?;
+            }
+        }
+        Ok(similarity_score)
+    }
+}
+
+impl Explain for Day01 {
+    /// For part 1 the distance between each pair of columns, for part 2 how much each ID on both
+    /// lists adds to the similarity, the most first. Nothing if a total overflows, as the answer
+    /// is the error that says so.
+    fn explain(&self, part: u8) -> Explanation {
+        if part == 1 {
+            let mut explanation = Explanation::new(&["columns", "metric", "distance"]);
+            for comparison in self.compare(self.metric).unwrap_or_default() {
+                let columns = format!("{} and {}", self.columns.name(comparison.left), self.columns.name(comparison.right));
+                explanation.row(vec![columns, self.metric.to_string(), comparison.distance.to_string()]);
+            }
+            return explanation;
+        }
+        let mut explanation = Explanation::new(&["location ID", "times on the left", "times on the right", "similarity"]);
+        for shared in Day01p2::from_day01(self).top_shared(usize::MAX).unwrap_or_default() {
+            explanation.row(vec![shared.id.to_string(), shared.left.to_string(), shared.right.to_string(), shared.similarity.to_string()]);
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day01 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day01{columns: Columns::from_reader(reader, Header::Detect)?, metric: Metric::default()})
+    }
+}
+
+impl Solution<'_> for Day01 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day01::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        total_or_error(self.run())
+    }
+
+    fn part2(&self) -> Answer {
+        total_or_error(Day01p2::from_day01(self).run())
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "metric" => { self.metric = parse_param(name, value)?; },
+            "header" => match parse_param::<bool>(name, value)? {
+                true => self.columns.first_line_as_header(),
+                // a header line read from the input can't be taken back as IDs
+                false if self.columns.has_header() => {
+                    return Err(ParamError::Invalid{name: name.to_string(), value: value.to_string()});
+                },
+                false => {},
+            },
+            _ => { return Err(ParamError::Unknown(name.to_string())); }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Comparison, Day01, Day01p2, Overflow};
+    use crate::metric::Metric;
+    use common::{Answer, Explain, FromReader, ParamError, ParseError, ReadError, Solution};
+    const SAMPLE: &str =
+"3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    #[test]
+    fn day01_sample_1() {
+        let day01 = Day01::new(SAMPLE.lines()).unwrap();
+        assert_eq!(Ok(11), day01.run());
+    }
+
+    #[test]
+    fn day01p2_sample_1() {
+        let day01p2 = Day01p2::new(SAMPLE.lines()).unwrap();
+        assert_eq!(Ok(31), day01p2.run());
+    }
+
+    #[test]
+    fn solution_answers_both_parts() {
+        let day01 = Day01::parse(SAMPLE).unwrap();
+        assert_eq!(Answer::Int(11), Solution::part1(&day01));
+        assert_eq!(Answer::Int(31), Solution::part2(&day01));
+    }
+
+    #[test]
+    fn compares_every_pair_of_columns() {
+        let day01 = Day01::new("a b c\n3 4 3\n4 3 4\n2 5 2\n".lines()).unwrap();
+        assert_eq!(vec![Comparison{left: 0, right: 1, distance: 3}, Comparison{left: 0, right: 2, distance: 0}, Comparison{left: 1, right: 2, distance: 3}],
+                   day01.compare(Metric::Absolute).unwrap());
+        assert_eq!(Ok(6), day01.run());
+        let explanation = day01.explain(1);
+        assert_eq!(vec!["a and b", "absolute", "3"], explanation.rows[0]);
+        assert_eq!(3, explanation.rows.len());
+    }
+
+    #[test]
+    fn the_metric_is_a_param() {
+        let mut day01 = Day01::parse(SAMPLE).unwrap();
+        day01.configure("metric", "squared").unwrap();
+        assert_eq!(Answer::Int(35), Solution::part1(&day01));
+        assert_eq!(Answer::Int(31), Solution::part2(&day01));
+        assert_eq!(Err(ParamError::Invalid{name: "metric".to_string(), value: "cosine".to_string()}), day01.configure("metric", "cosine"));
+    }
+
+    #[test]
+    fn the_header_is_a_param() {
+        let mut day01 = Day01::parse("2023  2024\n3 4\n4 3\n").unwrap();
+        // read as IDs, the 2023 and 2024 are paired off a year apart
+        assert_eq!(Answer::Int(1), Solution::part1(&day01));
+        day01.configure("header", "true").unwrap();
+        assert_eq!(Answer::Int(0), Solution::part1(&day01));
+        assert_eq!("2023 and 2024", day01.explain(1).rows[0][0]);
+        let mut named = Day01::parse("left  right\n3 4\n4 3\n").unwrap();
+        assert_eq!(Answer::Int(0), Solution::part1(&named));
+        assert_eq!(Ok(()), named.configure("header", "true"));
+        assert_eq!(Err(ParamError::Invalid{name: "header".to_string(), value: "false".to_string()}), named.configure("header", "false"));
+    }
+
+    #[test]
+    fn both_parts_read_the_same_columns() {
+        const HEADED: &str = "a b c\n3 4 3\n4 3 4\n4 4 2\n";
+        let day01 = Day01::parse(HEADED).unwrap();
+        // the first two columns each list 3 once and 4 twice
+        assert_eq!(Answer::Int(3 + 4 * 2 * 2), Solution::part2(&day01));
+        assert_eq!(Ok(3 + 4 * 2 * 2), Day01p2::new(HEADED.lines()).unwrap().run());
+        assert_eq!(Answer::Int(4), Solution::part1(&day01));
+
+        let mut numbered = Day01::parse("1 2 3\n3 4 3\n4 3 4\n").unwrap();
+        numbered.configure("header", "true").unwrap();
+        assert_eq!((Answer::Int(0), Answer::Int(3 + 4)), (Solution::part1(&numbered), Solution::part2(&numbered)));
+    }
+
+    #[test]
+    fn no_input_has_nothing_to_compare() {
+        for input in ["", "\n\n", "  \r\n\t\n"] {
+            let day01 = Day01::parse(input).unwrap();
+            assert_eq!((Answer::Int(0), Answer::Int(0)), (Solution::part1(&day01), Solution::part2(&day01)), "{input:?}");
+            assert!(day01.explain(1).rows.is_empty() && day01.explain(2).rows.is_empty());
+            let streamed = Day01::from_reader(input.as_bytes()).unwrap();
+            assert_eq!(Answer::Int(0), Solution::part2(&streamed));
+        }
+        // a header alone names the columns but lists no IDs
+        let day01 = Day01::parse("left  right\n").unwrap();
+        assert_eq!((Answer::Int(0), Answer::Int(0)), (Solution::part1(&day01), Solution::part2(&day01)));
+    }
+
+    #[test]
+    fn explains_the_similarity_of_each_shared_id() {
+        let explanation = Day01::parse(SAMPLE).unwrap().explain(2);
+        assert_eq!(vec![vec!["3", "3", "3", "27"], vec!["4", "1", "1", "4"]], explanation.rows);
+    }
+
+    #[test]
+    fn totals_past_32_bits() {
+        let input = "3000000000   1\n4000000000   2000000000\n2000000000   2000000000\n2000000000   5\n";
+        let day01 = Day01::parse(input).unwrap();
+        // 3e9-5 + 4e9-2e9 + 2e9-1 + 2e9-2e9, sorted 2e9 2e9 3e9 4e9 against 1 5 2e9 2e9
+        assert_eq!(Answer::Int(2_000_000_000 - 1 + 2_000_000_000 - 5 + 1_000_000_000 + 2_000_000_000), Solution::part1(&day01));
+        assert_eq!(Answer::Int(2_000_000_000 * 2 * 2), Solution::part2(&day01));
+        assert!(Day01::parse("9223372036854775807   -9223372036854775808").is_ok());
+        assert_eq!(Some(ParseError::new(1, 1, "a location ID", "9223372036854775808")), Day01::parse("9223372036854775808   1").err());
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert_eq!(Err(Overflow("similarity score")), Day01p2::similarity(i64::MAX, u64::MAX, 2));
+        assert_eq!(Ok(i64::MAX as i128 * u32::MAX as i128), Day01p2::similarity(i64::MAX, u32::MAX as u64, 1));
+        let mut day01 = Day01::parse("-9223372036854775808   9223372036854775807\n".repeat(3).as_str()).unwrap();
+        day01.configure("metric", "squared").unwrap();
+        assert_eq!(Err(Overflow("total distance")), day01.run());
+        day01.configure("metric", "absolute").unwrap();
+        assert_eq!(Ok(3 * u64::MAX as i128), day01.run());
+    }
+
+    #[test]
+    fn the_solution_answers_an_overflow_with_the_error() {
+        let mut day01 = Day01::parse("-9223372036854775808   9223372036854775807\n".repeat(3).as_str()).unwrap();
+        day01.configure("metric", "squared").unwrap();
+        assert_eq!(Answer::Error("the total distance overflowed".to_string()), Solution::part1(&day01));
+        assert!(day01.explain(1).rows.is_empty());
+        assert_eq!(Answer::Int(0), Solution::part2(&day01));
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 2, "a location ID", "")), Day01::new("3   4\n4   \n".lines()).err());
+        assert_eq!(Some(ParseError::new(3, 5, "a location ID", "x5")), Day01::new("3   4\n4   3\n2   x5".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 2, "a location ID", "")), Day01p2::new("3   4\n4   \n".lines()).err());
+    }
+
+    #[test]
+    fn streams_from_a_reader() {
+        let day01 = Day01::from_reader(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(Ok(11), day01.run());
+        match Day01::from_reader("3   4\n4   x3".as_bytes()) {
+            Err(ReadError::Parse(e)) => assert_eq!(ParseError::new(2, 5, "a location ID", "x3"), e),
+            _ => panic!("expected a parse error"),
+        }
+    }
+}
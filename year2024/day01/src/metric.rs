@@ -0,0 +1,189 @@
+//! Ways of measuring how far apart two lists of location IDs are.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Overflow;
+
+/// How two columns are compared. The first two pair the IDs up smallest with smallest, as the
+/// puzzle does; the rank distances take each column as a ranking of the input's lines instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    /// The difference between each pair of IDs, added up. The puzzle's answer.
+    #[default]
+    Absolute,
+    /// The difference between each pair of IDs squared, added up.
+    Squared,
+    /// How many pairs of lines the two columns put in opposite orders. Lines with the same ID
+    /// in either column aren't ordered by it, so never count.
+    KendallTau,
+    /// How many places each line moves between its rank in one column and in the other, added
+    /// up. Lines with the same ID rank in the order they were read.
+    SpearmanFootrule,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 4] = [Metric::Absolute, Metric::Squared, Metric::KendallTau, Metric::SpearmanFootrule];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Absolute => "absolute",
+            Metric::Squared => "squared",
+            Metric::KendallTau => "kendall-tau",
+            Metric::SpearmanFootrule => "spearman-footrule",
+        }
+    }
+
+    /// The distance between two columns of the same length, or an error if it's too big for
+    /// an `i128`. Only squared differences of IDs more than 2^63 apart can be; the rank
+    /// distances are at most the number of lines squared.
+    pub fn distance(&self, a: &[i64], b: &[i64]) -> Result<i128, Overflow> {
+        assert_eq!(a.len(), b.len(), "columns are compared line by line");
+        match self {
+            Metric::Absolute => sum(sorted_pairs(a, b).map(|(a, b)| Some((a - b).abs()))),
+            Metric::Squared => sum(sorted_pairs(a, b).map(|(a, b)| (a - b).checked_mul(a - b))),
+            Metric::KendallTau => {
+                // with the lines in `a`'s order, every pair `b` has the wrong way round is discordant
+                let mut lines: Vec<(i64, i64)> = a.iter().copied().zip(b.iter().copied()).collect();
+                lines.sort();
+                let mut b: Vec<i64> = lines.into_iter().map(|(_, b)| b).collect();
+                Ok(count_inversions(&mut b, &mut Vec::with_capacity(a.len())) as i128)
+            },
+            Metric::SpearmanFootrule => {
+                Ok(ranks(a).into_iter().zip(ranks(b)).map(|(a, b)| a.abs_diff(b) as i128).sum())
+            },
+        }
+    }
+}
+
+/// Adds up the differences, any of which may have overflowed already.
+fn sum(mut differences: impl Iterator<Item = Option<i128>>) -> Result<i128, Overflow> {
+    differences.try_fold(0i128, |total, difference| total.checked_add(difference?)).ok_or(Overflow("total distance"))
+}
+
+/// Both columns sorted and paired up, smallest with smallest. Any two `i64`s are less than
+/// 2^64 apart, so the differences fit an `i128` however far apart they are.
+fn sorted_pairs(a: &[i64], b: &[i64]) -> impl Iterator<Item = (i128, i128)> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    a.sort_unstable();
+    b.sort_unstable();
+    a.into_iter().zip(b).map(|(a, b)| (a as i128, b as i128))
+}
+
+/// Where each line comes in the column sorted, ties broken by the order of the lines.
+fn ranks(values: &[i64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by_key(|line| (values[*line], *line));
+    let mut ranks = vec![0; values.len()];
+    for (rank, line) in order.into_iter().enumerate() {
+        ranks[line] = rank;
+    }
+    ranks
+}
+
+/// How many pairs of values are strictly out of order, sorting `values` by merging as it goes.
+fn count_inversions(values: &mut [i64], merged: &mut Vec<i64>) -> u64 {
+    if values.len() < 2 {
+        return 0;
+    }
+    let middle = values.len() / 2;
+    let mut inversions = count_inversions(&mut values[..middle], merged) + count_inversions(&mut values[middle..], merged);
+    merged.clear();
+    let (mut left, mut right) = (0, middle);
+    while left < middle && right < values.len() {
+        if values[right] < values[left] {
+            // everything left in the first half is bigger than this
+            inversions += (middle - left) as u64;
+            merged.push(values[right]);
+            right += 1;
+        } else {
+            merged.push(values[left]);
+            left += 1;
+        }
+    }
+    merged.extend_from_slice(&values[left..middle]);
+    merged.extend_from_slice(&values[right..]);
+    values.copy_from_slice(merged);
+    inversions
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Metric::ALL.into_iter().find(|metric| metric.name() == name)
+            .ok_or_else(|| format!("unknown metric: {name}, expected absolute, squared, kendall-tau or spearman-footrule"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::synthetic::Rng;
+
+    const LEFT: [i64; 6] = [3, 4, 2, 1, 3, 3];
+    const RIGHT: [i64; 6] = [4, 3, 5, 3, 9, 3];
+
+    fn discordant_pairs_by_checking_every_pair(a: &[i64], b: &[i64]) -> i128 {
+        let mut discordant = 0;
+        for i in 0..a.len() {
+            for j in i + 1..a.len() {
+                if (a[i] < a[j] && b[i] > b[j]) || (a[i] > a[j] && b[i] < b[j]) {
+                    discordant += 1;
+                }
+            }
+        }
+        discordant
+    }
+
+    #[test]
+    fn sample_distances() {
+        assert_eq!(Ok(11), Metric::Absolute.distance(&LEFT, &RIGHT));
+        assert_eq!(Ok(35), Metric::Squared.distance(&LEFT, &RIGHT));
+        assert_eq!(Ok(discordant_pairs_by_checking_every_pair(&LEFT, &RIGHT)), Metric::KendallTau.distance(&LEFT, &RIGHT));
+        assert_eq!(Ok(14), Metric::SpearmanFootrule.distance(&LEFT, &RIGHT));
+    }
+
+    #[test]
+    fn a_column_is_no_distance_from_itself() {
+        for metric in Metric::ALL {
+            assert_eq!(Ok(0), metric.distance(&LEFT, &LEFT), "{metric}");
+        }
+        assert_eq!(Ok(15), Metric::KendallTau.distance(&[1, 2, 3, 4, 5, 6], &[6, 5, 4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn kendall_tau_counts_the_same_pairs_as_checking_each() {
+        let rng = &mut Rng::new(21);
+        for length in 0..60 {
+            let a: Vec<i64> = (0..length).map(|_| rng.range(0, 9) as i64).collect();
+            let b: Vec<i64> = (0..length).map(|_| rng.range(0, 9) as i64).collect();
+            assert_eq!(Ok(discordant_pairs_by_checking_every_pair(&a, &b)), Metric::KendallTau.distance(&a, &b));
+        }
+    }
+
+    #[test]
+    fn differences_past_64_bits_are_exact_or_an_error() {
+        let (lowest, highest) = ([i64::MIN; 3], [i64::MAX; 3]);
+        assert_eq!(Ok(3 * u64::MAX as i128), Metric::Absolute.distance(&lowest, &highest));
+        // each difference squared is nearly 2^128
+        assert_eq!(Err(Overflow("total distance")), Metric::Squared.distance(&lowest, &highest));
+        // each fits, but three of them don't add up
+        assert_eq!(Ok(2 * (i64::MAX as i128) * (i64::MAX as i128)), Metric::Squared.distance(&[0; 2], &highest[..2]));
+        assert_eq!(Err(Overflow("total distance")), Metric::Squared.distance(&[0; 3], &highest));
+    }
+
+    #[test]
+    fn metrics_go_by_name() {
+        for metric in Metric::ALL {
+            assert_eq!(Ok(metric), metric.name().parse());
+        }
+        assert!("euclidean".parse::<Metric>().is_err());
+    }
+}
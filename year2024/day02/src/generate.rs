@@ -0,0 +1,32 @@
+//! Made-up inputs for day 2: reports that mostly climb or fall steadily, some with a bad level or two.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` reports of five to eight levels.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    lines((0..size.max(1)).map(|_| {
+        let length = rng.range(5, 8);
+        let rising = rng.chance(50);
+        let mut level = rng.range(20, 80) as i64;
+        let mut report = vec![level.to_string()];
+        for _ in 1..length {
+            let step = if rng.chance(10) { rng.range(0, 6) as i64 } else { rng.range(1, 3) as i64 };
+            level += if rising { step } else { -step };
+            report.push(level.to_string());
+        }
+        report.join(" ")
+    })).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day02;
+
+    #[test]
+    fn dampener_only_adds_safe_reports() {
+        let day = Day02::new(generate(&mut Rng::new(2), 200).input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part2() > day.part1());
+    }
+}
@@ -0,0 +1,204 @@
+pub mod generate;
+pub mod rules;
+
+use common::parallel::par_map;
+use common::{for_each_line, parse_param, Answer, Explain, Explanation, FromReader, InputLines, Line, ParamError, ParseError, ReadError, Solution};
+use std::io::BufRead;
+use std::iter::Iterator;
+
+use crate::rules::Rules;
+
+pub struct Day02 {
+    reports: Vec::<Vec::<i32>>,
+    rules: Rules,
+}
+
+impl Day02 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut reports = Vec::<Vec::<i32>>::new();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            reports.push(Self::report(&line)?);
+        }
+        Ok(Day02{ reports, rules: Rules::PUZZLE })
+    }
+
+    fn report(line: &Line<'_>) -> Result<Vec<i32>, ParseError> {
+        let mut report = Vec::<i32>::new();
+        for value in line.text.split_whitespace() {
+            report.push(line.parse(value, "a level")?);
+        }
+        if report.len() < 2 {
+            return Err(line.missing("a report of at least two levels"));
+        }
+        Ok(report)
+    }
+
+    fn levels_to_remove(report: &[i32], rules: &Rules) -> Option<Vec<usize>> {
+        let removed = rules.fewest_removals(report);
+        (removed.len() <= rules.removals).then_some(removed)
+    }
+
+    /// For each report, the positions of the levels that have to be taken out for it to be safe
+    /// under `rules`, as few as possible, or `None` if it would take more than the dampener can.
+    pub fn evaluate(&self, rules: &Rules) -> Vec<Option<Vec<usize>>> {
+        par_map(&self.reports, |report| Self::levels_to_remove(report, rules))
+    }
+
+    /// How many reports are safe under `rules`.
+    pub fn count_safe(&self, rules: &Rules) -> u32 {
+        self.evaluate(rules).into_iter().filter(|removed| removed.is_some()).count() as u32
+    }
+
+    pub fn part1(&self) -> u32 {
+        self.count_safe(&self.rules.with_removals(0))
+    }
+
+    pub fn part2(&self) -> u32 {
+        self.count_safe(&self.rules)
+    }
+}
+
+impl Explain for Day02 {
+    /// Whether each report is safe, and for part 2 which levels the dampener took out to make it so.
+    fn explain(&self, part: u8) -> Explanation {
+        let mut explanation = Explanation::new(if part == 1 { &["report", "safe"] } else { &["report", "safe", "dampened levels"] });
+        let rules = if part == 1 { self.rules.with_removals(0) } else { self.rules };
+        for (report, removed) in self.reports.iter().zip(self.evaluate(&rules)) {
+            let levels = report.iter().map(|level| level.to_string()).collect::<Vec<_>>().join(" ");
+            if part == 1 {
+                explanation.row(vec![levels, removed.is_some().to_string()]);
+                continue;
+            }
+            let dampened = removed.iter().flatten()
+                .map(|index| format!("{} (position {})", report[*index], index + 1))
+                .collect::<Vec<_>>().join(", ");
+            explanation.row(vec![levels, removed.is_some().to_string(), dampened]);
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day02 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut reports = Vec::<Vec::<i32>>::new();
+        for_each_line(reader, |line| {
+            reports.push(Self::report(&line)?);
+            Ok(())
+        })?;
+        Ok(Day02{ reports, rules: Rules::PUZZLE })
+    }
+}
+
+impl Solution<'_> for Day02 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day02::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day02::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day02::part2(self).into()
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "min-step" => { self.rules.min_step = parse_param(name, value)?; },
+            "max-step" => { self.rules.max_step = parse_param(name, value)?; },
+            "monotonic" => { self.rules.monotonic = parse_param(name, value)?; },
+            "removals" => { self.rules.removals = parse_param(name, value)?; },
+            _ => { return Err(ParamError::Unknown(name.to_string())); }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Monotonic;
+    const SAMPLE_INPUT: &str =
+"7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn part1_sample_generates_2() {
+        let day02 = Day02::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(2, day02.part1());
+    }
+
+    #[test]
+    fn part2_sample_generates_2() {
+        let day02 = Day02::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(4, day02.part2());
+    }
+
+    #[test]
+    fn rules_set_the_tolerances() {
+        let day02 = Day02::new(SAMPLE_INPUT.lines()).unwrap();
+        let safe = |rules: Rules| day02.evaluate(&rules).iter().map(Option::is_some).collect::<Vec<_>>();
+        // 1 2 7 8 9 and 9 7 6 2 1 only step by 4 or 5
+        assert_eq!(vec![true, true, true, false, false, true], safe(Rules{max_step: 5, ..Rules::PUZZLE}.with_removals(0)));
+        // 8 6 4 4 1 is allowed its repeated 4
+        let level = Rules{monotonic: Monotonic::NonStrict, ..Rules::PUZZLE.with_removals(0)};
+        assert_eq!(vec![true, false, false, false, true, true], safe(level));
+        // and keeps it once the smallest step is 2, while every other report moves by 1 somewhere
+        assert_eq!(vec![false, false, false, false, true, false], safe(Rules{min_step: 2, ..level}));
+        assert_eq!(vec![Some(vec![]), None, None, Some(vec![2]), Some(vec![3]), Some(vec![])], day02.evaluate(&Rules::PUZZLE));
+    }
+
+    #[test]
+    fn the_dampener_can_take_out_more_than_one_level() {
+        let day02 = Day02::new("1 2 9 3 9 4\n1 9 9 9 2 3\n5 4 4 4 3".lines()).unwrap();
+        assert_eq!(vec![None, None, None], day02.evaluate(&Rules::PUZZLE));
+        assert_eq!(vec![Some(vec![2, 4]), None, Some(vec![2, 3])], day02.evaluate(&Rules::PUZZLE.with_removals(2)));
+        assert_eq!(vec![Some(vec![2, 4]), Some(vec![1, 2, 3]), Some(vec![2, 3])], day02.evaluate(&Rules::PUZZLE.with_removals(3)));
+        assert_eq!(vec![Some(vec![2, 4]), None, Some(vec![])],
+                   day02.evaluate(&Rules{monotonic: Monotonic::NonStrict, ..Rules::PUZZLE.with_removals(2)}));
+    }
+
+    #[test]
+    fn params_configure_the_rules() {
+        let mut day02 = Day02::new(SAMPLE_INPUT.lines()).unwrap();
+        day02.configure("max-step", "5").unwrap();
+        assert_eq!(4, day02.part1());
+        day02.configure("removals", "0").unwrap();
+        assert_eq!(4, day02.part2());
+        day02.configure("monotonic", "non-strict").unwrap();
+        assert_eq!((5, 5), (day02.part1(), day02.part2()));
+        day02.configure("min-step", "2").unwrap();
+        assert_eq!((1, 1), (day02.part1(), day02.part2()));
+        day02.configure("monotonic", "strict").unwrap();
+        assert_eq!((0, 0), (day02.part1(), day02.part2()));
+        assert_eq!(Err(ParamError::Invalid{name: "monotonic".to_string(), value: "loose".to_string()}), day02.configure("monotonic", "loose"));
+        assert_eq!(Err(ParamError::Unknown("steps".to_string())), day02.configure("steps", "1"));
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 7, "a level", "x8")), Day02::new("7 6 4 2 1\n1 2 7 x8 9".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 2, "a report of at least two levels", "")), Day02::new("7 6 4 2 1\n1".lines()).err());
+    }
+
+    #[test]
+    fn streams_from_a_reader() {
+        let day02 = Day02::from_reader("7 6 4 2 1\r\n1 2  7 8 9 \r\n\n9 7 6 2 1\n".as_bytes()).unwrap();
+        assert_eq!(1, day02.part1());
+        assert!(matches!(Day02::from_reader("7 6 4 2 1\n1".as_bytes()), Err(ReadError::Parse(e)) if e.line == 2));
+    }
+
+    #[test]
+    fn explains_which_level_was_dampened() {
+        let day02 = Day02::new(SAMPLE_INPUT.lines()).unwrap();
+        let explanation = day02.explain(2);
+        let dampened: Vec<&str> = explanation.rows.iter().map(|row| row[2].as_str()).collect();
+        assert_eq!(vec!["", "", "", "2 (position 3)", "4 (position 4)", ""], dampened);
+        assert_eq!(day02.part2() as usize, explanation.rows.iter().filter(|row| row[1] == "true").count());
+        assert_eq!(day02.part1() as usize, day02.explain(1).rows.iter().filter(|row| row[1] == "true").count());
+    }
+}
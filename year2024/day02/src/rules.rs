@@ -0,0 +1,212 @@
+//! What makes a report safe: how far apart neighbouring levels can be, whether a level can
+//! repeat the one before, and how many levels the dampener can take out.
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+
+/// Whether a level can repeat the one before, or the report has to rise or fall at every level.
+/// Only which way the levels go: how far they can move is up to the steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Monotonic {
+    #[default]
+    Strict,
+    NonStrict,
+}
+
+impl Monotonic {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Monotonic::Strict => "strict",
+            Monotonic::NonStrict => "non-strict",
+        }
+    }
+}
+
+impl fmt::Display for Monotonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Monotonic {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        [Monotonic::Strict, Monotonic::NonStrict].into_iter().find(|monotonic| monotonic.name() == name)
+            .ok_or_else(|| format!("unknown monotonicity: {name}, expected strict or non-strict"))
+    }
+}
+
+/// The tolerances a report is checked against. A report is safe if it only rises or only falls,
+/// by between `min_step` and `max_step` whenever a level moves, once up to `removals` of its
+/// levels have been taken out. A repeated level doesn't move, so `monotonic` alone says whether
+/// it's allowed, and a `min_step` of 0 is the same as 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub min_step: u32,
+    pub max_step: u32,
+    pub monotonic: Monotonic,
+    pub removals: usize,
+}
+
+impl Rules {
+    /// The puzzle's: steps of 1 to 3, never level, and the dampener taking out one level.
+    pub const PUZZLE: Rules = Rules{min_step: 1, max_step: 3, monotonic: Monotonic::Strict, removals: 1};
+
+    /// The same rules with the dampener able to take out `removals` levels instead.
+    pub fn with_removals(self, removals: usize) -> Rules {
+        Rules{removals, ..self}
+    }
+
+    /// Whether a report that's `rising`, or falling if not, can go from `first` to `second`.
+    pub fn allows(&self, rising: bool, first: i32, second: i32) -> bool {
+        if first == second {
+            return self.monotonic == Monotonic::NonStrict;
+        }
+        (second > first) == rising && (self.min_step..=self.max_step).contains(&first.abs_diff(second))
+    }
+
+    /// The positions of the fewest levels that can be taken out of `report` to leave it safe,
+    /// smallest first, however many `removals` allows. Of the ways to take out that few, the
+    /// one that keeps the earliest levels.
+    pub fn fewest_removals(&self, report: &[i32]) -> Vec<usize> {
+        let kept = [true, false].into_iter()
+            .map(|rising| self.longest_safe(report, rising))
+            .min_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)))
+            .unwrap_or_default();
+        (0..report.len()).filter(|index| kept.binary_search(index).is_err()).collect()
+    }
+
+    /// The positions of the most levels that are safe together going one way, the earliest of
+    /// them on a tie. Worked out back to front, as the longest safe run of levels starting at
+    /// each one is that level followed by the longest run from one it can step to.
+    fn longest_safe(&self, report: &[i32], rising: bool) -> Vec<usize> {
+        let mut longest = vec![1; report.len()];
+        let mut next = vec![None; report.len()];
+        for first in (0..report.len()).rev() {
+            for second in first + 1..report.len() {
+                // only a strictly longer run replaces the one found from an earlier level
+                if longest[second] + 1 > longest[first] && self.allows(rising, report[first], report[second]) {
+                    longest[first] = longest[second] + 1;
+                    next[first] = Some(second);
+                }
+            }
+        }
+        let mut kept = Vec::new();
+        let mut level = (0..report.len()).max_by_key(|level| (longest[*level], Reverse(*level)));
+        while let Some(at) = level {
+            kept.push(at);
+            level = next[at];
+        }
+        kept
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::PUZZLE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Whether the levels left once those at `removed` are taken out are safe one way or the other,
+    /// going by the rules as written rather than `Rules::allows`.
+    fn safe_without(rules: &Rules, report: &[i32], removed: &[usize]) -> bool {
+        let kept: Vec<i32> = report.iter().enumerate().filter(|(index, _)| !removed.contains(index)).map(|(_, level)| *level).collect();
+        let steps: Vec<i32> = kept.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let repeats_allowed = rules.monotonic == Monotonic::NonStrict || steps.iter().all(|step| *step != 0);
+        let in_range = steps.iter().filter(|step| **step != 0).all(|step| rules.min_step <= step.unsigned_abs() && step.unsigned_abs() <= rules.max_step);
+        let one_way = steps.iter().all(|step| *step >= 0) || steps.iter().all(|step| *step <= 0);
+        repeats_allowed && in_range && one_way
+    }
+
+    /// The fewest levels to take out, by trying every set of levels.
+    fn fewest_removals_by_trying_every_set(rules: &Rules, report: &[i32]) -> usize {
+        (0u32..1 << report.len())
+            .filter(|set| {
+                let removed: Vec<usize> = (0..report.len()).filter(|index| set & (1 << index) != 0).collect();
+                safe_without(rules, report, &removed)
+            })
+            .map(|set| set.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    fn rules() -> impl Strategy<Value = Rules> {
+        (0..3u32, 0..4u32, any::<bool>()).prop_map(|(min_step, extra, strict)| Rules{
+            min_step, max_step: min_step + extra, monotonic: if strict { Monotonic::Strict } else { Monotonic::NonStrict }, removals: 0,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn fewest_removals_are_the_fewest(rules in rules(), report in prop::collection::vec(0..12i32, 1..11)) {
+            let removed = rules.fewest_removals(&report);
+            prop_assert!(safe_without(&rules, &report, &removed));
+            prop_assert_eq!(fewest_removals_by_trying_every_set(&rules, &report), removed.len());
+        }
+    }
+
+    #[test]
+    fn monotonicity_and_the_smallest_step_work_apart() {
+        let reports: [&[i32]; 6] = [&[9, 4, 4, 5], &[1, 1, 3, 3, 6], &[5, 5, 5], &[1, 2, 4, 7, 7, 6], &[3, 1, 1, 2, 8], &[4, 6, 5, 5, 9, 11]];
+        for monotonic in [Monotonic::Strict, Monotonic::NonStrict] {
+            for min_step in 0..4 {
+                let rules = Rules{min_step, max_step: 3, monotonic, removals: 0};
+                for report in reports {
+                    let removed = rules.fewest_removals(report);
+                    assert!(safe_without(&rules, report, &removed), "{rules:?} {report:?}");
+                    assert_eq!(fewest_removals_by_trying_every_set(&rules, report), removed.len(), "{rules:?} {report:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn takes_out_the_fewest_levels() {
+        let rules = Rules::PUZZLE;
+        assert_eq!(Vec::<usize>::new(), rules.fewest_removals(&[7, 6, 4, 2, 1]));
+        // taking out the 3 or the 2 both work, and the earlier levels are kept
+        assert_eq!(vec![2], rules.fewest_removals(&[1, 3, 2, 4, 5]));
+        // the first step goes the wrong way, and the first level is the one out of place
+        assert_eq!(vec![0], rules.fewest_removals(&[9, 1, 2, 3, 4]));
+        // most steps fall, but keeping the three that rise takes out fewer levels
+        assert_eq!(vec![1, 2, 3], rules.fewest_removals(&[1, 9, 8, 7, 2, 3]));
+        assert_eq!(vec![0], Rules{monotonic: Monotonic::NonStrict, ..rules}.fewest_removals(&[9, 4, 4, 5]));
+        // the repeated 4 doesn't have to clear the smallest step, but 4 to 5 does
+        assert_eq!(vec![0, 3], Rules{min_step: 2, monotonic: Monotonic::NonStrict, ..rules}.fewest_removals(&[9, 4, 4, 5]));
+    }
+
+    #[test]
+    fn steps_within_the_range_in_one_direction() {
+        let rules = Rules::PUZZLE;
+        assert!(rules.allows(true, 1, 4));
+        assert!(!rules.allows(true, 1, 5));
+        assert!(!rules.allows(true, 4, 1));
+        assert!(rules.allows(false, 4, 1));
+        assert!(!rules.allows(false, 4, 4));
+        let loose = Rules{min_step: 0, max_step: 10, monotonic: Monotonic::NonStrict, removals: 0};
+        assert!(loose.allows(true, 4, 4));
+        assert!(loose.allows(false, 4, 4));
+        assert!(loose.allows(true, 4, 5));
+        assert!(loose.allows(true, 4, 14));
+        assert!(!Rules{monotonic: Monotonic::Strict, ..loose}.allows(true, 4, 4));
+        // the smallest step only holds back levels that move
+        let wide = Rules{min_step: 2, ..loose};
+        assert!(wide.allows(true, 4, 4));
+        assert!(!wide.allows(true, 4, 5));
+        assert!(wide.allows(true, 4, 6));
+    }
+
+    #[test]
+    fn monotonicity_goes_by_name() {
+        assert_eq!(Ok(Monotonic::NonStrict), "non-strict".parse());
+        assert_eq!(Ok(Monotonic::Strict), Monotonic::Strict.to_string().parse());
+        assert!("loose".parse::<Monotonic>().is_err());
+    }
+}
@@ -0,0 +1,34 @@
+//! Made-up inputs for day 3: corrupted memory with instructions, valid and not, among junk.
+
+use common::synthetic::{Rng, Synthetic};
+
+/// Memory with `size` instructions, forty to a line.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    const JUNK: [&str; 8] = ["%&", "!@^", "where()", "+", "select()", "mul[3,7]", "mul(4*", "what() "];
+    let mut memory = String::new();
+    for count in 1..=size.max(1) {
+        memory += rng.pick(&JUNK);
+        memory += &match rng.range(1, 10) {
+            1 => "do()".to_string(),
+            2 => "don't()".to_string(),
+            3 => format!("mul({},{}]", rng.range(1, 99), rng.range(1, 99)),
+            _ => format!("mul({},{})", rng.range(1, 99), rng.range(1, 99)),
+        };
+        if count % 40 == 0 { memory.push('\n'); }
+    }
+    (memory + "\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day03;
+
+    #[test]
+    fn disabled_instructions_only_take_away() {
+        let synthetic = generate(&mut Rng::new(3), 400);
+        let day = Day03::new(synthetic.input.lines()).unwrap();
+        assert!(day.part2() > 0);
+        assert!(day.part2() < day.part1());
+    }
+}
@@ -1,16 +1,21 @@
+pub mod generate;
+
+use common::{Answer, FromReader, ParseError, ReadError, Solution};
 use regex::Regex;
+use std::io::BufRead;
 
-pub struct Day03<'a> {
-    input: Vec<&'a str>
+pub struct Day03 {
+    input: Vec<String>
 }
 
-impl<'a> Day03<'a> {
-    pub fn new(lines: std::str::Lines<'a>) -> Self {
-        Day03{input: lines.collect()}
+impl Day03 {
+    /// Any text is valid corrupted memory, so this never fails; it returns a `Result` like every other day.
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        Ok(Day03{input: lines.map(str::to_string).collect()})
     }
 
     pub fn part1(&self) -> i32 {
-        let re = Regex::new(r"mul\((?<multiplicand>\d+),(?<multiplier>\d+)\)").unwrap();
+        let re = Regex::new(r"mul\((?<multiplicand>\d{1,3}),(?<multiplier>\d{1,3})\)").unwrap();
         let mut sum_of_good_multiplies = 0;
         for line in &self.input {
             for pairs in re.captures_iter(line).map(|caps| {
@@ -24,8 +29,8 @@ impl<'a> Day03<'a> {
     }
 
     pub fn part2(&self) -> i32 {
-        let instruction_re = Regex::new(r"(mul|do|don\'t)(\(\)|\(\d+,\d+\))").unwrap();
-        let multiply_args_re = Regex::new(r"^\((?<multiplicand>\d+),(?<multiplier>\d+)\)$").unwrap();
+        let instruction_re = Regex::new(r"(mul|do|don\'t)(\(\)|\(\d{1,3},\d{1,3}\))").unwrap();
+        let multiply_args_re = Regex::new(r"^\((?<multiplicand>\d{1,3}),(?<multiplier>\d{1,3})\)$").unwrap();
         let mut enable_instructions = true;
         let mut sum_of_good_multiplies = 0;
         for line in &self.input {
@@ -56,20 +61,53 @@ impl<'a> Day03<'a> {
     }
 }
 
+impl FromReader for Day03 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day03{input: reader.lines().collect::<Result<_, _>>()?})
+    }
+}
+
+impl Solution<'_> for Day03 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day03::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day03::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day03::part2(self).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     const SAMPLE_INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
     #[test]
     fn sample_input_results_in_161() {
-        let day03 = Day03::new(SAMPLE_INPUT.lines());
+        let day03 = Day03::new(SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(161, day03.part1());
     }
 
     #[test]
     fn sample_input_results_in_48() {
         const SAMPLE_INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-        let day03 = Day03::new(SAMPLE_INPUT.lines());
+        let day03 = Day03::new(SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(48, day03.part2());
     }
+
+    #[test]
+    fn skips_malformed_instructions() {
+        let day03 = Day03::new("mul(2,4)\nmul(1234,5) mul( 3,4) mul(x,2)\nmul(3,3\n".lines()).unwrap();
+        assert_eq!(8, day03.part1());
+        assert_eq!(8, day03.part2());
+    }
+
+    #[test]
+    fn reads_from_a_reader() {
+        let day03 = Day03::from_reader(SAMPLE_INPUT.as_bytes()).unwrap();
+        assert_eq!(161, day03.part1());
+    }
 }
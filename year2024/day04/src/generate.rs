@@ -0,0 +1,21 @@
+//! Made-up inputs for day 4: a word search of random letters from XMAS.
+
+use common::synthetic::{letter_grid, Rng, Synthetic};
+
+/// A grid `size` letters square, at least four so a word fits.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    letter_grid(rng, size.max(4), "XMAS").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+
+    #[test]
+    fn words_turn_up_in_a_large_grid() {
+        let day = Day04::new(generate(&mut Rng::new(4), 40).input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part2() > 0);
+    }
+}
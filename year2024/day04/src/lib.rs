@@ -0,0 +1,185 @@
+pub mod generate;
+
+use common::{read_input, Answer, FromReader, ParseError, ReadError, Solution};
+use std::io::BufRead;
+use grid::{Direction, Grid, Point};
+
+pub struct Day04 {
+    input: Grid<char>
+}
+
+impl Day04 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let input = Grid::parse(lines, "a letter", |_, a_char| a_char.is_ascii_graphic().then_some(a_char))?;
+        Ok(Day04{input})
+    }
+
+    fn spells_mas(&self, x_pos: Point, direction: Direction) -> bool {
+        self.input.ray(x_pos, direction).take(3).map(|pos| self.input[pos]).eq("MAS".chars())
+    }
+
+    pub fn part1(&self) -> u32 {
+        let mut sum_of_xmases: u32 = 0;
+        for x_pos in self.input.positions(|a_char| *a_char == 'X') {
+            let count_of_xmases_from_here = Direction::ALL.into_iter().filter(|direction| self.spells_mas(x_pos, *direction)).count();
+            sum_of_xmases += count_of_xmases_from_here as u32;
+        }
+        sum_of_xmases
+    }
+
+    fn test_for_mas_cross(&self, a_pos: Point) -> bool {
+        let corner = |direction| self.input.step(a_pos, direction).map(|pos| self.input[pos]);
+        match (corner(Direction::NorthWest), corner(Direction::SouthWest), corner(Direction::NorthEast), corner(Direction::SouthEast)) {
+            (Some('M'), Some('M'), Some('S'), Some('S')) => true,
+            (Some('M'), Some('S'), Some('M'), Some('S')) => true,
+            (Some('S'), Some('M'), Some('S'), Some('M')) => true,
+            (Some('S'), Some('S'), Some('M'), Some('M')) => true,
+            (_, _, _, _) => false
+        }
+    }
+
+    pub fn part2(&self) -> u32 {
+        let mut sum_of_mas_crosses: u32 = 0;
+        for a_pos in self.input.positions(|a_char| *a_char == 'A') {
+            if self.test_for_mas_cross(a_pos) {
+                sum_of_mas_crosses += 1;
+            }
+        }
+        sum_of_mas_crosses
+    }
+}
+
+impl FromReader for Day04 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day04::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day04 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day04::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day04::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day04::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample1_for_part1_is_4() {
+        const SAMPLE_INPUT: &str =
+"..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(4, day.part1());
+    }
+
+    #[test]
+    fn sample2_for_part1_is_18() {
+        const SAMPLE_INPUT_2: &str =
+"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        let day = Day04::new(SAMPLE_INPUT_2.lines()).unwrap();
+        assert_eq!(18, day.part1());
+    }
+
+    #[test]
+    fn sample3_for_part1_is_18() {
+        const SAMPLE_INPUT_3: &str =
+"....XXMAS.
+.SAMXMS...
+...S..A...
+..A.A.MS.X
+XMASAMX.MM
+X.....XA.A
+S.S.S.S.SS
+.A.A.A.A.A
+..M.M.M.MM
+.X.X.XMASX";
+        let day = Day04::new(SAMPLE_INPUT_3.lines()).unwrap();
+        assert_eq!(18, day.part1());
+    }
+
+    #[test]
+    fn down_extremes() {
+        const SAMPLE_INPUT: &str = "...X...
+..MMM..
+.A.A.A.
+S..S..S";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(3, day.part1());
+    }
+
+    #[test]
+    fn up_extremes() {
+        const SAMPLE_INPUT: &str =
+"S..S..S
+.A.A.A.
+..MMM..
+...X...";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(3, day.part1());
+    }
+
+    #[test]
+    fn sideways_extremes() {
+        const SAMPLE_INPUT: &str =
+"XMASAMX
+SAMXMAS";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(4, day.part1());
+    }
+
+    #[test]
+    fn part2_first_example() {
+        const SAMPLE_INPUT: &str =
+"M.S
+.A.
+M.S";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(1, day.part2());
+    }
+
+    #[test]
+    fn part2_second_example() {
+        const SAMPLE_INPUT: &str =
+".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........";
+        let day = Day04::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(9, day.part2());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 3, "a row 4 wide", "")), Day04::new("XMAS\nSA".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 5, "end of row", "S")), Day04::new("XMAS\nSAMXS".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 3, "a letter", "é")), Day04::new("XMéS".lines()).err());
+    }
+}
@@ -0,0 +1,45 @@
+//! Made-up inputs for day 5: ordering rules and updates to check against them.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// Rules giving every pair of 49 pages an order, so they never contradict each other, and
+/// `size` updates of which about half already follow them.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut pages: Vec<u64> = (10..99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{before}|{after}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let updates = (0..size.max(1)).map(|_| {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(rng.range(2, 11) as usize * 2 + 1);
+        if rng.chance(50) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        update.iter().map(u64::to_string).collect::<Vec<_>>().join(",")
+    });
+    (lines(rules) + "\n" + &lines(updates)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day05;
+
+    #[test]
+    fn updates_in_and_out_of_order() {
+        let synthetic = generate(&mut Rng::new(5), 20);
+        let (rules, updates) = synthetic.input.split_once("\n\n").unwrap();
+        assert_eq!(49 * 48 / 2, rules.lines().count());
+        assert_eq!(20, updates.lines().count());
+        let day = Day05::new(synthetic.input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part2() > 0);
+    }
+}
@@ -0,0 +1,252 @@
+pub mod generate;
+
+use common::{read_input, Answer, Explain, Explanation, FromReader, InputLines, ParseError, ReadError, Solution};
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+pub struct Day05 {
+    pages_that_come_before: HashMap<u32, HashSet<u32>>,
+    page_lists: Vec<Vec<u32>>,
+}
+
+impl Day05 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut empty_line: Option<usize> = None;
+        
+        let mut pages_that_come_before: HashMap<u32, HashSet<u32>> = HashMap::new();
+        let mut page_lists: Vec<Vec<u32>> = Vec::new();
+        let mut lines = InputLines::new(lines);
+        for line in &mut lines {
+            if empty_line.is_none() {
+                if line.is_blank() {
+                    empty_line = Some(line.number);
+                } else {
+                    let mut items = line.text.split("|").map(str::trim);
+                    let before = line.field(&mut items, "a page number")?;
+                    let after = line.field(&mut items, "a page number")?;
+                    line.end(&mut items)?;
+                    pages_that_come_before.entry(after).or_default();
+                    pages_that_come_before.entry(after).and_modify(|e| { e.insert(before); });
+                }
+            }
+            else if !line.is_blank() {
+                let mut page_list: Vec<u32> = Vec::new();
+                for page in line.text.split(",").map(str::trim) {
+                    page_list.push(line.parse(page, "a page number")?);
+                }
+                page_lists.push(page_list);
+            }
+        }
+        if empty_line.is_none() {
+            return Err(lines.expect("a blank line before the page updates").unwrap_err());
+        }
+        Ok(Day05{pages_that_come_before, page_lists})
+    }
+
+    fn determine_middle_page_if_good(page_list: &[u32], good: bool) -> u32 {
+        if good {
+            page_list[(page_list.len() - 1) / 2]
+        } else {
+            0
+        }
+    }
+
+    fn get_hashset_of_predicates(&self, page: u32) -> HashSet<u32> {
+        match self.pages_that_come_before.get(&page) {
+            Some(v) => v.clone(),
+            None => HashSet::new()
+        }
+    }
+
+    fn build_hashset_from_page_list(page_list: &[u32]) -> HashSet::<u32> {
+        let mut hash_set = HashSet::new();
+        for page in page_list { hash_set.insert(*page); }
+        hash_set
+    }
+
+    fn does_page_order_follow_rules(&self, page_list: &[u32]) -> bool {
+        // Build HashSet from page_list contents
+        // iterate through vec
+        // remove vec iter from HashSet
+        // get hashset of all rules with values that need to show up _before_ vec iter
+        // if intersection is not empty, return false
+        let mut pages_after = Self::build_hashset_from_page_list(page_list);
+        for page in page_list {
+            pages_after.remove(page);
+            let page_set_required_before = self.get_hashset_of_predicates(*page);
+            if page_set_required_before.intersection(&pages_after).count() > 0 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    pub fn part1(&self) -> u32 {
+        let mut sum: u32 = 0;
+        for page_list in &self.page_lists {
+            sum += Self::determine_middle_page_if_good(page_list, self.does_page_order_follow_rules(page_list));
+        }
+        sum
+    }
+
+    fn fix_page_order(&self, page_list: &[u32]) -> Vec<u32> {
+        let mut page_rules: HashMap<u32, HashSet<u32>> = HashMap::new();
+        let mut pages = HashSet::new();
+        for page in page_list {
+            page_rules.insert(*page, self.get_hashset_of_predicates(*page));
+            pages.insert(*page);
+        }
+
+        let mut good_page_order: VecDeque::<u32> = VecDeque::new();
+
+        while !page_rules.is_empty() {
+            let mut pages_not_in_other_rules: Vec<u32> = Vec::new();
+            for page in &pages {
+                let mut found = false;
+                for earlier_pages in page_rules.values() {
+                    if earlier_pages.contains(page) {
+                        found = true;
+                    }
+                }
+                if !found { 
+                    pages_not_in_other_rules.push(*page);
+                }
+            }
+
+            for page in pages_not_in_other_rules {
+                good_page_order.push_front(page);
+                page_rules.remove(&page);
+                pages.remove(&page);
+            }
+
+        }
+
+        good_page_order.into_iter().collect()
+
+    }
+
+    pub fn part2(&self) -> u32 {
+        let mut sum: u32 = 0;
+        for page_list in &self.page_lists {
+            if !self.does_page_order_follow_rules(page_list) {
+                sum += Self::determine_middle_page_if_good(&self.fix_page_order(page_list), true);
+            }
+        }
+        sum
+    }
+}
+
+fn page_order(page_list: &[u32]) -> String {
+    page_list.iter().map(|page| page.to_string()).collect::<Vec<_>>().join(",")
+}
+
+impl Explain for Day05 {
+    /// Every update and whether it's in order for part 1; for part 2 just the ones that weren't,
+    /// in their corrected order.
+    fn explain(&self, part: u8) -> Explanation {
+        let mut explanation = Explanation::new(if part == 1 { &["update", "in order", "middle page"] } else { &["update", "corrected order", "middle page"] });
+        for page_list in &self.page_lists {
+            let in_order = self.does_page_order_follow_rules(page_list);
+            if part == 1 {
+                let middle = if in_order { Self::determine_middle_page_if_good(page_list, true).to_string() } else { String::new() };
+                explanation.row(vec![page_order(page_list), in_order.to_string(), middle]);
+            } else if !in_order {
+                let fixed = self.fix_page_order(page_list);
+                let middle = Self::determine_middle_page_if_good(&fixed, true);
+                explanation.row(vec![page_order(page_list), page_order(&fixed), middle.to_string()]);
+            }
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day05 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day05::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day05 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day05::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day05::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day05::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+    #[test]
+    fn sample_input_gives_example_out() {
+        let day = Day05::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(143, day.part1());
+    }
+
+    #[test]
+    fn sample_input_gives_part2_example_out() {
+        let day: Day05 = Day05::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(123, day.part2());
+    }
+
+    #[test]
+    fn explains_the_corrected_orders() {
+        let day = Day05::new(SAMPLE_INPUT.lines()).unwrap();
+        let explanation = day.explain(2);
+        let corrected: Vec<&str> = explanation.rows.iter().map(|row| row[1].as_str()).collect();
+        assert_eq!(vec!["97,75,47,61,53", "61,29,13", "97,75,47,29,13"], corrected);
+        assert_eq!(vec!["true", "true", "true", "false", "false", "false"],
+                   day.explain(1).rows.iter().map(|row| row[1].as_str()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn spaces_around_separators_are_ignored() {
+        let day = Day05::new("47 |53\n97 | 47\n\n97, 47 ,53\n53, 47".lines()).unwrap();
+        assert_eq!(47, day.part1());
+        assert_eq!(47, day.part2());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 4, "a page number", "")), Day05::new("47|53\n97|\n\n97,47".lines()).err());
+        assert_eq!(Some(ParseError::new(4, 4, "a page number", "4x")), Day05::new("47|53\n97|47\n\n97,4x".lines()).err());
+        assert_eq!(Some(ParseError::new(3, 1, "a blank line before the page updates", "")), Day05::new("47|53\n97|47".lines()).err());
+    }
+}
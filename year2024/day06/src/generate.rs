@@ -0,0 +1,46 @@
+//! Made-up inputs for day 6: a lab with scattered obstacles and a guard who eventually walks out.
+
+use std::collections::HashSet;
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// Whether a guard starting at `start` facing north walks off the map rather than in circles.
+fn guard_leaves(map: &[Vec<char>], start: (usize, usize)) -> bool {
+    let mut visited = HashSet::new();
+    let (mut x, mut y, mut facing) = (start.0 as i64, start.1 as i64, 0);
+    let offsets = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    while visited.insert((x, y, facing)) {
+        let (next_x, next_y) = (x + offsets[facing].0, y + offsets[facing].1);
+        match map.get(next_y as usize).and_then(|row| row.get(next_x as usize)) {
+            None => { return true; },
+            Some('#') => { facing = (facing + 1) % 4; },
+            Some(_) => { (x, y) = (next_x, next_y); }
+        }
+    }
+    false
+}
+
+/// A map `size` tiles square, redrawn until the guard's patrol leads off the edge.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let side = size.max(4);
+    loop {
+        let mut map: Vec<Vec<char>> = (0..side).map(|_| (0..side).map(|_| if rng.chance(6) { '#' } else { '.' }).collect()).collect();
+        let guard = (rng.index(side), rng.index(side));
+        map[guard.1][guard.0] = '^';
+        if guard_leaves(&map, guard) {
+            return lines(map.into_iter().map(|row| row.into_iter().collect())).into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+
+    #[test]
+    fn guard_walks_out() {
+        let day = Day06::new(generate(&mut Rng::new(6), 30).input.lines()).unwrap();
+        assert!(day.part1() > 1);
+    }
+}
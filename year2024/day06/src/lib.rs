@@ -0,0 +1,359 @@
+pub mod generate;
+
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use common::parallel::par_map;
+use common::{read_input, Answer, FromReader, ParseError, ReadError, Solution};
+use grid::{Direction, Grid, Point};
+use render::{frame_interval, Colour, Frame, Render, Simulation, View};
+
+pub struct Day06 {
+    obstacles: Grid<bool>,
+    guard_start_pos_and_dir: (Point, Direction),
+}
+
+impl Day06 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut guard_pos_and_dir: Option<(Point, Direction)> = None;
+        let obstacles = Grid::parse(lines, "one of `.`, `#` or a single `^`", |position, a_char| {
+            match a_char {
+                '#' => Some(true),
+                '^' if guard_pos_and_dir.is_none() => {
+                    guard_pos_and_dir = Some((position, Direction::North));
+                    Some(false)
+                },
+                '.' => Some(false),
+                _ => None,
+            }
+        })?;
+        let Some(guard_start_pos_and_dir) = guard_pos_and_dir else {
+            return Err(ParseError::end_of_input(obstacles.height() + 1, "a guard `^` on the map"));
+        };
+        Ok(Day06{obstacles, guard_start_pos_and_dir})
+    }
+
+    fn calc_next_pos_in_direction(&self, guard_pos: &(Point, Direction)) -> Option<(Point, Direction)> {
+        self.obstacles.step(guard_pos.0, guard_pos.1).map(|next_pos| (next_pos, guard_pos.1))
+    }
+
+    fn turn_guard(guard_pos: (Point, Direction)) -> (Point, Direction) {
+        (guard_pos.0, guard_pos.1.turn_right())
+    }
+
+
+
+    fn is_forward_blocked(&self, guard_pos: &(Point, Direction)) -> Option<bool> {
+        self.calc_next_pos_in_direction(guard_pos).map(|forward_position| self.obstacles[forward_position.0])
+    }
+
+
+    /// Every position the guard stands on until walking off the map, in order, revisits included.
+    fn guard_route(&self) -> Vec<Point> {
+        let mut guard_pos = self.guard_start_pos_and_dir;
+        let mut route = vec![guard_pos.0];
+
+        loop {
+            match self.is_forward_blocked(&guard_pos) {
+                None => { break; },
+                Some(true) => { guard_pos = Day06::turn_guard(guard_pos); }
+                Some(false) => {
+                    if let Some(a_guard_pos) = self.calc_next_pos_in_direction(&guard_pos) {
+                        route.push(a_guard_pos.0);
+                        guard_pos = a_guard_pos;
+                    }
+                }
+            }
+        }
+        route
+    }
+
+    pub fn part1(&self) -> usize {
+        let guard_views: HashSet<Point> = self.guard_route().into_iter().collect();
+        guard_views.len()
+    }
+
+    /// Where a single new obstacle would send the guard round in a loop, in the order the guard reaches them.
+    fn loop_obstacles(&self) -> Vec<Point> {
+        // Every tile the guard steps onto for the first time could take the obstacle. The route up
+        // to there doesn't change, so each is checked on its own from where the guard stood before it.
+        let mut candidates = Vec::new();
+        let mut guard_views: HashMap<Point, HashSet<Direction>> = HashMap::new();
+        let mut guard_pos = self.guard_start_pos_and_dir;
+        add_to_guard_visited(&mut guard_views, &guard_pos);
+
+        loop {
+            match self.is_forward_blocked(&guard_pos) {
+                None => {
+                    break;
+                }
+                Some(true) => {
+                    guard_pos = Day06::turn_guard(guard_pos);
+                    add_to_guard_visited(&mut guard_views, &guard_pos);
+                },
+                Some(false) => {
+                    let forward_step = self.calc_next_pos_in_direction(&guard_pos).unwrap();
+                    if is_forward_available_for_obstacle_positioning(self, &guard_views, &guard_pos) {
+                        candidates.push((guard_pos, forward_step.0));
+                    }
+                    add_to_guard_visited(&mut guard_views, &forward_step);
+                    guard_pos = forward_step;
+                }
+            }
+        }
+        let loops = par_map(&candidates, |(guard_pos, obstacle)| Day06p2::new(self, *obstacle).check_for_deep_loop(guard_pos));
+        candidates.into_iter().zip(loops).filter(|(_, loops)| *loops).map(|((_, obstacle), _)| obstacle).collect()
+    }
+
+    pub fn part2(&self) -> u32 {
+        self.loop_obstacles().len() as u32
+    }
+}
+
+struct Day06p2<'a> {
+    day: &'a Day06,
+    added_obstacle: Point,
+}
+
+impl<'a> Day06p2<'a> {
+    pub fn new(day06: &'a Day06, added_obstacle: Point) -> Self {
+        Day06p2{day: day06, added_obstacle}
+    }
+
+    fn calc_next_pos_in_direction(&self, guard_pos: &(Point, Direction)) -> Option<(Point, Direction)> {
+        self.day.calc_next_pos_in_direction(guard_pos)
+    }
+
+    fn check_for_guard_visited_already(guard_views: &HashMap<Point, HashSet<Direction>>, guard_pos: (Point, Direction)) -> bool {
+        match guard_views.get(&guard_pos.0) {
+            None => false,
+            Some(directions) => {
+                directions.get(&guard_pos.1).is_some()
+            }
+        }
+    }
+
+    fn is_forward_blocked(&self, guard_pos: &(Point, Direction)) -> Option<bool> {
+        match self.day.is_forward_blocked(guard_pos) {
+            None => None,
+            Some(true) => Some(true),
+            Some(false) => {
+                let forward_pos = self.calc_next_pos_in_direction(guard_pos).unwrap();
+                Some(forward_pos.0 == self.added_obstacle)
+            }
+        }
+    }
+
+    fn check_for_deep_loop(&self, guard_pos: &(Point, Direction)) -> bool {
+        let mut guard_views: HashMap<Point, HashSet<Direction>> = HashMap::new();
+        let mut guard_pos = *guard_pos;
+        add_to_guard_visited(&mut guard_views, &guard_pos);
+        loop {
+            match self.is_forward_blocked(&guard_pos) {
+                None => {
+                    // End condition and no loop, because the guard has walked off the map
+                    return false;
+                },
+                Some(true) => {
+                    // Guard has to turn
+                    let new_guard_pos = Day06::turn_guard(guard_pos);
+                    add_to_guard_visited(&mut guard_views, &new_guard_pos);
+                    guard_pos = new_guard_pos;
+                },
+                Some(false) => {
+                    // Check if we take a step forward, if it has already been visited, if so, loop found, otherwise continue
+                    let forward_step = self.day.calc_next_pos_in_direction(&guard_pos).unwrap();
+                    if Self::check_for_guard_visited_already(&guard_views, forward_step) {
+                        return true;
+                    }
+                    add_to_guard_visited(&mut guard_views, &forward_step);
+                    guard_pos = forward_step;
+                }
+            }
+        }
+    }
+
+}
+
+fn is_forward_available_for_obstacle_positioning(day: &Day06, guard_views: &HashMap<Point, HashSet<Direction>>, guard_pos: &(Point, Direction)) -> bool {
+    let forward_pos = day.calc_next_pos_in_direction(guard_pos);
+    match forward_pos {
+        None => false,
+        Some(position) => {
+            let has_guard_visited = guard_views.get(&position.0).is_some();
+            let has_obstacle = day.obstacles[position.0];
+            !has_guard_visited && !has_obstacle
+        }
+    }
+}
+
+fn add_to_guard_visited(guard_views: &mut HashMap<Point, HashSet<Direction>>, guard_pos: &(Point, Direction)) {
+    let create_new_direction_set = || -> HashSet<Direction> {
+        let mut initial_hash = HashSet::<Direction>::new();
+        initial_hash.insert(guard_pos.1);
+        initial_hash
+    };
+
+    guard_views.entry(guard_pos.0).and_modify(|values| { values.insert(guard_pos.1); }).or_insert_with(create_new_direction_set);
+}
+
+
+impl Render for Day06 {
+    /// The guard's route growing step by step, then for part 2 the obstacles that would trap it.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let route = self.guard_route();
+        let mut frame = Frame::from_grid(&self.obstacles, |obstacle| if *obstacle { Colour::WALL } else { Colour::OPEN });
+        let mut frames = Vec::new();
+        let interval = frame_interval(route.len());
+        for (step, position) in route.iter().enumerate() {
+            frame.set(*position, Colour::VISITED);
+            if step % interval == 0 || step == route.len() - 1 {
+                let mut with_guard = frame.clone();
+                with_guard.set(*position, Colour::ROBOT);
+                frames.push(with_guard);
+            }
+        }
+        if part == 2 {
+            for obstacle in self.loop_obstacles() {
+                frame.set(obstacle, Colour::HIGHLIGHT);
+            }
+            frames.push(frame);
+        }
+        frames
+    }
+}
+
+/// The guard part way round the patrol, for stepping through it.
+#[derive(Clone)]
+pub struct Patrol {
+    guard: (Point, Direction),
+    visited: Grid<bool>,
+    visited_count: usize,
+    turns: usize,
+}
+
+impl Simulation for Day06 {
+    type State = Patrol;
+
+    fn start(&self, _part: u8) -> Patrol {
+        let guard = self.guard_start_pos_and_dir;
+        let mut visited = self.obstacles.map(|_| false);
+        visited[guard.0] = true;
+        Patrol{guard, visited, visited_count: 1, turns: 0}
+    }
+
+    fn step(&self, patrol: &mut Patrol) -> bool {
+        match self.is_forward_blocked(&patrol.guard) {
+            None => false,
+            Some(true) => {
+                patrol.guard = Day06::turn_guard(patrol.guard);
+                patrol.turns += 1;
+                true
+            },
+            Some(false) => {
+                patrol.guard = self.calc_next_pos_in_direction(&patrol.guard).unwrap();
+                if !patrol.visited[patrol.guard.0] {
+                    patrol.visited[patrol.guard.0] = true;
+                    patrol.visited_count += 1;
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, patrol: &Patrol) -> View {
+        let (guard, facing) = patrol.guard;
+        let lines = self.obstacles.rows().enumerate().map(|(y, row)| row.iter().enumerate().map(|(x, obstacle)| {
+            let position = Point::new(x, y);
+            if position == guard { facing.to_arrow().unwrap() }
+            else if *obstacle { '#' }
+            else if patrol.visited[position] { 'X' }
+            else { '.' }
+        }).collect()).collect();
+        View{lines, actor: Some(guard), panel: vec![
+            ("guard".to_string(), format!("{guard} facing {facing:?}")),
+            ("visited".to_string(), patrol.visited_count.to_string()),
+            ("turns".to_string(), patrol.turns.to_string()),
+        ]}
+    }
+}
+
+impl FromReader for Day06 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day06::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day06 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day06::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day06::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day06::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn part1_sample_results_in_41() {
+        let day = Day06::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(41, day.part1());
+    }
+
+    #[test]
+    fn part2_sample_results_in_6() {
+        let day = Day06::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(6, day.part2());
+    }
+
+    #[test]
+    fn render_shows_the_route_and_the_loop_obstacles() {
+        let day = Day06::new(SAMPLE_INPUT.lines()).unwrap();
+        let count = |frame: &Frame, colour: Colour| frame.rows().flatten().filter(|cell| **cell == colour).count();
+        let route = day.render(1);
+        assert_eq!(45, route.len());
+        assert_eq!(Some(Colour::ROBOT), route[0].get(Point::new(4, 6)));
+        assert_eq!(40, count(route.last().unwrap(), Colour::VISITED));
+        let loops = day.render(2);
+        assert_eq!(6, count(loops.last().unwrap(), Colour::HIGHLIGHT));
+    }
+
+    #[test]
+    fn stepping_through_the_patrol_visits_every_tile_of_the_route() {
+        let day = Day06::new(SAMPLE_INPUT.lines()).unwrap();
+        let mut patrol = day.start(1);
+        assert_eq!("....^.....", day.view(&patrol).lines[6].replace('#', "."));
+        assert!(day.step(&mut patrol));
+        assert_eq!(Some(Point::new(4, 5)), day.view(&patrol).actor);
+        while day.step(&mut patrol) {}
+        assert_eq!(41, patrol.visited_count);
+        assert_eq!(("visited".to_string(), "41".to_string()), day.view(&patrol).panel[1]);
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 3, "a row 4 wide", "")), Day06::new(".#..\n.^".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "one of `.`, `#` or a single `^`", "7")), Day06::new(".#..\n.^7.".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "one of `.`, `#` or a single `^`", "^")), Day06::new(".^..\n..^.".lines()).err());
+        assert_eq!(Some(ParseError::end_of_input(3, "a guard `^` on the map")), Day06::new(".#..\n....".lines()).err());
+    }
+}
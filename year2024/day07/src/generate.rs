@@ -0,0 +1,33 @@
+//! Made-up inputs for day 7: calibration equations, most of which some choice of operators makes true.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` equations built by applying random operators, with some test values nudged to be wrong.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    lines((0..size.max(1)).map(|_| {
+        let parts: Vec<u64> = (0..rng.range(2, 7)).map(|_| rng.range(1, 99)).collect();
+        let mut total = parts[0];
+        for part in &parts[1..] {
+            total = match rng.range(1, 3) {
+                1 => total + part,
+                2 => total * part,
+                _ => format!("{total}{part}").parse().unwrap(),
+            };
+        }
+        if rng.chance(30) { total += 1; }
+        format!("{total}: {}", parts.iter().map(u64::to_string).collect::<Vec<_>>().join(" "))
+    })).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day07;
+
+    #[test]
+    fn concatenation_calibrates_more() {
+        let day = Day07::new(generate(&mut Rng::new(7), 100).input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part2() > day.part1());
+    }
+}
@@ -0,0 +1,186 @@
+pub mod generate;
+
+use common::parallel::par_map;
+use common::{for_each_line, Answer, Explain, Explanation, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use std::io::BufRead;
+
+struct EquationParts {
+    sum: u64,
+    parts: Vec<u64>,
+}
+
+pub struct Day07 {
+    equations: Vec<EquationParts>,
+}
+
+impl Day07 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut equations: Vec<EquationParts> = Vec::new();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            equations.push(Self::equation(&line)?);
+        }
+        Ok(Day07{equations})
+    }
+
+    fn equation(line: &Line<'_>) -> Result<EquationParts, ParseError> {
+        let Some((sum, parts)) = line.text.split_once(':') else {
+            return Err(line.missing("`:` after the test value"));
+        };
+        let sum = line.parse(sum.trim(), "a test value")?;
+        let parts = parts.split_whitespace().map(|e| line.parse(e, "a number")).collect::<Result<Vec<u64>, _>>()?;
+        if parts.is_empty() {
+            return Err(line.missing("a number"));
+        }
+        Ok(EquationParts{sum, parts})
+    }
+
+    /// The operators, as indexes into `operators`, that make the parts left total the sum, in
+    /// reverse order.
+    fn operators_that_total_sum(sum: u64, current_value: u64, parts_left: &[u64], operators: &[fn(u64, u64)->u64]) -> Option<Vec<usize>> {
+        if current_value > sum {
+            None
+        }
+        else if parts_left.is_empty() {
+            (current_value == sum).then(Vec::new)
+        }
+        else {
+            for (index, operator) in operators.iter().enumerate() {
+                if let Some(mut chosen) = Self::operators_that_total_sum(sum, operator(current_value, parts_left[0]), &parts_left[1..], operators) {
+                    chosen.push(index);
+                    return Some(chosen);
+                }
+            }
+            None
+        }
+    }
+
+    fn multiplication(a: u64, b: u64) -> u64 { a * b }
+    fn addition(a: u64, b: u64) -> u64 { a + b }
+    fn concat(a: u64, b: u64) -> u64 {
+        format!("{}{}", a, b).parse::<u64>().unwrap()
+    }
+    /// The sum of the test values of the equations some choice of `operators` can make true.
+    fn calibration_total(&self, operators: &[fn(u64, u64)->u64]) -> u64 {
+        par_map(&self.equations, |equation| {
+            if Self::operators_that_total_sum(equation.sum, equation.parts[0], &(equation.parts[1..]), operators).is_some() { equation.sum } else { 0 }
+        }).into_iter().sum()
+    }
+
+    /// The operators each part may use, in the order they're tried.
+    fn operators(part: u8) -> Vec<fn(u64, u64)->u64> {
+        if part == 1 { vec![Self::addition, Self::multiplication] } else { vec![Self::addition, Self::multiplication, Self::concat] }
+    }
+
+    pub fn part1(&self) -> u64 {
+        self.calibration_total(&Self::operators(1))
+    }
+
+    pub fn part2(&self) -> u64 {
+        self.calibration_total(&Self::operators(2))
+    }
+}
+
+impl Explain for Day07 {
+    /// Each equation with the operators that make it true, left blank when none do.
+    fn explain(&self, part: u8) -> Explanation {
+        const SYMBOLS: [&str; 3] = ["+", "*", "||"];
+        let operators = Self::operators(part);
+        let mut explanation = Explanation::new(&["test value", "equation"]);
+        for equation in &self.equations {
+            let chosen = Self::operators_that_total_sum(equation.sum, equation.parts[0], &(equation.parts[1..]), &operators);
+            let working = match chosen {
+                Some(chosen) => {
+                    let mut working = equation.parts[0].to_string();
+                    for (index, part) in chosen.iter().rev().zip(&equation.parts[1..]) {
+                        working += &format!(" {} {part}", SYMBOLS[*index]);
+                    }
+                    working
+                },
+                None => String::new(),
+            };
+            explanation.row(vec![equation.sum.to_string(), working]);
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day07 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut equations: Vec<EquationParts> = Vec::new();
+        for_each_line(reader, |line| {
+            equations.push(Self::equation(&line)?);
+            Ok(())
+        })?;
+        Ok(Day07{equations})
+    }
+}
+
+impl Solution<'_> for Day07 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day07::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day07::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day07::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_LINES: &str =
+"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+    #[test]
+    fn sample_with_part1_is_3749() {
+        let day = Day07::new(SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(3749, day.part1());
+    }
+
+    #[test]
+    fn sample_with_part2_is_11387() {
+        let day = Day07::new(SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(11387, day.part2());
+    }
+
+    #[test]
+    fn explains_the_operators_that_work() {
+        let day = Day07::new(SAMPLE_LINES.lines()).unwrap();
+        let explanation = day.explain(2);
+        let equations: Vec<&str> = explanation.rows.iter().map(|row| row[1].as_str()).collect();
+        assert_eq!(vec!["10 * 19", "81 + 40 * 27", "", "15 || 6", "6 * 8 || 6 * 15", "", "17 || 8 + 14", "", "11 + 6 * 16 + 20"], equations);
+        assert_eq!("", day.explain(1).rows[3][1]);
+    }
+
+    #[test]
+    fn spaces_around_the_colon_are_ignored() {
+        let day = Day07::new("190: 10 19\n3267 :81  40 27\n83: 17 5".lines()).unwrap();
+        assert_eq!(3457, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 6, "a number", "")), Day07::new("190: 10 19\n3267:".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 10, "a number", "4o")), Day07::new("190: 10 19\n3267: 81 4o 27".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 1, "a test value", "19O")), Day07::new("19O: 10 19".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 10, "`:` after the test value", "")), Day07::new("190 10 19".lines()).err());
+    }
+
+    #[test]
+    fn streams_from_a_reader() {
+        let day = Day07::from_reader(SAMPLE_LINES.as_bytes()).unwrap();
+        assert_eq!(3749, day.part1());
+        assert!(matches!(Day07::from_reader("190: 10 19\n3267:".as_bytes()), Err(ReadError::Parse(e)) if e == ParseError::new(2, 6, "a number", "")));
+    }
+}
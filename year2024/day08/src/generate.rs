@@ -0,0 +1,29 @@
+//! Made-up inputs for day 8: a roof map with a handful of antennas for each of a few frequencies.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A map `size` tiles square, with four antennas for each of up to half that many frequencies.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let side = size.max(4);
+    let mut map = vec![vec!['.'; side]; side];
+    for frequency in FREQUENCIES.chars().take(side / 2) {
+        for _ in 0..4 {
+            map[rng.index(side)][rng.index(side)] = frequency;
+        }
+    }
+    lines(map.into_iter().map(|row| row.into_iter().collect())).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day08;
+
+    #[test]
+    fn resonant_harmonics_reach_further() {
+        let day = Day08::new(generate(&mut Rng::new(8), 30).input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part2() > day.part1());
+    }
+}
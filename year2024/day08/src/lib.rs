@@ -1,31 +1,39 @@
+pub mod generate;
+
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
 
 pub struct Day08 {
     antennas: HashMap<char,Vec<(usize, usize)>>,
     max_y: usize,
     max_x: usize,
-    consider_harmonics: bool,
 }
 
 impl Day08 {
-    pub fn new(lines: std::str::Lines<'_>, consider_harmonics: bool) -> Self {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
         let mut antennas = HashMap::new();
         let mut max_y = 0;
         let mut max_x = 0;
-        for (yindex, line) in lines.enumerate() {
+        for (yindex, line) in InputLines::new(lines).filter(|line| !line.is_blank()).enumerate() {
+            if yindex == 0 {
+                max_x = line.text.len();
+            }
+            line.expect_width(max_x)?;
             max_y += 1;
-            max_x = line.len();
-            for (xindex, a_char) in line.chars().enumerate() {
+            for (xindex, a_char) in line.text.char_indices() {
                 match a_char {
                     '.' => { /* no-op */ },
-                    val => {
+                    val if val.is_ascii_alphanumeric() => {
                         antennas.entry(val).or_insert(Vec::<(usize, usize)>::new()).push((yindex, xindex));
-                    }
+                    },
+                    _ => { return Err(line.char_error(xindex, "`.` or an antenna frequency")); }
                 }
             }
         }
-        Day08{antennas, max_y, max_x, consider_harmonics}
+        Ok(Day08{antennas, max_y, max_x})
     }
 
     fn is_possible_antinode_in_range(&self, possible_antinode: (i64, i64)) -> bool {
@@ -37,13 +45,13 @@ impl Day08 {
         antinodes.insert(antinode);
     }
 
-    fn calc_antinode_from(&self, a: &(usize, usize), b: &(usize, usize), antinodes: &mut HashSet<(usize, usize)>) {
+    fn calc_antinode_from(&self, a: &(usize, usize), b: &(usize, usize), antinodes: &mut HashSet<(usize, usize)>, consider_harmonics: bool) {
         let ydiff = b.0 as i64 - a.0 as i64;
         let xdiff = b.1 as i64 - a.1 as i64;
         let mut base_antinode= (b.0 as i64 + ydiff, b.1 as i64 + xdiff);
         if self.is_possible_antinode_in_range(base_antinode) {
             Self::add_possible_antinode(base_antinode, antinodes);
-            if self.consider_harmonics
+            if consider_harmonics
             {
                 loop {
                     let possible_antinode = (base_antinode.0 + ydiff, base_antinode.1 + xdiff);
@@ -58,37 +66,64 @@ impl Day08 {
             }
         }
     }
-    fn calc_antinodes(&self, a: &(usize, usize), b: &(usize, usize), antinodes: &mut HashSet<(usize, usize)>) {
-        self.calc_antinode_from(a, b, antinodes);
-        self.calc_antinode_from(b, a, antinodes);
+    fn calc_antinodes(&self, a: &(usize, usize), b: &(usize, usize), antinodes: &mut HashSet<(usize, usize)>, consider_harmonics: bool) {
+        self.calc_antinode_from(a, b, antinodes, consider_harmonics);
+        self.calc_antinode_from(b, a, antinodes, consider_harmonics);
     }
 
-    fn check_antenna_against_others(&self, antenna: &(usize, usize), others: &[(usize, usize)], antinodes: &mut HashSet<(usize, usize)>) {
+    fn check_antenna_against_others(&self, antenna: &(usize, usize), others: &[(usize, usize)], antinodes: &mut HashSet<(usize, usize)>, consider_harmonics: bool) {
         for other in others {
-            self.calc_antinodes(&antenna, &other, antinodes);
+            self.calc_antinodes(antenna, other, antinodes, consider_harmonics);
         }
     }
 
-    fn check_antennas_antinodes(&self, antennas: &Vec<(usize, usize)>, antinodes: &mut HashSet<(usize, usize)>) {
+    fn check_antennas_antinodes(&self, antennas: &[(usize, usize)], antinodes: &mut HashSet<(usize, usize)>, consider_harmonics: bool) {
         // pick all pairs of the antennas at a time to determine antinodes for that pair
-        for (index, antenna) in antennas.into_iter().enumerate() {
-            if self.consider_harmonics {
+        for (index, antenna) in antennas.iter().enumerate() {
+            if consider_harmonics {
                 antinodes.insert(*antenna);
             }
-            self.check_antenna_against_others(&antenna, &antennas[index+1..], antinodes);
+            self.check_antenna_against_others(antenna, &antennas[index+1..], antinodes, consider_harmonics);
         }
     }
 
-    pub fn part1(&self) -> usize {
+    fn count_antinodes(&self, consider_harmonics: bool) -> usize {
         let mut antinodes = HashSet::<(usize, usize)>::new();
         for antenna_type in &self.antennas {
             if antenna_type.1.len() > 1 {
-                self.check_antennas_antinodes(antenna_type.1, &mut antinodes);
+                self.check_antennas_antinodes(antenna_type.1, &mut antinodes, consider_harmonics);
             }
         }
         antinodes.len()
     }
 
+    pub fn part1(&self) -> usize {
+        self.count_antinodes(false)
+    }
+
+    pub fn part2(&self) -> usize {
+        self.count_antinodes(true)
+    }
+}
+
+impl FromReader for Day08 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day08::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day08 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day08::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day08::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day08::part2(self).into()
+    }
 }
 
 #[cfg(test)]
@@ -110,14 +145,14 @@ mod tests {
 
     #[test]
     fn sample_with_part1_is_14() {
-        let day = Day08::new(SAMPLE_LINES.lines(), false);
+        let day = Day08::new(SAMPLE_LINES.lines()).unwrap();
         assert_eq!(14, day.part1());
     }
 
     #[test]
     fn sample_with_part2_is_34() {
-        let day  = Day08::new(SAMPLE_LINES.lines(), true);
-        assert_eq!(34, day.part1());
+        let day  = Day08::new(SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(34, day.part2());
     }
 
     #[test]
@@ -133,7 +168,13 @@ mod tests {
 ..........
 ..........
 ..........";
-        let day = Day08::new(PART2_SAMPLE_LINES.lines(), true);
-        assert_eq!(9, day.part1());
+        let day = Day08::new(PART2_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(9, day.part2());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 4, "a row 5 wide", "")), Day08::new(".....\n.A.".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "`.` or an antenna frequency", "#")), Day08::new(".....\n.A#..".lines()).err());
     }
 }
@@ -0,0 +1,25 @@
+//! Made-up inputs for day 9: a disk map of alternating file and free space lengths.
+
+use common::synthetic::{Rng, Synthetic};
+
+/// A disk map of `size` digits, rounded up to odd so it starts and ends with a file.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let digits = size | 1;
+    let map: String = (0..digits).map(|index| if index % 2 == 0 { rng.range(1, 9) } else { rng.range(0, 9) }.to_string()).collect();
+    (map + "\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day09;
+
+    #[test]
+    fn files_at_both_ends() {
+        let synthetic = generate(&mut Rng::new(9), 100);
+        let map = synthetic.input.trim_end();
+        assert_eq!(101, map.len());
+        assert!(!map.starts_with('0') && !map.ends_with('0'));
+        assert!(Day09::new(synthetic.input.lines()).unwrap().part1() > 0);
+    }
+}
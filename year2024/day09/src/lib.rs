@@ -1,9 +1,15 @@
+pub mod generate;
+
 use std::collections::VecDeque;
 use std::collections::HashMap;
 use std::collections::BinaryHeap;
 use std::cmp::Reverse;
+use std::io::BufRead;
 
-pub struct Day09 {
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
+
+/// The disk as individual blocks, for moving single blocks into the leftmost free space.
+struct BlockCompaction {
     free_list: VecDeque<usize>,
     file_list: HashMap<usize, Vec<usize>>,
     sector_list: Vec<Option<usize>>,
@@ -25,8 +31,8 @@ fn determine_num_blocks(a_char: char) -> Option<usize> {
     }
 }
 
-impl Day09 {
-    pub fn new(line: &str) -> Self {
+impl BlockCompaction {
+    fn new(line: &str) -> Self {
         let mut file_id: usize = 0;
         let mut disk_sector: usize = 0;
         let mut free_list = VecDeque::<usize>::new();
@@ -56,7 +62,7 @@ impl Day09 {
             }
             assert_eq!(disk_sector, sector_list.len());
         }
-        Day09{free_list, file_list, sector_list}
+        BlockCompaction{free_list, file_list, sector_list}
     }
 
     fn get_back_file_sector_id(sector_list: &mut Vec<Option<usize>>, free_list: &mut VecDeque<usize>) -> Option<(usize, usize)> {
@@ -66,8 +72,10 @@ impl Day09 {
             match sector_list.pop() {
                 None => { return None; }
                 Some(None) => {
-                    let back_free = free_list.pop_back().unwrap();
-                    assert_eq!(back_free, last_sector);
+                    // the sector being filled has already been taken off the front of the free list
+                    if free_list.back() == Some(&last_sector) {
+                        free_list.pop_back();
+                    }
                 },
                 Some(Some(possible_file_id)) => {
                     return Some((possible_file_id, last_sector));
@@ -75,7 +83,7 @@ impl Day09 {
             }
         }
     }
-    fn find_sector_and_replace(sectors: &mut Vec<usize>, old_sector_id: usize, new_sector_id: usize) {
+    fn find_sector_and_replace(sectors: &mut [usize], old_sector_id: usize, new_sector_id: usize) {
         for elem in sectors.iter_mut() {
             if *elem == old_sector_id { *elem = new_sector_id; }
         }
@@ -84,7 +92,7 @@ impl Day09 {
         file_list.entry(file_id).and_modify(|v| { Self::find_sector_and_replace(v, old_sector_id, new_sector_id); });
     }
 
-    pub fn part1(&self) -> usize {
+    fn checksum(&self) -> usize {
         let mut free_list = self.free_list.clone();
         let mut sector_list = self.sector_list.clone();
         let mut file_list = self.file_list.clone();
@@ -95,6 +103,11 @@ impl Day09 {
                 Some(sector_id) => {
                     assert_eq!(sector_list[sector_id], None);
                     let (back_sector_file_id, old_sector_id) = Self::get_back_file_sector_id(&mut sector_list, &mut free_list).unwrap();
+                    if old_sector_id < sector_id {
+                        // the free space ran to the end of the disk, so everything's already compact
+                        sector_list.push(Some(back_sector_file_id));
+                        break;
+                    }
                     Self::update_file_list(&mut file_list, back_sector_file_id, old_sector_id, sector_id);
                     sector_list[sector_id] = Some(back_sector_file_id);
                 }
@@ -111,15 +124,16 @@ impl Day09 {
     }
 }
 
-pub struct Day09p2 {
+/// The disk as whole files, for moving each file into the leftmost free span it fits in.
+struct FileCompaction {
     free_list: HashMap<usize, BinaryHeap<Reverse<usize>>>,
     file_list: HashMap<usize, Vec<usize>>,
     sector_list: Vec<Option<usize>>,
     largest_file_id: usize,
 }
 
-impl Day09p2 {
-    pub fn new(line: &str) -> Self {
+impl FileCompaction {
+    fn new(line: &str) -> Self {
         let mut file_id: usize = 0;
         let mut disk_sector: usize = 0;
         let mut free_list = HashMap::<usize, BinaryHeap::<Reverse<usize>>>::new();
@@ -141,7 +155,7 @@ impl Day09p2 {
                 disk_sector += num_blocks;
             }
             else {
-                free_list.entry(num_blocks).or_insert(BinaryHeap::new()).push(Reverse(disk_sector));
+                free_list.entry(num_blocks).or_default().push(Reverse(disk_sector));
                 for _i in 0..num_blocks {
                     sector_list.push(None);
                 }
@@ -149,7 +163,7 @@ impl Day09p2 {
             }
             assert_eq!(disk_sector, sector_list.len());
         }
-        Day09p2{free_list, file_list, sector_list, largest_file_id: file_id}
+        FileCompaction{free_list, file_list, sector_list, largest_file_id: file_id}
     }
 
     fn find_best_free_space(space_needed: usize, file_sectors_start: usize, free_list_sizes: &mut HashMap<usize, BinaryHeap<Reverse<usize>>>) -> Option<usize> {
@@ -174,7 +188,7 @@ impl Day09p2 {
             Some((left_spot, size_free_list)) => { if file_sectors_start > left_spot { Some(size_free_list) } else { None } }
         }
     }
-    fn try_move_file(file_id: usize, file_sectors: &mut Vec<usize>, sector_list: &mut Vec<Option<usize>>, free_list: &mut HashMap<usize, BinaryHeap<Reverse<usize>>>) {
+    fn try_move_file(file_id: usize, file_sectors: &mut [usize], sector_list: &mut [Option<usize>], free_list: &mut HashMap<usize, BinaryHeap<Reverse<usize>>>) {
         if let Some(free_entry_size) = Self::find_best_free_space(file_sectors.len(), file_sectors[0], free_list) {
             if let Some(Reverse(free_list_start)) = free_list.get_mut(&free_entry_size).unwrap().pop() {
                 for sector in &file_sectors[..] {
@@ -189,19 +203,19 @@ impl Day09p2 {
                 if free_entry_size > file_sectors.len() {
                     let free_entry_left = free_entry_size - file_sectors.len();
                     let new_free_list_start = free_list_start + file_sectors.len();
-                    free_list.entry(free_entry_left).or_insert(BinaryHeap::new()).push(Reverse(new_free_list_start));
+                    free_list.entry(free_entry_left).or_default().push(Reverse(new_free_list_start));
                 }
             }
         }
     }
 
-    pub fn part2(&self) -> usize {
+    fn checksum(&self) -> usize {
         let mut free_list = self.free_list.clone();
         let mut sector_list = self.sector_list.clone();
         let mut file_list = self.file_list.clone();
 
         for id in (1..self.largest_file_id).rev() {
-            Self::try_move_file(id, &mut file_list.get_mut(&id).unwrap(), &mut sector_list, &mut free_list);
+            Self::try_move_file(id, file_list.get_mut(&id).unwrap(), &mut sector_list, &mut free_list);
         }
 
         let mut checksum = 0;
@@ -216,19 +230,78 @@ impl Day09p2 {
     }
 }
 
+pub struct Day09 {
+    block_compaction: BlockCompaction,
+    file_compaction: FileCompaction,
+}
+
+impl Day09 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut lines = InputLines::new(lines);
+        let line = lines.expect_non_blank("a disk map")?;
+        if let Some((index, _)) = line.text.char_indices().find(|(_, a_char)| determine_num_blocks(*a_char).is_none()) {
+            return Err(line.char_error(index, "a digit"));
+        }
+        lines.end()?;
+        Ok(Day09{block_compaction: BlockCompaction::new(line.text), file_compaction: FileCompaction::new(line.text)})
+    }
+
+    pub fn part1(&self) -> usize {
+        self.block_compaction.checksum()
+    }
+
+    pub fn part2(&self) -> usize {
+        self.file_compaction.checksum()
+    }
+}
+
+impl FromReader for Day09 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day09::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day09 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day09::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day09::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day09::part2(self).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     const SAMPLE_LINE: &str = "2333133121414131402";
     #[test]
     fn sample_input_part1_is_1928() {
-        let day = Day09::new(SAMPLE_LINE);
+        let day = Day09::new(SAMPLE_LINE.lines()).unwrap();
         assert_eq!(1928, day.part1());
     }
 
     #[test]
     fn sample_input_part2_is_2858() {
-        let day = Day09p2::new(SAMPLE_LINE);
+        let day = Day09::new(SAMPLE_LINE.lines()).unwrap();
         assert_eq!(2858, day.part2());
     }
+
+    #[test]
+    fn part1_copes_with_free_space_left_at_the_end() {
+        assert_eq!(1, Day09::new("121".lines()).unwrap().part1());
+        assert_eq!(1, Day09::new("1311".lines()).unwrap().part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(2, "a disk map")), Day09::new("\n".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 6, "a digit", "x")), Day09::new("23331x3121414131402".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 5, "a digit", " ")), Day09::new("2333 133121414131402".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 1, "end of input", "12")), Day09::new("2333133121414131402\n12".lines()).err());
+    }
 }
@@ -0,0 +1,22 @@
+//! Made-up inputs for day 10: a topographic map of random heights.
+
+use common::synthetic::{letter_grid, Rng, Synthetic};
+
+/// A map `size` tiles square of heights from 0 to 9.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    letter_grid(rng, size.max(1), "0123456789").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day10;
+
+    #[test]
+    fn every_tile_has_a_height() {
+        let synthetic = generate(&mut Rng::new(10), 20);
+        assert!(synthetic.input.lines().all(|line| line.len() == 20 && line.chars().all(|c| c.is_ascii_digit())));
+        let day = Day10::new(synthetic.input.lines()).unwrap();
+        assert!(day.part2() >= day.part1());
+    }
+}
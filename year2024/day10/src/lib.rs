@@ -0,0 +1,113 @@
+pub mod generate;
+
+use common::parallel::par_map;
+use common::{read_input, Answer, FromReader, ParseError, ReadError, Solution};
+use std::io::BufRead;
+use grid::{Grid, Point};
+use search::{bfs_distances, count_paths};
+
+pub struct Day10 {
+    topography: Grid<i8>,
+    trailheads: Vec<Point>,
+}
+
+fn char_to_num(a_char: char) -> Option<i8> {
+    a_char.to_digit(10).map(|digit| digit as i8)
+}
+
+impl Day10 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let topography = Grid::parse(lines, "a height from 0 to 9", |_, a_char| char_to_num(a_char))?;
+        let trailheads = topography.positions(|height| *height == 0).collect();
+        Ok(Day10{topography, trailheads})
+    }
+
+    fn gradual_climbs(&self, pos: &Point) -> Vec<Point> {
+        let next_target = self.topography[*pos] + 1;
+        self.topography.neighbours(*pos).map(|(_, neighbor)| neighbor).filter(|neighbor| self.topography[*neighbor] == next_target).collect()
+    }
+
+    fn is_peak(&self, pos: &Point) -> bool {
+        self.topography[*pos] == 9
+    }
+
+    fn score_trailhead(&self, trailhead: Point) -> usize {
+        bfs_distances(trailhead, |pos| self.gradual_climbs(pos)).keys().filter(|pos| self.is_peak(pos)).count()
+    }
+
+    pub fn part1(&self) -> usize {
+        par_map(&self.trailheads, |trailhead| self.score_trailhead(*trailhead)).into_iter().sum()
+    }
+
+    fn rate_trailhead(&self, trailhead: Point) -> usize {
+        count_paths(trailhead, |pos| self.gradual_climbs(pos), |pos| self.is_peak(pos))
+    }
+
+    pub fn part2(&self) -> usize {
+        par_map(&self.trailheads, |trailhead| self.rate_trailhead(*trailhead)).into_iter().sum()
+    }
+}
+
+impl FromReader for Day10 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day10::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day10 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day10::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day10::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day10::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_sample1_is_1() {
+        const SAMPLE_LINES: &str =
+"0123
+1234
+8765
+9876";
+        let day = Day10::new(SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(1, day.part1());
+    }
+
+    const LARGER_SAMPLE_LINES: &str =
+"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn part1_larger_sample_is_36() {
+        let day = Day10::new(LARGER_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(36, day.part1());
+    }
+
+    #[test]
+    fn part2_larger_sample_is_81() {
+        let day = Day10::new(LARGER_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(81, day.part2());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(3, 3, "a row 4 wide", "")), Day10::new("0123\n1234\n87".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 2, "a height from 0 to 9", ".")), Day10::new("0123\n1.34".lines()).err());
+    }
+}
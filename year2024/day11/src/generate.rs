@@ -0,0 +1,21 @@
+//! Made-up inputs for day 11: a line of stones with numbers engraved on them.
+
+use common::synthetic::{Rng, Synthetic};
+
+/// `size` stones, numbered up to 999999.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    ((0..size.max(1)).map(|_| rng.range(0, 999_999).to_string()).collect::<Vec<_>>().join(" ") + "\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day11;
+
+    #[test]
+    fn stones_multiply() {
+        let synthetic = generate(&mut Rng::new(11), 8);
+        assert_eq!(8, synthetic.input.split_whitespace().count());
+        assert!(Day11::new(synthetic.input.lines()).unwrap().part1() > 8);
+    }
+}
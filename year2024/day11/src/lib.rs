@@ -0,0 +1,191 @@
+pub mod generate;
+
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub struct Day11 {
+    stones: Vec<u64>
+}
+
+impl Day11 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut lines = InputLines::new(lines);
+        let line = lines.expect_non_blank("a line of stones")?;
+        let stones = line.text.split_whitespace().map(|v| line.parse(v, "a stone number")).collect::<Result<_, _>>()?;
+        lines.end()?;
+        Ok(Day11{stones})
+    }
+
+    pub fn blink(stones: &[u64]) -> Vec<u64> {
+        let mut answer = Vec::new();
+        for stone in stones {
+            if 0 == *stone {
+                answer.push(1);
+            } else {
+                let decimal_number = format!("{}", *stone);
+                if decimal_number.len() % 2 == 0 {
+                    let half_number = decimal_number.len() / 2;
+                    answer.push(decimal_number[0..half_number].parse::<u64>().unwrap());
+                    answer.push(decimal_number[half_number..].parse::<u64>().unwrap());
+                } else {
+                    answer.push(*stone * 2024);
+                }
+            }
+        }
+        answer
+    }
+
+    pub fn blink_n_times(condition: &[u64], n: usize) -> Vec<u64> {
+        let mut answer = condition.to_vec();
+        for _ in 0..n {
+            answer = Self::blink(&answer);
+        }
+        answer
+    }
+
+    pub fn part1(&self) -> usize {
+        Self::blink_n_times(&self.stones, 25).len()
+    }
+
+    fn count_num_of_stone_values(stones: &[u64]) -> HashMap<u64, u64> {
+        let mut stone_num_to_count: HashMap<u64, u64> = HashMap::new();
+        for stone in stones {
+            stone_num_to_count.entry(*stone).and_modify(|v| *v += 1).or_insert(1);
+        }
+        stone_num_to_count
+    }
+
+    /// How many stones there are after `rounds` rounds of `blinks` blinks each. We don't care
+    /// about the order of the stones, so after the first round each distinct number is blinked
+    /// once and the numbers it turns into are cached for the rounds after.
+    fn count_after_rounds(stones: &[u64], blinks: usize, rounds: usize) -> u64 {
+        let mut stone_num_to_count = Self::count_num_of_stone_values(&Self::blink_n_times(stones, blinks));
+        let mut cache: HashMap<u64, HashMap<u64, u64>> = HashMap::new();
+
+        for _ in 1..rounds {
+            let mut next_stone_num_to_count: HashMap<u64, u64> = HashMap::new();
+            for (stone_num, count) in stone_num_to_count {
+                let result = cache.entry(stone_num).or_insert_with(|| Self::count_num_of_stone_values(&Self::blink_n_times(&[stone_num], blinks)));
+                for (result_stone, result_count) in result {
+                    let val = next_stone_num_to_count.entry(*result_stone).or_insert(0);
+                    *val += *result_count * count;
+                }
+            }
+            stone_num_to_count = next_stone_num_to_count;
+        }
+
+        stone_num_to_count.values().sum()
+    }
+
+    pub fn part2(&self) -> u64 {
+        // we need to repeat 75 times, as three rounds of 25
+        Self::count_after_rounds(&self.stones, 25, 3)
+    }
+}
+
+impl FromReader for Day11 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day11::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day11 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day11::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day11::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day11::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// The rules worked with digits rather than strings.
+    fn blink_reference(stone: u64) -> Vec<u64> {
+        if stone == 0 { return vec![1]; }
+        let digits = stone.ilog10() + 1;
+        if digits.is_multiple_of(2) {
+            let half = 10u64.pow(digits / 2);
+            vec![stone / half, stone % half]
+        } else {
+            vec![stone * 2024]
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn blink_follows_the_rules(stones in prop::collection::vec(0..u64::MAX / 2024, 1..20)) {
+            let expected: Vec<u64> = stones.iter().flat_map(|stone| blink_reference(*stone)).collect();
+            prop_assert_eq!(expected, Day11::blink(&stones));
+        }
+
+        #[test]
+        fn cached_rounds_count_every_stone(stones in prop::collection::vec(0..100_000u64, 1..4), blinks in 1..6usize, rounds in 1..4usize) {
+            prop_assert_eq!(Day11::blink_n_times(&stones, blinks * rounds).len() as u64, Day11::count_after_rounds(&stones, blinks, rounds));
+        }
+    }
+
+    #[test]
+    fn test_one_blink_example() {
+        assert_eq!(vec![1, 2024, 1, 0, 9, 9, 2021976], Day11::blink(&[0, 1, 10, 99, 999]))
+    }
+
+
+    #[test]
+    fn larger_test_one_blink() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![253000, 1, 7], Day11::blink(&larger_example));
+    }
+
+    #[test]
+    fn larger_test_two_blinks() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![253, 0, 2024, 14168], Day11::blink_n_times(&larger_example, 2));
+    }
+
+    #[test]
+    fn larger_test_three_blinks() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![512072, 1, 20, 24, 28676032], Day11::blink_n_times(&larger_example, 3));
+    }
+
+    #[test]
+    fn larger_test_four_blinks() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![512, 72, 2024, 2, 0, 2, 4, 2867, 6032], Day11::blink_n_times(&larger_example, 4));
+    }
+
+    #[test]
+    fn larger_test_five_blinks() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![1036288, 7, 2, 20, 24, 4048, 1, 4048, 8096, 28, 67, 60, 32], Day11::blink_n_times(&larger_example, 5));
+    }
+
+    #[test]
+    fn larger_test_six_blinks() {
+        let larger_example: Vec<u64> = vec![125, 17];
+        assert_eq!(vec![2097446912, 14168, 4048, 2, 0, 2, 4, 40, 48, 2024, 40, 48, 80, 96, 2, 8, 6, 7, 6, 0, 3, 2], Day11::blink_n_times(&larger_example, 6));
+    }
+
+    #[test]
+    fn test_part1_with_larger_example_has_55312_stones() {
+        let day = Day11::new("125 17".lines()).unwrap();
+        assert_eq!(55312, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(1, "a line of stones")), Day11::new("".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 5, "a stone number", "-17")), Day11::new("125 -17".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 1, "end of input", "4")), Day11::new("125 17\n4".lines()).err());
+    }
+}
\ No newline at end of file
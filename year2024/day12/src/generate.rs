@@ -0,0 +1,21 @@
+//! Made-up inputs for day 12: a garden of plots growing a few kinds of plant.
+
+use common::synthetic::{letter_grid, Rng, Synthetic};
+
+/// A garden `size` plots square, from five kinds of plant.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    letter_grid(rng, size.max(1), "ABCDE").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day12;
+
+    #[test]
+    fn bulk_discount_is_cheaper() {
+        let day = Day12::new(generate(&mut Rng::new(12), 20).input.lines()).unwrap();
+        assert!(day.part2() > 0);
+        assert!(day.part2() < day.part1());
+    }
+}
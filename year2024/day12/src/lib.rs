@@ -0,0 +1,312 @@
+pub mod generate;
+
+use common::{read_input, Answer, FromReader, ParseError, ReadError, Solution};
+use grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+pub struct Day12 {
+    garden_map: Grid<char>,
+}
+
+impl Day12 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let garden_map = Grid::parse(lines, "a plant letter", |_, plant| plant.is_ascii_uppercase().then_some(plant))?;
+        Ok(Day12{garden_map})
+    }
+
+    fn get_neighbors(&self, plot_loc: &Point) -> Vec<(Option<Point>, Direction)> {
+        Direction::CARDINAL.into_iter().map(|dir| (self.garden_map.step(*plot_loc, dir), dir)).collect()
+    }
+
+    fn find_connected_plots_and_perimeter(&self, garden_plant: char, plot_loc: &Point) -> (HashSet<Point>, usize) {
+        let mut perimeter: usize = 0;  // top side and left side should be a given at the first location, but every subsequent neighbor needs to look left (after we go down)
+        let mut plots_to_check: VecDeque<Point> = VecDeque::new();
+        plots_to_check.push_back(*plot_loc);
+        let mut garden_section_plots: HashSet<Point> = HashSet::new();
+
+        while !plots_to_check.is_empty() {
+            let plot = plots_to_check.pop_front().unwrap();
+            if !garden_section_plots.contains(&plot) {
+                garden_section_plots.insert(plot);
+
+                let neighbors: Vec<Point> = self.garden_map.neighbours(plot).map(|(_dir, neighbor)| neighbor).collect();
+                perimeter += 4 - neighbors.len(); // provides for 'perimeter' on edges of map
+
+                for neighbor in neighbors {
+                    if self.garden_map[neighbor] != garden_plant {
+                        perimeter += 1;
+                    } else if !garden_section_plots.contains(&neighbor) {
+                        plots_to_check.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        (garden_section_plots, perimeter)
+    }
+
+    fn count_perimeter_sides(&self, plots_with_fences_adjacent: HashMap<Point, HashSet<Direction>>, plot_loc: &Point) -> usize {
+        assert!(plots_with_fences_adjacent.get(plot_loc).unwrap().contains(&Direction::North));
+        assert!(plots_with_fences_adjacent.get(plot_loc).unwrap().contains(&Direction::West));
+    
+        struct FenceGraph {
+            nodes: HashSet<Point>,
+            edges: HashSet<(Point, Point)>,
+            nodes_edges: HashMap<Point, HashSet<(Point, Point)>>,
+        }
+
+        fn get_fence_node(plot: Point, fence_side: Direction) -> (Point, Point) {
+            let (x, y) = (plot.x, plot.y);
+            match fence_side {
+                Direction::North => (Point::new(x,   y), Point::new(x+1,   y)),
+                Direction::East  => (Point::new(x+1, y), Point::new(x+1, y+1)),
+                Direction::South => (Point::new(x,   y+1), Point::new(x+1, y+1)),
+                Direction::West  => (Point::new(x,   y), Point::new(x,   y+1)),
+                _ => unreachable!("fences only run along the four sides of a plot"),
+            }
+        }
+        fn get_fence_edges(plot: Point, fence_sides: HashSet<Direction>) -> Vec<(Point, Point)> {
+            let mut fence_edges: Vec<(Point, Point)> = Vec::new();
+            for fence_side in fence_sides {
+                fence_edges.push(get_fence_node(plot, fence_side));
+            }
+            fence_edges
+        }
+        let mut fence_graph: FenceGraph = FenceGraph{nodes: HashSet::new(), edges: HashSet::new(), nodes_edges: HashMap::new()};
+
+        for (plot, fences_adjacent) in plots_with_fences_adjacent {
+            let fence_edges = get_fence_edges(plot, fences_adjacent);
+            for fence_edge in fence_edges {
+                fence_graph.nodes.insert(fence_edge.0);
+                fence_graph.nodes.insert(fence_edge.1);
+                fence_graph.edges.insert(fence_edge);
+                fence_graph.nodes_edges.entry(fence_edge.0).or_default().insert(fence_edge);
+                fence_graph.nodes_edges.entry(fence_edge.1).or_default().insert(fence_edge);
+            }
+        }
+
+        fn remove_fence_graph_node(fence_graph: &mut FenceGraph, node_to_remove: Point) {
+            let nodes_edges_to_remove: Vec<(Point, Point)> = fence_graph.nodes_edges.get(&node_to_remove).unwrap().clone().into_iter().collect();
+            let edge1 = nodes_edges_to_remove[0];
+            let edge2 = nodes_edges_to_remove[1];
+            let new_edge_left = if edge1.0 == node_to_remove { edge1.1 } else { edge1.0 };
+            let new_edge_right = if edge2.0 == node_to_remove { edge2.1 } else { edge2.0 };
+            let new_edge: (Point, Point) = (new_edge_left, new_edge_right);
+            fence_graph.nodes_edges.remove(&node_to_remove);
+            fence_graph.edges.remove(&edge1);
+            fence_graph.edges.remove(&edge2);
+            fence_graph.nodes.remove(&node_to_remove);
+            fence_graph.nodes_edges.entry(new_edge_left).and_modify(|v| { v.remove(&edge1); v.insert(new_edge); });
+            fence_graph.nodes_edges.entry(new_edge_right).and_modify(|v| { v.remove(&edge2); v.insert(new_edge); });
+            fence_graph.edges.insert(new_edge);
+        }
+
+        fn attempt_reduce_fence_graph(fence_graph: &mut FenceGraph, node_to_test: Point) {
+            match fence_graph.nodes_edges.get(&node_to_test) {
+                None => { panic!("Missing node edges for node: {:?}", node_to_test); }
+                Some(edges) => {
+                    assert!(edges.len() == 2 || edges.len() == 4);
+                    if edges.len() == 2 {
+                        let node_edges_to_test: Vec<(Point, Point)> = fence_graph.nodes_edges.get(&node_to_test).unwrap().clone().into_iter().collect();
+                        let left_node = if node_edges_to_test[0].0 == node_to_test { node_edges_to_test[0].1 } else { node_edges_to_test[0].0 };
+                        let right_node = if node_edges_to_test[1].0 == node_to_test { node_edges_to_test[1].1 } else { node_edges_to_test[1].0 };
+                        if (left_node.y == node_to_test.y && node_to_test.y == right_node.y)
+                           || (left_node.x == node_to_test.x && node_to_test.x == right_node.x ) {
+                            remove_fence_graph_node(fence_graph, node_to_test);
+                        }
+                    }
+                }
+            }
+        }
+
+        let nodes = fence_graph.nodes.clone();
+        for node in nodes {
+            attempt_reduce_fence_graph(&mut fence_graph, node);
+        }
+        fence_graph.edges.len()
+    }
+
+    fn find_connected_plots_and_perimeter_fencing(&self, garden_plant: char, plot_loc: &Point) -> (HashSet<Point>, usize) {
+        let mut consumed_garden_plots: HashSet<Point> = HashSet::new();
+        let mut plots_to_check: VecDeque<Point> = VecDeque::new();
+        let mut perimeter_fence_sections: HashMap<Point, HashSet<Direction>> = HashMap::new();
+
+        plots_to_check.push_back(*plot_loc);
+        
+        while !plots_to_check.is_empty() {
+            let plot = plots_to_check.pop_front().unwrap();
+            if !consumed_garden_plots.contains(&plot) {
+                consumed_garden_plots.insert(plot);
+                let neighbors = self.get_neighbors(&plot);
+                for (neighbor, dir) in neighbors {
+                    match neighbor {
+                        None => { perimeter_fence_sections.entry(plot).or_default().insert(dir); },
+                        Some(neighbor_plot) => {
+                            if self.garden_map[neighbor_plot] != garden_plant {
+                                perimeter_fence_sections.entry(plot).or_default().insert(dir);
+                            }
+                            else if !consumed_garden_plots.contains(&neighbor_plot) {
+                                plots_to_check.push_back(neighbor_plot);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let perimeter_sides = self.count_perimeter_sides(perimeter_fence_sections, plot_loc);
+
+        (consumed_garden_plots, perimeter_sides)
+    }
+
+    pub fn part1(&self) -> usize {
+        let mut used_plots: HashSet<Point> = HashSet::new();
+        let mut score: usize = 0;
+
+        for (plot_loc, garden_plant) in self.garden_map.iter() {
+            if !used_plots.contains(&plot_loc) {
+                let (garden_section_plots, perimeter) = self.find_connected_plots_and_perimeter(*garden_plant, &plot_loc);
+                let area = garden_section_plots.len();
+                score += area * perimeter;
+                for plot in garden_section_plots {
+                    used_plots.insert(plot);
+                }
+            }
+        }
+        score
+    }
+
+
+
+    pub fn part2(&self) -> usize {
+        let mut score: usize = 0;
+        let mut used_plots: HashSet<Point> = HashSet::new();
+
+        for (plot_loc, garden_plant) in self.garden_map.iter() {
+            if !used_plots.contains(&plot_loc) {
+                let (garden_section_plots, perimeter_sides) = self.find_connected_plots_and_perimeter_fencing(*garden_plant, &plot_loc);
+                let area = garden_section_plots.len();
+                score += area * perimeter_sides;
+                for plot in garden_section_plots {
+                    used_plots.insert(plot);
+                }
+            }
+        }
+        score
+    }
+}
+
+impl FromReader for Day12 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day12::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day12 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day12::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day12::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day12::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SMALL_SAMPLE_LINES: &str =
+"AAAA
+BBCD
+BBCC
+EEEC";
+    const HOLEY_SAMPLE_LINES: &str =
+"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+const FULL_SAMPLE_LINES: &str =
+"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn first_small_sample_part1_is_140() {
+        let day = Day12::new(SMALL_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(140, day.part1());
+    }
+
+    #[test]
+    fn second_holey_sample_part1_is_772() {
+        let day = Day12::new(HOLEY_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(772, day.part1());
+    }
+
+    #[test]
+    fn third_full_sample_part1_is_1930() {
+        let day = Day12::new(FULL_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(1930, day.part1());
+    }
+
+    #[test]
+    fn small_sample_part2_is_80() {
+        let day = Day12::new(SMALL_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(80, day.part2());
+    }
+
+    #[test]
+    fn holey_sample_part2_is_436() {
+        let day = Day12::new(HOLEY_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(436, day.part2());
+    }
+
+    #[test]
+    fn full_sample_part2_is_1206() {
+        let day = Day12::new(FULL_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(1206, day.part2());
+    }
+
+    #[test]
+    fn e_sample_part2_is_236() {
+        const E_SAMPLE_LINES: &str =
+"EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+        let day = Day12::new(E_SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(236, day.part2());
+    }
+
+    #[test]
+    fn diagonal_stress_part2_is_368() {
+        const DIAGONAL_STRESS_LINES: &str = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+        let day = Day12::new(DIAGONAL_STRESS_LINES.lines()).unwrap();
+        assert_eq!(368, day.part2());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 3, "a row 4 wide", "")), Day12::new("AAAA\nBB".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "a plant letter", "7")), Day12::new("AAAA\nBB7D".lines()).err());
+    }
+}
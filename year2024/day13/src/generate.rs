@@ -0,0 +1,37 @@
+//! Made-up inputs for day 13: claw machines, some of which can't win their prize.
+
+use common::synthetic::{Rng, Synthetic};
+
+/// `size` machines whose prizes can be reached in at most 100 presses of each button, or not at all.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let mut machines = Vec::new();
+    while machines.len() < size.max(1) {
+        let a = (rng.range(10, 99) as i64, rng.range(10, 99) as i64);
+        let b = (rng.range(10, 99) as i64, rng.range(10, 99) as i64);
+        let determinant = a.0 * b.1 - a.1 * b.0;
+        if determinant == 0 { continue; }
+        let (presses_a, presses_b) = (rng.range(1, 100) as i64, rng.range(1, 100) as i64);
+        let mut prize = (a.0 * presses_a + b.0 * presses_b, a.1 * presses_a + b.1 * presses_b);
+        if rng.chance(30) {
+            prize.0 += 1;
+            // only keep a nudged prize if no whole number of presses reaches it any more
+            let reachable = (prize.0 * b.1 - prize.1 * b.0) % determinant == 0 && (a.0 * prize.1 - a.1 * prize.0) % determinant == 0;
+            if reachable { continue; }
+        }
+        machines.push(format!("Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n", a.0, a.1, b.0, b.1, prize.0, prize.1));
+    }
+    machines.join("\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day13;
+
+    #[test]
+    fn most_prizes_can_be_won() {
+        let synthetic = generate(&mut Rng::new(13), 30);
+        assert_eq!(30, synthetic.input.matches("Prize").count());
+        assert!(Day13::new(synthetic.input.lines()).unwrap().part1() > 0);
+    }
+}
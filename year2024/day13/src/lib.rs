@@ -0,0 +1,404 @@
+pub mod generate;
+
+use common::parallel::par_map;
+use common::{read_input, Answer, Explain, Explanation, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use std::io::BufRead;
+use log::debug;
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct ClawMachine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+/// The most times part 1 lets either button be pressed.
+const PART1_MAX_PRESSES: i64 = 100;
+
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - (a / b) * y)
+    }
+}
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    n.div_euclid(d)
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    -(-n).div_euclid(d)
+}
+
+pub struct Day13 {
+    claw_machines: Vec<ClawMachine>,
+    /*
+    button_a_travel: (usize, usize),
+    button_b_travel: (usize, usize),
+    prize_position: (usize, usize), */
+}
+
+impl Day13 {
+    fn read_pair(regex: &Regex, line: &Line<'_>, expected: &str) -> Result<(i64, i64), ParseError> {
+        let Some(captures) = regex.captures(line.text) else {
+            return Err(line.error(line.text, expected));
+        };
+        Ok((line.parse(&captures[1], "a number")?, line.parse(&captures[2], "a number")?))
+    }
+
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        const BUTTON_A: &str = "`Button A: X+<n>, Y+<n>`";
+        const BUTTON_B: &str = "`Button B: X+<n>, Y+<n>`";
+        const PRIZE: &str = "`Prize: X=<n>, Y=<n>`";
+        let button_a_regex = Regex::new(r"^Button A: X\+(\d+),\s*Y\+(\d+)$").unwrap();
+        let button_b_regex = Regex::new(r"^Button B: X\+(\d+),\s*Y\+(\d+)$").unwrap();
+        let prize_regex = Regex::new(r"^Prize: X=(\d+),\s*Y=(\d+)$").unwrap();
+        let mut line_iter = InputLines::new(lines);
+        let mut claw_machines = Vec::new();
+
+        while let Some(line) = line_iter.find(|line| !line.is_blank()) {
+            let button_a = Self::read_pair(&button_a_regex, &line, BUTTON_A)?;
+            let button_b = Self::read_pair(&button_b_regex, &line_iter.expect(BUTTON_B)?, BUTTON_B)?;
+            let prize    = Self::read_pair(&prize_regex, &line_iter.expect(PRIZE)?, PRIZE)?;
+
+            claw_machines.push(ClawMachine{button_a, button_b, prize});
+        }
+        Ok(Day13{claw_machines})
+    }
+
+    fn find_solution_through_a_mult(claw_machine: &ClawMachine) -> Option<(i64, i64)> {
+        // B.0 is divided by below, so a B button that doesn't move the claw along X is left to
+        // find_solution_through_b_mult
+        if claw_machine.button_b.0 == 0 {
+            return None;
+        }
+        // A.0 * X + B.0 * Y = C.0
+        // A.1 * X + B.1 * Y = C.1
+        // A.1 * X + B.1 * Y - (A.0 * X + B.0 * Y) * (B.1/B.0) = C.1 - C.0 * (B.1/B.0)
+        // A.1 * X - (B.1/B.0) * A.0 * X = (C.1 - C.0 * (B.1/B.0))
+        // X * (A.1 - (B.1/B.0) * A.0) = (C.1 - C.0 * (B.1/B.0))
+        // X = (C.1 - C.0 * (B.1/B.0)) / (A.1 - (B.1/B.0) * A.0)
+        let b_ratio: f64 = claw_machine.button_b.1 as f64 / claw_machine.button_b.0 as f64;
+        let a_mult: f64 = (claw_machine.prize.1 as f64 - b_ratio * claw_machine.prize.0 as f64) / (claw_machine.button_a.1 as f64 - b_ratio * claw_machine.button_a.0 as f64);
+
+        let a_mult_rounded = a_mult.round() as i64;
+
+        let b_mult: i64 = (claw_machine.prize.0 - claw_machine.button_a.0 * a_mult_rounded) / claw_machine.button_b.0;
+        let test_1 = claw_machine.button_a.0 * a_mult_rounded + claw_machine.button_b.0 * b_mult == claw_machine.prize.0;
+        let test_2 = claw_machine.button_a.1 * a_mult_rounded + claw_machine.button_b.1 * b_mult == claw_machine.prize.1;
+        
+        // the lines can cross where one of the buttons would have to be pressed backwards
+        if test_1 && test_2 && a_mult_rounded >= 0 && b_mult >= 0 {
+            debug!("A Button presses: {}, B Button Presses: {}", a_mult_rounded, b_mult);
+            Some((a_mult_rounded, b_mult))
+        }
+        else {
+            None
+        }
+    }
+
+    fn find_solution_through_b_mult(claw_machine: &ClawMachine) -> Option<(i64, i64)> {
+        // as above, with the A button moving the claw along X
+        if claw_machine.button_a.0 == 0 {
+            return None;
+        }
+        // A.0 * X + B.0 * Y = C.0
+        // A.1 * X + B.1 * Y = C.1
+        // A.1 * X + B.1 * Y - A.0 * (A.1/A.0) * X - B.0 * (A.1/A.0) * Y = C.1 - C.0 * (A.1/A.0)
+        // B.1 * Y - B.0 * (A.1 / A.0) * Y = C.1 - C.0 * (A.1/A.0)
+        // (B.1 - B.0 * (A.1 / A.0)) * Y = (C.1 - C.0 * (A.1 / A.0))
+        // Y = (C.1 - C.0 * (A.1 / A.0)) / (B.1 - B.0 * (A.1 / A.0))
+        let a_ratio: f64 = claw_machine.button_a.1 as f64 / claw_machine.button_a.0 as f64;
+        let b_mult: f64 = (claw_machine.prize.1 as f64 - a_ratio * claw_machine.prize.0 as f64) / (claw_machine.button_b.1 as f64 - claw_machine.button_b.0 as f64 * a_ratio);
+
+        // A.0 * X + B.0 * Y = C.0
+        // X = (C.0 - B.0 * Y) / A.0
+        let a_mult: f64 = (claw_machine.prize.0 as f64 - b_mult * claw_machine.button_b.0 as f64) / claw_machine.button_a.0 as f64;
+
+        // rounded and checked in whole numbers, as a ratio like 1/3 isn't exact as a float
+        let (a_mult, b_mult) = (a_mult.round() as i64, b_mult.round() as i64);
+        let test_1 = claw_machine.button_a.0 * a_mult + claw_machine.button_b.0 * b_mult == claw_machine.prize.0;
+        let test_2 = claw_machine.button_a.1 * a_mult + claw_machine.button_b.1 * b_mult == claw_machine.prize.1;
+        (test_1 && test_2 && a_mult >= 0 && b_mult >= 0).then_some((a_mult, b_mult))
+    }
+
+    /// The cheapest presses for buttons that move the claw the same way, so that there can be
+    /// any number of ways to the prize or none. Along one axis either button moves the claw,
+    /// every way there is `a = a0 + k * s, b = b0 - k * t` for whole numbers `k`, and as the
+    /// cost changes by the same amount with each step of `k` the cheapest is at one end of
+    /// those that press each button between 0 and `max_presses` times.
+    fn find_solution_in_line(claw_machine: &ClawMachine, costs: (i64, i64), max_presses: i64) -> Option<(i64, i64)> {
+        let ((ax, ay), (bx, by), (px, py)) = (claw_machine.button_a, claw_machine.button_b, claw_machine.prize);
+        let (u, v, w) = if (ax, bx) != (0, 0) { (ax, bx, px) } else { (ay, by, py) };
+        let (u, v, w, max) = (u as i128, v as i128, w as i128, max_presses as i128);
+        let (a, b) = match (u, v) {
+            // neither button moves the claw at all
+            (0, 0) => (0, 0),
+            // a button that doesn't move the claw here doesn't move it at all, so isn't pressed
+            (0, v) => (0, if w % v == 0 { w / v } else { return None; }),
+            (u, 0) => (if w % u == 0 { w / u } else { return None; }, 0),
+            (u, v) => {
+                let (gcd, x, y) = extended_gcd(u, v);
+                if w % gcd != 0 {
+                    return None;
+                }
+                let (a0, b0, s, t) = (x * (w / gcd), y * (w / gcd), v / gcd, u / gcd);
+                let least = ceil_div(-a0, s).max(ceil_div(b0 - max, t));
+                let most = floor_div(max - a0, s).min(floor_div(b0, t));
+                if least > most {
+                    return None;
+                }
+                let k = if costs.0 as i128 * s >= costs.1 as i128 * t { least } else { most };
+                (a0 + k * s, b0 - k * t)
+            },
+        };
+        let reaches = a * ax as i128 + b * bx as i128 == px as i128 && a * ay as i128 + b * by as i128 == py as i128;
+        (reaches && a <= max && b <= max).then_some((a as i64, b as i64))
+    }
+
+    pub fn find_solution_and_cost(claw_machine: &ClawMachine, costs: (i64, i64)) -> Option<((i64, i64), i64)> {
+        Self::find_solution_within(claw_machine, costs, i64::MAX)
+    }
+
+    /// The cheapest way to the prize pressing neither button more than `max_presses` times.
+    fn find_solution_within(claw_machine: &ClawMachine, costs: (i64, i64), max_presses: i64) -> Option<((i64, i64), i64)> {
+        let (button_a, button_b) = (claw_machine.button_a, claw_machine.button_b);
+        if button_a.0 * button_b.1 == button_a.1 * button_b.0 {
+            return Self::find_solution_in_line(claw_machine, costs, max_presses)
+                .map(|(a, b)| ((a, b), costs.0 * a + costs.1 * b));
+        }
+        let within = |(a, b): (i64, i64)| a <= max_presses && b <= max_presses;
+        match (Self::find_solution_through_b_mult(claw_machine).filter(|presses| within(*presses)),
+               Self::find_solution_through_a_mult(claw_machine).filter(|presses| within(*presses))) {
+            (Some((a1, b1)), Some((a2, b2))) => {
+                let cost_for_1 = costs.0 * a1 + costs.1 * b1;
+                let cost_for_2 = costs.0 * a2 + costs.1 * b2;
+                if cost_for_1 < cost_for_2 { Some(((a1, b1), cost_for_1)) } else { Some(((a2, b2), cost_for_2)) }
+            },
+            (Some((a1, b1)), None) => {
+                Some(((a1, b1), costs.0 * a1 + costs.1 * b1))
+            },
+            (None, Some((a2,b2))) => {
+                Some(((a2, b2), costs.0 * a2 + costs.1 * b2))
+            },
+            (None, None) => None
+        }
+        // A.0 * X + B.0 * Y = C.0
+        // A.1 * X + B.1 * Y = C.1
+        // A.1 * X + B.1 * Y - A.0 * (A.1/A.0) * X - B.0 * (A.1/A.0) * Y = C.1 - C.0 * (A.1/A.0)
+        // B.1 * Y - B.0 * (A.1 / A.0) * Y = C.1 - C.0 * (A.1/A.0)
+        // (B.1 - B.0 * (A.1 / A.0)) * Y = (C.1 - C.0 * (A.1 / A.0))
+        // Y = (C.1 - C.0 * (A.1 / A.0)) / (B.1 - B.0 * (A.1 / A.0))
+        /* 
+        let a_ratio: f64 = claw_machine.button_a.1 as f64 / claw_machine.button_a.0 as f64;
+        let b_mult: f64 = (claw_machine.prize.1 as f64 - a_ratio * claw_machine.prize.0 as f64) / (claw_machine.button_b.1 as f64 - claw_machine.button_b.0 as f64 * a_ratio);
+
+        // A.0 * X + B.0 * Y = C.0
+        // X = (C.0 - B.0 * Y) / A.0
+        let a_mult: f64 = (claw_machine.prize.0 as f64 - b_mult * claw_machine.button_b.0 as f64) / claw_machine.button_a.0 as f64;
+        debug!("A Button presses: {}, B Button Presses: {}", a_mult, b_mult);
+
+        match ((a_mult as i64) as f64 == a_mult, (b_mult as i64) as f64 == b_mult) {
+            (true, true) => Some(((a_mult as i64, b_mult as i64), a_mult as i64 * costs.0 + b_mult as i64 * costs.1)),
+            (_, _) => None
+        }
+        */
+    }
+
+    /// A machine that needs a button pressed more than 100 times can't be won, and costs nothing.
+    pub fn part1(&self) -> i64 {
+        par_map(&self.claw_machines, |claw_machine| {
+            Self::find_solution_within(claw_machine, (3, 1), PART1_MAX_PRESSES).map_or(0, |(_, cost)| cost)
+        }).into_iter().sum()
+    }
+
+    /// The machine with its prize where part 2 says it really is.
+    fn corrected(claw_machine: &ClawMachine) -> ClawMachine {
+        const PRIZE_ADDED_DISTANCE: i64 = 10000000000000;
+        ClawMachine{button_a: claw_machine.button_a, button_b: claw_machine.button_b, prize: (PRIZE_ADDED_DISTANCE + claw_machine.prize.0, PRIZE_ADDED_DISTANCE + claw_machine.prize.1)}
+    }
+
+    pub fn part2(&self) -> i64 {
+        par_map(&self.claw_machines, |claw_machine| {
+            Self::find_solution_and_cost(&Self::corrected(claw_machine), (3, 1)).map_or(0, |(_, cost)| cost)
+        }).into_iter().sum()
+    }
+}
+
+impl Explain for Day13 {
+    /// How many times each machine's buttons are pressed to win its prize, and the tokens that costs.
+    fn explain(&self, part: u8) -> Explanation {
+        let mut explanation = Explanation::new(&["machine", "prize", "A presses", "B presses", "tokens"]);
+        for (index, claw_machine) in self.claw_machines.iter().enumerate() {
+            let (claw_machine, max_presses) = if part == 1 { (claw_machine.clone(), PART1_MAX_PRESSES) } else { (Self::corrected(claw_machine), i64::MAX) };
+            let prize = format!("{},{}", claw_machine.prize.0, claw_machine.prize.1);
+            let cells = match Self::find_solution_within(&claw_machine, (3, 1), max_presses) {
+                Some(((a_mult, b_mult), cost)) => vec![a_mult.to_string(), b_mult.to_string(), cost.to_string()],
+                None => vec![String::new(), String::new(), String::new()],
+            };
+            explanation.row([vec![(index + 1).to_string(), prize], cells].concat());
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day13 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day13::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day13 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day13::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day13::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day13::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// The cheapest way to the prize trying every number of presses up to 100 of each button.
+    fn cheapest_by_trying_every_press(claw_machine: &ClawMachine) -> Option<i64> {
+        (0..=100).flat_map(|a| (0..=100).map(move |b| (a, b)))
+            .filter(|(a, b)| {
+                claw_machine.button_a.0 * a + claw_machine.button_b.0 * b == claw_machine.prize.0
+                    && claw_machine.button_a.1 * a + claw_machine.button_b.1 * b == claw_machine.prize.1
+            })
+            .map(|(a, b)| 3 * a + b)
+            .min()
+    }
+
+    fn claw_machine() -> impl Strategy<Value = ClawMachine> {
+        // buttons that move the claw in different directions, as every machine in the puzzle has,
+        // though one might not move it along X at all
+        let x = || prop_oneof![1 => Just(0i64), 9 => 1..100i64];
+        ((x(), 1..100i64), (x(), 1..100i64))
+            .prop_filter("buttons move the claw the same way", |(a, b)| a.0 * b.1 != a.1 * b.0)
+            .prop_flat_map(|(button_a, button_b)| {
+                let presses = move |(a, b): (i64, i64)| (button_a.0 * a + button_b.0 * b, button_a.1 * a + button_b.1 * b);
+                let reachable = (0..=100i64, 0..=100i64).prop_map(presses);
+                // whole numbers of presses reach these, but only by pressing a button backwards
+                let behind = (1..=20i64).prop_flat_map(move |back| {
+                    let least = ((button_b.0 * back) as u64).div_ceil(button_a.0.max(1) as u64).max(((button_b.1 * back) as u64).div_ceil(button_a.1 as u64)) as i64;
+                    (least..=least + 100, Just(-back))
+                }).prop_map(presses);
+                let anywhere = (0..20000i64, 0..20000i64);
+                (Just(button_a), Just(button_b), prop_oneof![reachable, behind, anywhere])
+            })
+            .prop_map(|(button_a, button_b, prize)| ClawMachine{button_a, button_b, prize})
+    }
+
+    /// The only presses that reach the prize, by Cramer's rule in exact integer arithmetic.
+    fn cost_by_cramers_rule(claw_machine: &ClawMachine) -> Option<i64> {
+        let ((ax, ay), (bx, by), (px, py)) = (claw_machine.button_a, claw_machine.button_b, claw_machine.prize);
+        let determinant = (ax * by - ay * bx) as i128;
+        let a = (px as i128 * by as i128 - py as i128 * bx as i128) / determinant;
+        let b = (ax as i128 * py as i128 - ay as i128 * px as i128) / determinant;
+        let reaches = a >= 0 && b >= 0 && a * ax as i128 + b * bx as i128 == px as i128 && a * ay as i128 + b * by as i128 == py as i128;
+        reaches.then(|| (3 * a + b) as i64)
+    }
+
+    /// Buttons that both move the claw along the same line, with a prize somewhere on it.
+    fn claw_machine_in_line() -> impl Strategy<Value = ClawMachine> {
+        ((0..4i64, 0..4i64), 0..6i64, 0..6i64, 0..400i64).prop_map(|((dx, dy), a, b, along)| {
+            ClawMachine{button_a: (a * dx, a * dy), button_b: (b * dx, b * dy), prize: (along * dx, along * dy)}
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn closed_form_finds_the_cheapest_presses(claw_machine in claw_machine()) {
+            let day = Day13{claw_machines: vec![claw_machine.clone()]};
+            prop_assert_eq!(Answer::Int(cheapest_by_trying_every_press(&claw_machine).unwrap_or(0) as i128), Solution::part1(&day));
+        }
+
+        #[test]
+        fn buttons_in_line_find_the_cheapest_presses(claw_machine in claw_machine_in_line()) {
+            let day = Day13{claw_machines: vec![claw_machine.clone()]};
+            prop_assert_eq!(Answer::Int(cheapest_by_trying_every_press(&claw_machine).unwrap_or(0) as i128), Solution::part1(&day));
+        }
+
+        #[test]
+        fn closed_form_is_exact_for_distant_prizes(claw_machine in claw_machine()) {
+            let distant = ClawMachine{prize: (claw_machine.prize.0 + 10000000000000, claw_machine.prize.1 + 10000000000000), ..claw_machine};
+            let found = Day13::find_solution_and_cost(&distant, (3, 1)).map(|(_, cost)| cost);
+            prop_assert_eq!(cost_by_cramers_rule(&distant), found);
+        }
+    }
+    const SAMPLE_INPUT: &str =
+"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+    #[test]
+    fn sample_input_part1_results_in_480() {
+        let day = Day13::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(480, day.part1());
+    }
+
+    #[test]
+    fn machines_past_100_presses_or_without_x_movement_are_solved() {
+        // 202 presses of A would win it, but that's more than part 1 allows
+        let day = Day13::new("Button A: X+1, Y+2\nButton B: X+3, Y+1\nPrize: X=202, Y=404".lines()).unwrap();
+        assert_eq!(0, day.part1());
+        // B doesn't move the claw along X: 3 presses of A and 2 of B
+        let day = Day13::new("Button A: X+3, Y+1\nButton B: X+0, Y+2\nPrize: X=9, Y=7".lines()).unwrap();
+        assert_eq!(11, day.part1());
+        // B goes twice as far as A the same way, so it's cheaper to press B 2 times than A 4
+        let day = Day13::new("Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=4".lines()).unwrap();
+        assert_eq!(2, day.part1());
+    }
+
+    #[test]
+    fn explains_the_presses_per_machine() {
+        let day = Day13::new(SAMPLE_INPUT.lines()).unwrap();
+        let explanation = day.explain(1);
+        assert_eq!(vec!["1", "8400,5400", "80", "40", "280"], explanation.rows[0]);
+        assert_eq!(vec!["2", "12748,12176", "", "", ""], explanation.rows[1]);
+        assert_eq!(vec!["3", "7870,6450", "38", "86", "200"], explanation.rows[2]);
+        let explanation = day.explain(2);
+        let won: Vec<&str> = explanation.rows.iter().map(|row| row[4].as_str()).collect();
+        assert_eq!(vec!["", "459236326669", "", "416082282239"], won);
+    }
+
+    #[test]
+    fn tolerates_spaces_between_a_pair() {
+        let input = "Button A: X+94,  Y+34\nButton B: X+22,Y+67\nPrize: X=8400,\tY=5400";
+        let day = Day13::new(input.lines()).unwrap();
+        assert_eq!(280, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        let truncated = "Button A: X+94, Y+34\nButton B: X+22, Y+67\n";
+        assert_eq!(Some(ParseError::end_of_input(3, "`Prize: X=<n>, Y=<n>`")), Day13::new(truncated.lines()).err());
+        let swapped = "Button B: X+94, Y+34\nButton A: X+22, Y+67\nPrize: X=8400, Y=5400";
+        assert_eq!(Some(ParseError::new(1, 1, "`Button A: X+<n>, Y+<n>`", "Button B: X+94, Y+34")), Day13::new(swapped.lines()).err());
+        let overflowing = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=99999999999999999999, Y=5400";
+        assert_eq!(Some(ParseError::new(3, 10, "a number", "99999999999999999999")), Day13::new(overflowing.lines()).err());
+    }
+}
\ No newline at end of file
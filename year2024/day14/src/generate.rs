@@ -0,0 +1,36 @@
+//! Made-up inputs for day 14: robots patrolling the bathroom, which line up into a picture at some point.
+
+use std::collections::HashSet;
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` robots, up to half the tiles, that all stand on different tiles at some point so
+/// part 2 has a picture to find.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    const WIDTH: i64 = 101;
+    const HEIGHT: i64 = 103;
+    let robots = size.clamp(1, (WIDTH * HEIGHT / 2) as usize);
+    let time = rng.range(0, (WIDTH * HEIGHT - 1) as u64) as i64;
+    let mut tiles = HashSet::new();
+    let mut robot_lines = Vec::new();
+    while robot_lines.len() < robots {
+        let tile = (rng.range(0, WIDTH as u64 - 1) as i64, rng.range(0, HEIGHT as u64 - 1) as i64);
+        if !tiles.insert(tile) { continue; }
+        let velocity = (rng.range(0, 198) as i64 - 99, rng.range(0, 198) as i64 - 99);
+        let start = ((tile.0 - velocity.0 * time).rem_euclid(WIDTH), (tile.1 - velocity.1 * time).rem_euclid(HEIGHT));
+        robot_lines.push(format!("p={},{} v={},{}", start.0, start.1, velocity.0, velocity.1));
+    }
+    lines(robot_lines).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day14;
+
+    #[test]
+    fn robots_spread_out_in_time() {
+        let day = Day14::new(generate(&mut Rng::new(14), 300).input.lines()).unwrap();
+        assert!(day.part2().is_some());
+    }
+}
@@ -0,0 +1,312 @@
+pub mod generate;
+
+use log::{debug, trace};
+use regex::Regex;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{for_each_line, parse_param, Answer, FromReader, InputLines, Line, ParamError, ParseError, ReadError, Solution};
+use grid::Point;
+use render::{frame_interval, Colour, Frame, Render, Simulation, View};
+
+#[derive(Debug)]
+pub struct Robot {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+pub struct Day14 {
+    robots: Vec<Robot>,
+    height: usize,
+    width: usize,
+}
+
+impl Day14 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let robot_re = Self::robot_re();
+        let mut robots = Vec::new();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            robots.push(Self::robot(&robot_re, &line)?);
+        }
+        Ok(Day14{robots, height: 103, width: 101})
+    }
+
+    fn robot_re() -> Regex {
+        Regex::new(r"^p=(\d+),(\d+)\s+v=(-?\d+),(-?\d+)$").unwrap()
+    }
+
+    fn robot(robot_re: &Regex, line: &Line<'_>) -> Result<Robot, ParseError> {
+        let Some(values) = robot_re.captures(line.text) else {
+            return Err(line.error(line.text, "`p=<x>,<y> v=<dx>,<dy>`"));
+        };
+        Ok(Robot{position: (line.parse(&values[2], "a position")?, line.parse(&values[1], "a position")?),
+                 velocity: (line.parse(&values[4], "a velocity")?, line.parse(&values[3], "a velocity")?)})
+    }
+
+    fn find_loc_robot(robot: &Robot, time: usize, height: usize, width: usize) -> Robot {
+        let mut new_pos = ((robot.position.0 + time as i64 * robot.velocity.0) % height as i64, (robot.position.1 + time as i64 * robot.velocity.1) % width as i64);
+        if 0 > new_pos.0 {
+            new_pos = (new_pos.0 + height as i64, new_pos.1);
+        }
+        if 0 > new_pos.1 {
+            new_pos = (new_pos.0, new_pos.1 + width as i64);
+        }
+        Robot{position: new_pos, velocity: robot.velocity}
+    }
+
+    fn part1_sized(&self, height: usize, width: usize) -> usize {
+        trace!("{:?}", self.robots);
+        let mut quadrant1_count = 0;
+        let mut quadrant2_count = 0;
+        let mut quadrant3_count = 0;
+        let mut quadrant4_count = 0;
+        const TIME: usize = 100;
+        let width_divider = width / 2;
+        let height_divider = height / 2;
+
+        for robot in &self.robots {
+            let robot_pos = Self::find_loc_robot(robot, TIME, height, width).position;
+            let robot_pos = (robot_pos.0 as usize, robot_pos.1 as usize);
+            if robot_pos.0 < height_divider && robot_pos.1 < width_divider {
+                quadrant1_count += 1;
+            }
+            else if robot_pos.0 > height_divider && robot_pos.1 < width_divider {
+                quadrant3_count += 1;
+            }
+            else if robot_pos.0 < height_divider && robot_pos.1 > width_divider {
+                quadrant2_count += 1;
+            }
+            else if robot_pos.0 > height_divider && robot_pos.1 > width_divider {
+                quadrant4_count += 1;
+            }
+            else {
+                trace!("robot found on edge: {:?}", robot_pos);
+                assert!(robot_pos.0 == height_divider || robot_pos.1 == width_divider);
+            }
+        }
+        debug!("{quadrant1_count} * {quadrant2_count} * {quadrant3_count} * {quadrant4_count}");
+        quadrant1_count * quadrant2_count * quadrant3_count * quadrant4_count
+    }
+    pub fn part1(&self) -> usize {
+        self.part1_sized(self.height, self.width)
+    }
+
+    /// Renders the robots' positions at the given time, one '*' per occupied tile.
+    pub fn display_at(&self, time: usize) -> String {
+        let mut display = vec![ vec![ '.'; self.width]; self.height];
+        for robot in &self.robots {
+            let robot_pos = Self::find_loc_robot(robot, time, self.height, self.width).position;
+            display[robot_pos.0 as usize][robot_pos.1 as usize] = '*';
+        }
+        let mut output = String::new();
+        for line in &display {
+            output.extend(line);
+            output.push('\n');
+        }
+        output
+    }
+
+    fn robots_all_apart(&self, time: usize) -> bool {
+        let mut occupied = HashSet::<(i64, i64)>::new();
+        for robot in &self.robots {
+            if !occupied.insert(Self::find_loc_robot(robot, time, self.height, self.width).position) {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn part2(&self) -> Option<usize> {
+        // Watching the frames go by (351 first looks like it's pulling together, and then every 101 after)
+        // the tree is drawn at the first time where no two robots share a tile.
+        // Positions repeat after height * width seconds, so there's no point looking further.
+        (0..self.height * self.width).find(|time| self.robots_all_apart(*time))
+    }
+}
+
+impl Day14 {
+    /// The robots at `time`, with the rows and columns between the quadrants marked when `quadrants` is set.
+    fn frame_at(&self, time: usize, quadrants: bool) -> Frame {
+        let mut frame = Frame::new(self.width, self.height, Colour::OPEN);
+        if quadrants {
+            (0..self.width).for_each(|x| frame.set(Point::new(x, self.height / 2), Colour::WALL));
+            (0..self.height).for_each(|y| frame.set(Point::new(self.width / 2, y), Colour::WALL));
+        }
+        for robot in &self.robots {
+            let robot_pos = Self::find_loc_robot(robot, time, self.height, self.width).position;
+            frame.set(Point::new(robot_pos.1 as usize, robot_pos.0 as usize), Colour::ROBOT);
+        }
+        frame
+    }
+}
+
+impl Render for Day14 {
+    /// Part 1 shows the first 100 seconds against the quadrants, part 2 every second up to the tree,
+    /// or through a whole cycle if the robots never spread out.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let cycle_end = (self.height * self.width).saturating_sub(1);
+        let (end, quadrants) = if part == 2 { (self.part2().unwrap_or(cycle_end), false) } else { (100, true) };
+        let interval = frame_interval(end + 1);
+        (0..=end).filter(|time| time % interval == 0 || *time == end).map(|time| self.frame_at(time, quadrants)).collect()
+    }
+}
+
+impl Simulation for Day14 {
+    /// Seconds since the robots started moving.
+    type State = usize;
+
+    fn start(&self, _part: u8) -> usize {
+        0
+    }
+
+    fn step(&self, time: &mut usize) -> bool {
+        // positions repeat after height * width seconds
+        if *time + 1 >= self.height * self.width { return false; }
+        *time += 1;
+        true
+    }
+
+    fn view(&self, time: &usize) -> View {
+        View{lines: self.display_at(*time).lines().map(String::from).collect(), actor: None, panel: vec![
+            ("seconds".to_string(), time.to_string()),
+            ("robots".to_string(), self.robots.len().to_string()),
+            ("all apart".to_string(), if self.robots_all_apart(*time) { "yes" } else { "no" }.to_string()),
+        ]}
+    }
+}
+
+impl FromReader for Day14 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let robot_re = Self::robot_re();
+        let mut robots = Vec::new();
+        for_each_line(reader, |line| {
+            robots.push(Self::robot(&robot_re, &line)?);
+            Ok(())
+        })?;
+        Ok(Day14{robots, height: 103, width: 101})
+    }
+}
+
+impl Solution<'_> for Day14 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day14::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day14::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day14::part2(self).map_or_else(|| Answer::Error("robots never spread out to draw the tree".to_string()), Answer::from)
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "height" => { self.height = parse_param(name, value)?; },
+            "width" => { self.width = parse_param(name, value)?; },
+            _ => { return Err(ParamError::Unknown(name.to_string())); }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_INPUT: &str =
+"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn sample_input_part1_results_in_12() {
+        let day = Day14::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(12, day.part1_sized(7, 11));
+    }
+
+    #[test]
+    fn part2_finds_first_time_robots_are_apart() {
+        const STACKED_ROBOTS: &str =
+"p=0,0 v=1,0
+p=0,0 v=0,0";
+        let mut day = Day14::new(STACKED_ROBOTS.lines()).unwrap();
+        day.configure("height", "7").unwrap();
+        day.configure("width", "11").unwrap();
+        assert_eq!(Some(1), day.part2());
+        assert_eq!("**.........\n...........\n...........\n...........\n...........\n...........\n...........\n", day.display_at(1));
+        let mut time = day.start(2);
+        assert!(day.step(&mut time));
+        assert_eq!(("all apart".to_string(), "yes".to_string()), day.view(&time).panel[2]);
+        assert_eq!("**.........", day.view(&time).lines[0]);
+        let frames = day.render(2);
+        assert_eq!(2, frames.len());
+        assert_eq!(Some(Colour::ROBOT), frames[1].get(Point::new(1, 0)));
+    }
+
+    #[test]
+    fn robots_that_never_spread_out_are_an_error() {
+        let mut day = Day14::new("p=0,0 v=1,1\np=0,0 v=1,1".lines()).unwrap();
+        day.configure("height", "3").unwrap();
+        day.configure("width", "3").unwrap();
+        assert_eq!(Answer::Error("robots never spread out to draw the tree".to_string()), Solution::part2(&day));
+        assert_eq!(9, day.render(2).len());
+    }
+
+    #[test]
+    fn render_part1_marks_the_quadrants() {
+        let mut day = Day14::new(SAMPLE_INPUT.lines()).unwrap();
+        day.configure("height", "7").unwrap();
+        day.configure("width", "11").unwrap();
+        let frames = day.render(1);
+        assert_eq!(101, frames.len());
+        assert_eq!((11, 7), (frames[0].width(), frames[0].height()));
+        assert_eq!(Some(Colour::ROBOT), frames[0].get(Point::new(0, 4)));
+        assert_eq!(Some(Colour::WALL), frames[100].get(Point::new(0, 3)));
+    }
+
+    #[test]
+    fn find_loc_robot_1_sec() {
+        let robot = Robot{position:(4,2), velocity:(-3,2)};
+        assert_eq!((1,4), Day14::find_loc_robot(&robot, 1, 7, 11).position);
+    }
+
+    #[test]
+    fn find_loc_robot_2_sec() {
+        let robot = Robot{position:(4,2), velocity:(-3,2)};
+        assert_eq!((5, 6), Day14::find_loc_robot(&robot, 2, 7, 11).position);
+    }
+
+    #[test]
+    fn find_loc_robot_3_sec() {
+        let robot = Robot{position:(4,2), velocity:(-3,2)};
+        assert_eq!((2, 8), Day14::find_loc_robot(&robot, 3, 7, 11).position);
+    }
+
+    #[test]
+    fn find_loc_robot_4_sec() {
+        let robot = Robot{position:(4,2), velocity:(-3,2)};
+        assert_eq!((6, 10), Day14::find_loc_robot(&robot, 4, 7, 11).position);
+    }
+
+    #[test]
+    fn find_loc_robot_5_sec() {
+        let robot = Robot{position:(4,2), velocity:(-3,2)};
+        assert_eq!((3, 1), Day14::find_loc_robot(&robot, 5, 7, 11).position);
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 1, "`p=<x>,<y> v=<dx>,<dy>`", "p=6,3 v=-1")), Day14::new("p=0,4 v=3,-3\np=6,3 v=-1".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 1, "`p=<x>,<y> v=<dx>,<dy>`", "p=0,four v=3,-3")), Day14::new("p=0,four v=3,-3".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 9, "a velocity", "99999999999999999999")), Day14::new("p=0,4 v=99999999999999999999,-3".lines()).err());
+    }
+}
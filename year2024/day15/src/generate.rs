@@ -0,0 +1,30 @@
+//! Made-up inputs for day 15: a walled warehouse full of boxes, and the robot's moves.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A warehouse `size` tiles square, and ten moves for the robot per tile of its side.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let side = size.max(4);
+    let mut map: Vec<Vec<char>> = (0..side).map(|y| (0..side).map(|x| {
+        if x == 0 || y == 0 || x == side - 1 || y == side - 1 || rng.chance(8) { '#' }
+        else if rng.chance(25) { 'O' }
+        else { '.' }
+    }).collect()).collect();
+    map[rng.range(1, side as u64 - 2) as usize][rng.range(1, side as u64 - 2) as usize] = '@';
+    let moves: Vec<char> = (0..side * 10).map(|_| rng.pick(&['^', '>', 'v', '<'])).collect();
+    (lines(map.into_iter().map(|row| row.into_iter().collect())) + "\n" + &lines(moves.chunks(70).map(|row| row.iter().collect()))).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day15;
+
+    #[test]
+    fn one_robot_among_the_boxes() {
+        let synthetic = generate(&mut Rng::new(15), 20);
+        assert_eq!(1, synthetic.input.matches('@').count());
+        assert_eq!(200, synthetic.input.chars().filter(|c| "^>v<".contains(*c)).count());
+        assert!(Day15::new(synthetic.input.lines()).unwrap().part1() > 0);
+    }
+}
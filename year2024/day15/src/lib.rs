@@ -0,0 +1,388 @@
+pub mod generate;
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
+use grid::{Direction, Grid, Point};
+use log::trace;
+use render::{frame_interval, Colour, Frame, Render, Simulation, View};
+
+pub struct Day15 {
+    map: Grid<char>,
+    robot_pos: Point,
+    // part 2 runs the same directions through a warehouse twice as wide
+    wide_map: Grid<char>,
+    wide_robot_pos: Point,
+    directions: Vec<Direction>,
+}
+
+impl Day15 {
+
+    fn widen_row(row: &[char]) -> Vec<char> {
+        let mut wide_row: Vec::<char> = Vec::new();
+
+        for pos in row {
+            match *pos {
+                '#' => { wide_row.append(&mut vec!['#', '#']); },
+                'O' => { wide_row.append(&mut vec!['[', ']']); },
+                '.' => { wide_row.append(&mut vec!['.', '.']); },
+                '@' => { wide_row.append(&mut vec!['@', '.']); },
+                another => { panic!("Found an unexpected item in the map: {}", another); }
+            }
+        }
+        wide_row
+    }
+
+    fn consume_input_to_build_day(lines: std::str::Lines<'_>) -> Result<(Grid<char>, Point, Vec<Direction>), ParseError> {
+        let mut lines = InputLines::new(lines);
+        let mut robot_pos: Option<Point> = None;
+        let mut directions: Vec::<Direction> = Vec::new();
+
+        let map = Grid::read(&mut lines, "one of `#`, `O`, `.` or a single `@`", |position, pos| {
+            match pos {
+                '#' | 'O' | '.' => Some(pos),
+                '@' if robot_pos.is_none() => { robot_pos = Some(position); Some(pos) },
+                _ => None,
+            }
+        })?;
+        lines.expect("a blank line before the robot's moves")?;
+        let Some(robot_pos) = robot_pos else {
+            return Err(ParseError::end_of_input(map.height() + 1, "a robot `@` in the warehouse"));
+        };
+
+        for line in lines {
+            for (index, direction) in line.text.char_indices() {
+                match Direction::from_arrow(direction) {
+                    Some(direction) => { directions.push(direction); },
+                    None => { return Err(line.char_error(index, "one of `^`, `>`, `v` or `<`")); }
+                }
+            }
+        }
+        Ok((map, robot_pos, directions))
+    }
+
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let (map, robot_pos, directions) = Self::consume_input_to_build_day(lines)?;
+        let wide_map = Grid::from_rows(map.rows().map(Self::widen_row).collect()).expect("every row widens to the same width");
+        let wide_robot_pos = Point::new(robot_pos.x * 2, robot_pos.y);
+        Ok(Day15{map, robot_pos, wide_map, wide_robot_pos, directions})
+    }
+
+    fn move_item(map: &mut Grid<char>, pos: &mut Point, direction: Direction) {
+        if let Some(next_pos) = map.step(*pos, direction) {
+            if map[next_pos] == '#' { return; }
+            if map[next_pos] != '.' {
+                let mut adjusted_pos = next_pos;
+                Self::move_item(map, &mut adjusted_pos, direction);
+            }
+            if map[next_pos] == '.' {
+                map.swap(*pos, next_pos);
+                *pos = next_pos;
+            }
+        }
+    }
+
+    fn sum_of_box_coordinates(map: &Grid<char>, box_edge: char) -> usize {
+        map.positions(|item| *item == box_edge).map(|pos| pos.y * 100 + pos.x).sum()
+    }
+
+    /// Runs the robot through every move, in the wide warehouse when `wide` is set, calling
+    /// `after_move` with each move's index and the warehouse after it.
+    fn run_robot(&self, wide: bool, mut after_move: impl FnMut(usize, &Grid<char>)) -> Grid<char> {
+        let (mut map, mut robot_pos) = if wide { (self.wide_map.clone(), self.wide_robot_pos) } else { (self.map.clone(), self.robot_pos) };
+        for (index, direction) in self.directions.iter().enumerate() {
+            Self::move_robot(&mut map, &mut robot_pos, *direction, wide);
+            after_move(index, &map);
+        }
+        map
+    }
+
+    fn move_robot(map: &mut Grid<char>, robot_pos: &mut Point, direction: Direction, wide: bool) {
+        match direction {
+            Direction::North | Direction::South if wide => { Self::move_vertically_wide(map, robot_pos, direction); },
+            _ => { Self::move_item(map, robot_pos, direction); },
+        }
+    }
+
+    pub fn part1(&self) -> usize {
+        let map = self.run_robot(false, |_, _| {});
+        Self::sum_of_box_coordinates(&map, 'O')
+    }
+
+    fn build_ranges_to_move(items_at_needed_positions: &[(&[char], (usize, usize))]) -> Vec<(usize, usize)> {
+        let mut ranges_to_move = Vec::<(usize, usize)>::new();
+        for (items_slice, positions) in items_at_needed_positions {
+            let mut offset = 0;
+            while positions.0 + offset < positions.1 {
+                if items_slice[offset] == ']' && offset == 0 {
+                    ranges_to_move.push((positions.0-1, positions.0 + 1));
+                    offset += 1;
+                } else if items_slice[offset] == '[' {
+                    ranges_to_move.push((positions.0+offset, positions.0+offset+2));
+                    offset += 2; /* skip past the closing char of box */
+                } else if items_slice[offset] == '.' {
+                    offset += 1;
+                } else {
+                    panic!("Unknown item {} found at {offset}", items_slice[offset]);
+                }
+            }
+        }
+        ranges_to_move
+    }
+
+    fn get_items_in_row_of_ranges<'a>(map: &'a Grid<char>, row_y: usize, ranges_to_move: &'a [(usize, usize)]) -> Vec<(&'a [char], (usize, usize))> {
+        let mut items_at_needed_pos: Vec<(&[char], (usize, usize))> = Vec::new();
+        for range_to_move in ranges_to_move {
+            let items_in_range: &[char] = &map.row(row_y)[range_to_move.0..range_to_move.1];
+            items_at_needed_pos.push((items_in_range, *range_to_move));
+        }
+        items_at_needed_pos
+    }
+
+    fn move_range(map: &mut Grid<char>, ranges_to_move: &(usize, Vec<(usize, usize)>), direction: Direction) -> bool {
+        let cur_y = ranges_to_move.0;
+        if let Some(next_y) = map.step(Point::new(0, cur_y), direction).map(|pos| pos.y) {
+            let items_at_needed_positions = Self::get_items_in_row_of_ranges(map, next_y, &ranges_to_move.1);
+            let mut all_space = true;
+
+            for items in &items_at_needed_positions {
+                for item in items.0 {
+                    if *item == '#' { return false; } // can't push, we're blocked
+                    if *item != '.' { assert!(*item == '[' || *item == ']'); all_space = false; } // can't push til we push the boxes beyond us
+                }
+            }
+
+            if !all_space {
+                let ranges_to_move = (next_y, Self::build_ranges_to_move(&items_at_needed_positions));
+                if !Self::move_range(map, &ranges_to_move, direction) { return false; }
+            }
+
+            let mut moved_xindex = HashSet::<usize>::new();
+            for range_to_move in &ranges_to_move.1 {
+                for xindex in range_to_move.0..range_to_move.1 {
+                    if !moved_xindex.contains(&xindex) {
+                        moved_xindex.insert(xindex);
+                        let (from, to) = (Point::new(xindex, cur_y), Point::new(xindex, next_y));
+                        assert_eq!('.', map[to]);
+                        assert!(map[from] == '[' || map[from] == ']');
+                        map.swap(from, to);
+                    }
+                }
+            }
+            return true;
+        }
+        false
+    }
+
+    fn move_vertically_wide(map: &mut Grid<char>, robot_pos: &mut Point, direction: Direction) {
+        if let Some(new_pos) = map.step(*robot_pos, direction) {
+            let item_at_new_pos = map[new_pos];
+            if item_at_new_pos == '#' { return; }
+            let mut moved_range = true;
+            if item_at_new_pos != '.' {
+                assert!(item_at_new_pos == '[' || item_at_new_pos == ']');
+                let box_range = if item_at_new_pos == '[' { (new_pos.x, new_pos.x + 2) } else { (new_pos.x - 1, new_pos.x + 1) };
+                let range_to_move = (new_pos.y, vec![box_range]);
+                moved_range = Self::move_range(map, &range_to_move, direction);
+            }
+            if map[new_pos] == '.' {
+                assert!(moved_range);
+                map.swap(*robot_pos, new_pos);
+                *robot_pos = new_pos;
+            }
+        }
+    }
+
+    pub fn part2(&self) -> usize {
+        let map = self.run_robot(true, |index, map| trace!("After: {:?}:\n{map}", self.directions[index]));
+        Self::sum_of_box_coordinates(&map, '[')
+    }
+}
+
+fn warehouse_colour(item: &char) -> Colour {
+    match item {
+        '#' => Colour::WALL,
+        'O' | '[' | ']' => Colour::BOX,
+        '@' => Colour::ROBOT,
+        _ => Colour::OPEN,
+    }
+}
+
+impl Render for Day15 {
+    /// The warehouse as the robot moves, in the wide warehouse for part 2.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let wide = part == 2;
+        let mut frames = vec![Frame::from_grid(if wide { &self.wide_map } else { &self.map }, warehouse_colour)];
+        let interval = frame_interval(self.directions.len());
+        self.run_robot(wide, |index, map| {
+            if (index + 1) % interval == 0 || index + 1 == self.directions.len() {
+                frames.push(Frame::from_grid(map, warehouse_colour));
+            }
+        });
+        frames
+    }
+}
+
+/// The warehouse part way through the robot's moves, for stepping through them.
+#[derive(Clone)]
+pub struct Warehouse {
+    map: Grid<char>,
+    robot_pos: Point,
+    wide: bool,
+    moves_made: usize,
+}
+
+impl Simulation for Day15 {
+    type State = Warehouse;
+
+    /// The warehouse as given for part 1, or twice as wide for part 2.
+    fn start(&self, part: u8) -> Warehouse {
+        let wide = part == 2;
+        let (map, robot_pos) = if wide { (self.wide_map.clone(), self.wide_robot_pos) } else { (self.map.clone(), self.robot_pos) };
+        Warehouse{map, robot_pos, wide, moves_made: 0}
+    }
+
+    fn step(&self, warehouse: &mut Warehouse) -> bool {
+        let Some(direction) = self.directions.get(warehouse.moves_made) else {
+            return false;
+        };
+        Self::move_robot(&mut warehouse.map, &mut warehouse.robot_pos, *direction, warehouse.wide);
+        warehouse.moves_made += 1;
+        true
+    }
+
+    fn view(&self, warehouse: &Warehouse) -> View {
+        let box_edge = if warehouse.wide { '[' } else { 'O' };
+        let next_move = self.directions.get(warehouse.moves_made).and_then(Direction::to_arrow).map_or("none".to_string(), String::from);
+        View{lines: warehouse.map.rows().map(|row| row.iter().collect()).collect(), actor: Some(warehouse.robot_pos), panel: vec![
+            ("moves".to_string(), format!("{} of {}", warehouse.moves_made, self.directions.len())),
+            ("next move".to_string(), next_move),
+            ("boxes".to_string(), warehouse.map.positions(|item| *item == box_edge).count().to_string()),
+            ("GPS sum".to_string(), Self::sum_of_box_coordinates(&warehouse.map, box_edge).to_string()),
+        ]}
+    }
+}
+
+impl FromReader for Day15 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day15::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day15 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day15::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day15::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day15::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SMALL_SAMPLE: &str =
+"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+    const SAMPLE_INPUT: &str =
+"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    #[test]
+    fn small_sample_part1_results_in_2028() {
+        let day = Day15::new(SMALL_SAMPLE.lines()).unwrap();
+        assert_eq!(2028, day.part1());
+    }
+
+    #[test]
+    fn sample_part1_results_in_10092() {
+        let day = Day15::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(10092, day.part1());
+    }
+
+    #[test]
+    fn small_sample_part2_results_in_105() {
+        const SMALL_SAMPLE_PART2: &str =
+"#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let day = Day15::new(SMALL_SAMPLE_PART2.lines()).unwrap();
+        assert_eq!(105 + 207 + 306, day.part2());
+    }
+    #[test]
+    fn sample_part2_results_in_9021() {
+        let day = Day15::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(9021, day.part2());
+    }
+
+    #[test]
+    fn render_shows_the_warehouse_after_every_move() {
+        let day = Day15::new(SMALL_SAMPLE.lines()).unwrap();
+        let frames = day.render(1);
+        assert_eq!(16, frames.len());
+        assert_eq!(Some(Colour::ROBOT), frames[0].get(Point::new(2, 2)));
+        assert_eq!(Some(Colour::ROBOT), frames[15].get(Point::new(4, 4)));
+        assert_eq!(16, day.render(2)[0].width());
+    }
+
+    #[test]
+    fn stepping_through_the_moves_tracks_the_boxes() {
+        let day = Day15::new(SMALL_SAMPLE.lines()).unwrap();
+        let mut warehouse = day.start(1);
+        assert_eq!("##@.O..#", day.view(&warehouse).lines[2]);
+        assert_eq!(("next move".to_string(), "<".to_string()), day.view(&warehouse).panel[1]);
+        while day.step(&mut warehouse) {}
+        let view = day.view(&warehouse);
+        assert_eq!(Some(Point::new(4, 4)), view.actor);
+        assert_eq!(vec!["15 of 15", "none", "6", "2028"], view.panel.iter().map(|(_, value)| value.as_str()).collect::<Vec<_>>());
+        assert_eq!(16, day.view(&day.start(2)).lines[0].len());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(4, "a blank line before the robot's moves")), Day15::new("#####\n#@O.#\n#####".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 4, "one of `#`, `O`, `.` or a single `@`", "@")), Day15::new("#####\n#@O@#\n#####\n\n>".lines()).err());
+        assert_eq!(Some(ParseError::end_of_input(4, "a robot `@` in the warehouse")), Day15::new("#####\n#.O.#\n#####\n\n>".lines()).err());
+        assert_eq!(Some(ParseError::new(5, 3, "one of `^`, `>`, `v` or `<`", "2")), Day15::new("#####\n#@O.#\n#####\n\n>>2".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 4, "a row 5 wide", "")), Day15::new("#####\n#@O\n#####\n\n>".lines()).err());
+    }
+}
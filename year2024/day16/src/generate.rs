@@ -0,0 +1,36 @@
+//! Made-up inputs for day 16: a reindeer maze that always has a way through.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A maze `size` tiles square with the start in the bottom left and the end in the top right.
+/// Walls are scattered at random, then a staircase path is carved between the two so there's
+/// always at least one way through.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let side = size.max(5);
+    let mut maze: Vec<Vec<char>> = (0..side).map(|y| (0..side).map(|x| {
+        if x == 0 || y == 0 || x == side - 1 || y == side - 1 || rng.chance(30) { '#' } else { '.' }
+    }).collect()).collect();
+    let (mut x, mut y) = (1, side - 2);
+    while (x, y) != (side - 2, 1) {
+        maze[y][x] = '.';
+        if y == 1 || (x < side - 2 && rng.chance(50)) { x += 1; } else { y -= 1; }
+    }
+    maze[side - 2][1] = 'S';
+    maze[1][side - 2] = 'E';
+    lines(maze.into_iter().map(|row| row.into_iter().collect())).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day16;
+
+    #[test]
+    fn there_is_always_a_way_through() {
+        for seed in 1..20 {
+            let day = Day16::new(generate(&mut Rng::new(seed), 15).input.lines()).unwrap();
+            // at least the 24 steps of the shortest route, and one turn
+            assert!(day.part1().is_some_and(|cost| cost >= 24 + 1000));
+        }
+    }
+}
@@ -0,0 +1,231 @@
+pub mod generate;
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{read_input, Answer, FromReader, ParseError, ReadError, Solution};
+use grid::{Direction, Grid, Point};
+use log::{debug, log_enabled, Level};
+use render::{frame_interval, Colour, Frame, Render};
+use search::{all_optimal_states, dijkstra, Path};
+
+#[derive(Debug)]
+pub struct Day16 {
+    maze: Grid<char>,
+    start: (Point, Direction),
+    end: Point,
+}
+
+impl Day16 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut start_pos: Option<Point> = None;
+        let mut end_pos: Option<Point> = None;
+
+        let map = Grid::parse(lines, "one of `#`, `.` or a single `S` and `E`", |position, pos| {
+            match pos {
+                '#' | '.' => {},
+                'S' if start_pos.is_none() => { start_pos = Some(position); },
+                'E' if end_pos.is_none() => { end_pos = Some(position); },
+                _ => { return None; }
+            }
+            Some(pos)
+        })?;
+        let (Some(start_pos), Some(end_pos)) = (start_pos, end_pos) else {
+            return Err(ParseError::end_of_input(map.height() + 1, "a start `S` and an end `E` in the maze"));
+        };
+        Ok(Day16{maze: map, start: (start_pos, Direction::East), end: end_pos})
+    }
+
+    fn get_open_step(&self, pos: &Point, dir: Direction) -> Option<(Point, Direction)> {
+        self.maze.step(*pos, dir).filter(|next_pos| self.maze[*next_pos] != '#').map(|next_pos| (next_pos, dir))
+    }
+
+    pub fn get_right(&self, pos: &(Point, Direction)) -> Option<(Point, Direction)> {
+        self.get_open_step(&pos.0, pos.1.turn_right())
+    }
+
+    pub fn get_left(&self, pos: &(Point, Direction)) -> Option<(Point, Direction)> {
+        self.get_open_step(&pos.0, pos.1.turn_left())
+    }
+    pub fn get_forward(&self, pos: &(Point, Direction)) -> Option<(Point, Direction)> {
+        self.get_open_step(&pos.0, pos.1)
+    }
+
+    fn next_steps(&self, pos: &(Point, Direction)) -> Vec<((Point, Direction), usize)> {
+        let mut next_steps = Vec::new();
+        if let Some(forward) = self.get_forward(pos) { next_steps.push((forward, 1)); }
+        // turning costs 1000 on top of the step taken after it
+        if let Some(left) = self.get_left(pos) { next_steps.push((left, 1001)); }
+        if let Some(right) = self.get_right(pos) { next_steps.push((right, 1001)); }
+        next_steps
+    }
+
+    fn best_path(&self) -> Option<Path<(Point, Direction), usize>> {
+        dijkstra(self.start, |pos| self.next_steps(pos), |pos| pos.0 == self.end)
+    }
+
+    /// The lowest score through the maze, or `None` if walls cut the end off from the start.
+    pub fn part1(&self) -> Option<usize> {
+        self.best_path().map(|path| path.cost)
+    }
+
+    fn tiles_on_best_paths(&self) -> Option<HashSet<Point>> {
+        let best_paths = all_optimal_states(self.start, |pos| self.next_steps(pos), |pos| pos.0 == self.end)?;
+        Some(best_paths.states.iter().map(|(tile, _)| *tile).collect())
+    }
+
+    pub fn part2(&self) -> Option<usize> {
+        let tiles_on_best_paths = self.tiles_on_best_paths()?;
+        if log_enabled!(Level::Debug) {
+            let mut map = String::new();
+            for (yindex, row) in self.maze.rows().enumerate() {
+                for (xindex, val) in row.iter().enumerate() {
+                    if !tiles_on_best_paths.contains(&Point::new(xindex, yindex)) {
+                        map.push(*val);
+                    } else {
+                        map.push('O');
+                    }
+                }
+                map.push('\n');
+            }
+            debug!("Tiles on best paths:\n{map}");
+        }
+        Some(tiles_on_best_paths.len())
+    }
+}
+
+impl Render for Day16 {
+    /// Part 1 walks one best path through the maze, part 2 shows every tile on any best path.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let mut frame = Frame::from_grid(&self.maze, |tile| if *tile == '#' { Colour::WALL } else { Colour::OPEN });
+        frame.set(self.end, Colour::HIGHLIGHT);
+        if part == 2 {
+            for tile in self.tiles_on_best_paths().unwrap_or_default() {
+                frame.set(tile, Colour::PATH);
+            }
+            return vec![frame];
+        }
+        let Some(path) = self.best_path().map(|path| path.states) else { return vec![frame] };
+        let interval = frame_interval(path.len());
+        let mut frames = Vec::new();
+        for (step, (tile, _)) in path.iter().enumerate() {
+            frame.set(*tile, Colour::PATH);
+            if step % interval == 0 || step == path.len() - 1 {
+                let mut with_reindeer = frame.clone();
+                with_reindeer.set(*tile, Colour::ROBOT);
+                frames.push(with_reindeer);
+            }
+        }
+        frames
+    }
+}
+
+impl FromReader for Day16 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day16::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day16 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day16::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day16::part1(self).map_or_else(|| Answer::Error("no way through the maze".to_string()), Answer::from)
+    }
+
+    fn part2(&self) -> Answer {
+        Day16::part2(self).map_or_else(|| Answer::Error("no way through the maze".to_string()), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST_EXAMPLE: &str =
+"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const SECOND_EXAMPLE: &str =
+"#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn first_example_part1_best_path_cost_7036() {
+        let day = Day16::new(FIRST_EXAMPLE.lines()).unwrap();
+        assert_eq!(Some(7036), day.part1());
+    }
+
+    #[test]
+    fn second_example_part1_best_path_cost_11048() {
+        let day = Day16::new(SECOND_EXAMPLE.lines()).unwrap();
+        assert_eq!(Some(11048), day.part1());
+    }
+
+    #[test]
+    fn first_example_part2_equals_45() {
+        let day = Day16::new(FIRST_EXAMPLE.lines()).unwrap();
+        assert_eq!(Some(45), day.part2());
+    }
+
+    #[test]
+    fn second_example_part2_equals_64() {
+        let day = Day16::new(SECOND_EXAMPLE.lines()).unwrap();
+        assert_eq!(Some(64), day.part2());
+    }
+
+    #[test]
+    fn render_marks_the_best_paths() {
+        let day = Day16::new(FIRST_EXAMPLE.lines()).unwrap();
+        let count = |frame: &Frame, colour: Colour| frame.rows().flatten().filter(|cell| **cell == colour).count();
+        let walk = day.render(1);
+        assert_eq!(Some(Colour::ROBOT), walk[0].get(Point::new(1, 13)));
+        assert_eq!(Some(Colour::ROBOT), walk.last().unwrap().get(Point::new(13, 1)));
+        assert_eq!(45, count(&day.render(2)[0], Colour::PATH));
+    }
+
+    #[test]
+    fn a_walled_off_end_is_an_error() {
+        let day = Day16::new("#######\n#S.#.E#\n#######".lines()).unwrap();
+        assert_eq!(Answer::Error("no way through the maze".to_string()), Solution::part1(&day));
+        assert_eq!(Answer::Error("no way through the maze".to_string()), Solution::part2(&day));
+        assert_eq!(1, day.render(1).len());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 6, "end of row", "##")), Day16::new("#####\n#S.E###\n#####".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "one of `#`, `.` or a single `S` and `E`", "x")), Day16::new("#####\n#SxE#\n#####".lines()).err());
+        assert_eq!(Some(ParseError::end_of_input(3, "a start `S` and an end `E` in the maze")), Day16::new("#####\n#S..#".lines()).err());
+    }
+}
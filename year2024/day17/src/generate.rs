@@ -0,0 +1,23 @@
+//! Made-up inputs for day 17: a program for the three-bit computer and its starting registers.
+
+use common::synthetic::{Rng, Synthetic};
+
+/// The shape of program real puzzle inputs have, which part 2 relies on, with `A` growing with
+/// `size` for longer output.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let bits = (size.max(1) * 3).min(63) as u32;
+    let a = rng.next_u64() >> (64 - bits) | 1 << (bits - 1);
+    format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,5,7,5,1,6,4,3,0,3,5,5,3,0\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day17;
+
+    #[test]
+    fn a_sets_the_output_length() {
+        let day = Day17::new(generate(&mut Rng::new(17), 5).input.lines()).unwrap();
+        assert_eq!(5, day.part1().split(',').count());
+    }
+}
@@ -0,0 +1,567 @@
+pub mod generate;
+
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
+use grid::Point;
+use log::{debug, trace};
+use render::{Simulation, View};
+use std::cell::Cell;
+use std::fmt;
+use std::io::BufRead;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Operand {
+    Combo(u8),
+    Literal(u8),
+}
+
+// named as the puzzle writes the opcodes
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Clone)]
+enum Instruction {
+    ADV(Operand),
+    BXL(Operand),
+    BST(Operand),
+    JNZ(Operand),
+    BXC(Operand),
+    OUT(Operand),
+    BDV(Operand),
+    CDV(Operand),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Combo(4) => write!(f, "A"),
+            Operand::Combo(5) => write!(f, "B"),
+            Operand::Combo(6) => write!(f, "C"),
+            Operand::Combo(val) | Operand::Literal(val) => write!(f, "{val}"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, operand) = match self {
+            Instruction::ADV(operand) => ("adv", operand),
+            Instruction::BXL(operand) => ("bxl", operand),
+            Instruction::BST(operand) => ("bst", operand),
+            Instruction::JNZ(operand) => ("jnz", operand),
+            Instruction::BXC(operand) => ("bxc", operand),
+            Instruction::OUT(operand) => ("out", operand),
+            Instruction::BDV(operand) => ("bdv", operand),
+            Instruction::CDV(operand) => ("cdv", operand),
+        };
+        write!(f, "{name} {operand}")
+    }
+}
+
+fn interpret_opcode_and_operand(opcode: u8, operand: u8) -> Instruction {
+    assert!(operand < 8);
+    match opcode {
+        0 => Instruction::ADV(Operand::Combo(operand)),
+        1 => Instruction::BXL(Operand::Literal(operand)),
+        2 => Instruction::BST(Operand::Combo(operand)),
+        3 => Instruction::JNZ(Operand::Literal(operand)),
+        4 => Instruction::BXC(Operand::Literal(operand)),
+        5 => Instruction::OUT(Operand::Combo(operand)),
+        6 => Instruction::BDV(Operand::Combo(operand)),
+        7 => Instruction::CDV(Operand::Combo(operand)),
+        _ => { panic!("Invalid opcode: {opcode}"); }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Computer {
+    program: Vec<Instruction>,
+    ip: Cell<usize>,
+    a: Cell<u64>,
+    b: Cell<u64>,
+    c: Cell<u64>,
+}
+
+impl Computer {
+    fn new(program: Vec<Instruction>, ip: usize, a: u64, b: u64, c: u64) -> Self {
+        Computer{program: program.clone(), ip: Cell::new(ip), a: Cell::new(a), b: Cell::new(b), c: Cell::new(c)}
+    }
+
+    fn operand_value(&self, operand: &Operand) -> u64 {
+        match operand {
+            Operand::Combo(val) => {
+                if *val < 4 { return *val as u64; }
+                match val {
+                    4 => self.a.get(),
+                    5 => self.b.get(),
+                    6 => self.c.get(),
+                    _ => { panic!("Invalid combo operand"); }
+                }
+            },
+            Operand::Literal(val) => *val as u64
+        }
+    }
+
+    fn handle(&self, inst: &Instruction, output: &mut Option<String>) -> Option<usize> {
+        match inst {
+            Instruction::ADV(operand) => {
+                let numerator = self.a.get();
+                let power_of_2 = self.operand_value(operand);
+                let denominator = 1 << power_of_2;
+                self.a.set(numerator / denominator);
+                None
+            },
+            Instruction::BXL(operand) => {
+                let b = self.b.get();
+                let value = self.operand_value(operand);
+                let xor_value = b ^ value;
+                self.b.set(xor_value);
+                None
+            },
+            Instruction::BST(operand) => {
+                let value = self.operand_value(operand);
+                let value = value % 8;
+                self.b.set(value);
+                None
+            },
+            Instruction::JNZ(operand) => {
+                let possible_next_ip = self.operand_value(operand);
+                let a = self.a.get();
+                if a == 0 {
+                    None
+                } else {
+                    Some(possible_next_ip as usize)
+                }
+            },
+            Instruction::BXC(_) => {
+                let b = self.b.get();
+                let c = self.c.get();
+                let b = b ^ c;
+                self.b.set(b);
+                None
+            },
+            Instruction::BDV(operand) => {
+                let numerator = self.a.get();
+                let power_of_2 = self.operand_value(operand);
+                let denominator = 1 << power_of_2;
+                self.b.set(numerator / denominator);
+                None
+            },
+            Instruction::CDV(operand) => {
+                let numerator = self.a.get();
+                let power_of_2 = self.operand_value(operand);
+                let denominator = 1 << power_of_2;
+                self.c.set(numerator / denominator);
+                None
+            },
+            Instruction::OUT(operand) => {
+                let value = self.operand_value(operand) % 8;
+                *output = Some(format!("{}", value));
+                None
+            }
+        }
+    }
+
+    fn step(&self) -> (bool, Option<String>) {
+        assert!(self.ip.get() < self.program.len());
+        let mut output: Option<String> = None;
+        if let Some(new_ip) = self.handle(&self.program[self.ip.get()], &mut output) {
+            self.ip.set(new_ip);
+        } else {
+            let new_ip = self.ip.get() + 1;
+            self.ip.set(new_ip);
+        }
+        (self.ip.get() < self.program.len(), output)
+    }
+
+    fn run(&self) -> String {
+        let mut done = false;
+        let mut output: Option<String> = None;
+        while !done {
+            let (work_to_do, possible_string) = self.step();
+            done = !work_to_do;
+            if let Some(string) = possible_string {
+                if let Some(exist_string) = output {
+                    output = Some(format!("{},{}", exist_string, string));
+                }
+                else {
+                    output = Some(string);
+                }
+            }
+        }
+        if let Some(string) = output {
+            string
+        } else {
+            "".to_string()
+        }
+    }
+}
+
+fn interpret_program(program: &[u8]) -> Vec<Instruction> {
+    let mut interpreted_program = Vec::new();
+    assert!(program.len().is_multiple_of(2));
+    let mut offset = 0;
+    while offset < program.len() {
+        interpreted_program.push(interpret_opcode_and_operand(program[offset], program[offset+1]));
+        offset += 2;
+    }
+    interpreted_program
+}
+
+pub struct Day17 {
+    computer: Computer,
+    program_codes: Vec<u8>,
+}
+
+impl Day17 {
+    fn read_register(lines: &mut InputLines<'_>, name: char) -> Result<u64, ParseError> {
+        let expected = format!("`Register {name}: <n>`");
+        let line = lines.expect(expected.as_str())?;
+        let Some(value) = line.text.strip_prefix(&format!("Register {name}:")) else {
+            return Err(line.error(line.text, expected));
+        };
+        line.parse(value.trim_start(), "a register value")
+    }
+
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut lines_iter = InputLines::new(lines);
+        let a = Self::read_register(&mut lines_iter, 'A')?;
+        let b = Self::read_register(&mut lines_iter, 'B')?;
+        let c = Self::read_register(&mut lines_iter, 'C')?;
+        let line = lines_iter.expect_non_blank("`Program: <codes>`")?;
+        let Some(program_codes) = line.text.strip_prefix("Program:") else {
+            return Err(line.error(line.text, "`Program: <codes>`"));
+        };
+        lines_iter.end()?;
+
+        let mut code: Vec<u8> = Vec::new();
+        for value in program_codes.split(",").map(str::trim) {
+            match line.parse::<u8>(value, "a 3-bit number")? {
+                // combo operand 7 is reserved and won't appear in valid programs
+                7 if code.len() % 2 == 1 && !matches!(code.last(), Some(1) | Some(3) | Some(4)) => {
+                    return Err(line.error(value, "a combo operand from 0 to 6"));
+                },
+                value_code if value_code < 8 => { code.push(value_code); },
+                _ => { return Err(line.error(value, "a 3-bit number")); }
+            }
+        }
+        if code.len() % 2 == 1 {
+            return Err(line.missing("an operand for the last opcode"));
+        }
+        let program = interpret_program(&code);
+
+        Ok(Day17{computer: Computer::new(program, 0, a, b, c), program_codes: code})
+    }
+
+    pub fn part1(&self) -> String {
+        // run a copy, so the registers are as read for every call
+        self.computer.clone().run()
+    }
+
+    /// What the program prints when `A` starts at `a`.
+    fn output_for(&self, a: u64) -> String {
+        let computer = self.computer.clone();
+        computer.a.set(a);
+        computer.run()
+    }
+
+    // Part 2 worked through by hand on the puzzle input's program:
+    //
+    //    2nd octet can be 000, 001,
+    // Part 2                              A = 24 (011 010)
+    // 0  BST 4  => A % 8 -> B             B = 2 (010)
+    // 1  BXL 5  => B ^ 5 (101) -> B       B = 7 (111)
+    // 2  CDV 5  => A >> B -> C            C = 0 (000)
+    // 3  BXL 6  => B ^ 6 (110) -> B       B = 0 (110)
+    // 4  ADV 3  => A >> 3 -> A            A = 3
+    // 5  BXC 3  => B ^ C -> B             B = 0 (011)
+    // 6  OUT 5  => OUT(B%8)                   3                              ,0   (A=0,B=0,C=?), A!=0,B=3/11/19/...,
+    // 7  JNZ 0
+    //
+    // 16 loops through the program, on 16th one, A is 0, A is only changed against itself at step 4, always dividing by 8 (with trunc)
+    // At start of each iteration (backwards)
+    //
+    // A = 3, [24,25], [8,64), [64,512), []
+    // B =
+    //    (011)
+    //
+    // A (2nd round) = [24,32)
+    //
+    // (A % 8) ^ 011 ^ (A >> ((A % 8) ^ 101))
+    // 101 ^ 011 = 110
+
+    /// The first digit the program prints when `A` starts at `a`, if it prints any.
+    fn first_output_for(&self, a: u64) -> Option<u8> {
+        let computer = self.computer.clone();
+        computer.a.set(a);
+        loop {
+            let (more, output) = computer.step();
+            if let Some(output) = output {
+                return output.parse().ok();
+            }
+            if !more {
+                return None;
+            }
+        }
+    }
+
+    /// The lowest starting `A` that makes the program print `target`, if any does.
+    fn lowest_a_printing(&self, target: &[u8]) -> Option<u64> {
+        // Running the computer up to its first output gets through one pass of the loop.
+        // Need to step backwards through each output to get them to line up with the target,
+        // to understand what possible 'A' start positions result in the answer for this step.
+        let mut possible_as = vec![0u64];
+
+        for val in target.iter().rev() {
+            let mut next_possible_as = Vec::<u64>::new();
+            for possible_a in possible_as {
+                for i in 0..8 {
+                    let test_a: u64 = (possible_a << 3) | i;
+                    if self.first_output_for(test_a) == Some(*val) {
+                        next_possible_as.push(test_a);
+                    }
+                }
+            }
+            possible_as = next_possible_as;
+            trace!("{:?}", possible_as);
+        }
+        debug!("{:?}", possible_as);
+        // an A with leading zeros matches each output on its own, but stops the program early
+        let target = target.iter().map(u8::to_string).collect::<Vec<_>>().join(",");
+        possible_as.into_iter().filter(|a| self.output_for(*a) == target).min()
+    }
+
+    /// The lowest starting `A` that makes the program print itself, or `None` if no `A` does.
+    pub fn part2(&self) -> Option<u64> {
+        self.lowest_a_printing(&self.program_codes)
+    }
+}
+
+/// The computer part way through the program, for stepping through it.
+#[derive(Clone)]
+pub struct Execution {
+    computer: Computer,
+    output: Vec<String>,
+}
+
+impl Simulation for Day17 {
+    type State = Execution;
+
+    /// The program with the registers as read, whichever the part.
+    fn start(&self, _part: u8) -> Execution {
+        Execution{computer: self.computer.clone(), output: Vec::new()}
+    }
+
+    fn step(&self, execution: &mut Execution) -> bool {
+        if execution.computer.ip.get() >= execution.computer.program.len() { return false; }
+        if let (_, Some(output)) = execution.computer.step() {
+            execution.output.push(output);
+        }
+        true
+    }
+
+    fn view(&self, execution: &Execution) -> View {
+        let computer = &execution.computer;
+        let ip = computer.ip.get();
+        let lines = computer.program.iter().enumerate()
+            .map(|(index, instruction)| format!("{} {index:>2}: {instruction}", if index == ip { '>' } else { ' ' }))
+            .collect();
+        View{lines, actor: (ip < computer.program.len()).then(|| Point::new(0, ip)), panel: vec![
+            ("registers".to_string(), format!("A={} B={} C={}", computer.a.get(), computer.b.get(), computer.c.get())),
+            ("ip".to_string(), ip.to_string()),
+            ("output".to_string(), execution.output.join(",")),
+        ]}
+    }
+}
+
+impl FromReader for Day17 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day17::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day17 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day17::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day17::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day17::part2(self).map_or(Answer::None, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A program shaped like the puzzle's, which prints one digit per three bits of `A`.
+    fn shaped_program(a: u64, first_mask: u8, second_mask: u8) -> Day17 {
+        let input = format!("Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,{first_mask},7,5,1,{second_mask},4,0,0,3,5,5,3,0");
+        Day17::new(input.lines()).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn backward_search_finds_the_lowest_a(a in 1..8u64.pow(4), first_mask in 0..8u8, second_mask in 0..8u8) {
+            let day = shaped_program(a, first_mask, second_mask);
+            let printed = day.part1();
+            let target: Vec<u8> = printed.split(',').map(|digit| digit.parse().unwrap()).collect();
+            let lowest = (0..8u64.pow(target.len() as u32)).find(|a| day.output_for(*a) == printed);
+            prop_assert_eq!(lowest, day.lowest_a_printing(&target));
+        }
+    }
+
+    const SAMPLE_INPUT: &str =
+"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn sample_input_part1_check_output() {
+        let day = Day17::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", day.part1());
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", day.part1());
+    }
+
+    #[test]
+    fn part2_finds_the_a_that_prints_the_program_or_none() {
+        let program = |codes: &str| format!("Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: {codes}");
+        assert_eq!(Some(117440), Day17::new(program("0,3,5,4,3,0").lines()).unwrap().part2());
+        // prints one digit however A starts, so never both of its own
+        let once = Day17::new(program("5,4").lines()).unwrap();
+        assert_eq!((None, Answer::None), (once.part2(), Solution::part2(&once)));
+        // never prints at all
+        assert_eq!(None, Day17::new(program("1,2").lines()).unwrap().part2());
+    }
+
+    #[test]
+    fn stepping_through_the_program_shows_registers_and_output() {
+        let day = Day17::new(SAMPLE_INPUT.lines()).unwrap();
+        let mut execution = day.start(1);
+        let view = day.view(&execution);
+        assert_eq!(vec![">  0: adv 1", "   1: out A", "   2: jnz 0"], view.lines);
+        assert_eq!(("registers".to_string(), "A=729 B=0 C=0".to_string()), view.panel[0]);
+        assert!(day.step(&mut execution));
+        assert!(day.step(&mut execution));
+        assert_eq!(("output".to_string(), "4".to_string()), day.view(&execution).panel[2]);
+        while day.step(&mut execution) {}
+        assert_eq!(None, day.view(&execution).actor);
+        assert_eq!(day.part1(), execution.output.join(","));
+    }
+
+    #[test]
+    fn spaces_around_values_are_ignored() {
+        let input = "Register A:  729\nRegister B:0\nRegister C: 0\n\nProgram: 0, 1,5,4,3 ,0";
+        let day = Day17::new(input.lines()).unwrap();
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(3, "`Register C: <n>`")), Day17::new("Register A: 729\nRegister B: 0\n".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 1, "`Register B: <n>`", "Register C: 0")), Day17::new("Register A: 729\nRegister C: 0".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 13, "a register value", "-729")), Day17::new("Register A: -729".lines()).err());
+        let program = |codes: &str| format!("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: {codes}");
+        assert_eq!(Some(ParseError::new(5, 14, "a 3-bit number", "8")), Day17::new(program("0,1,8,4").lines()).err());
+        assert_eq!(Some(ParseError::new(5, 14, "a 3-bit number", "x")), Day17::new(program("0,1,x,4").lines()).err());
+        assert_eq!(Some(ParseError::new(5, 16, "a combo operand from 0 to 6", "7")), Day17::new(program("0,1,5,7").lines()).err());
+        assert_eq!(Some(ParseError::new(5, 15, "an operand for the last opcode", "")), Day17::new(program("0,1,5").lines()).err());
+    }
+
+    #[test]
+    fn instruction_creation() {
+        assert_eq!(Instruction::ADV(Operand::Combo(1)), interpret_opcode_and_operand(0, 1));
+        assert_eq!(Instruction::BXL(Operand::Literal(2)), interpret_opcode_and_operand(1, 2));
+        assert_eq!(Instruction::BST(Operand::Combo(3)), interpret_opcode_and_operand(2, 3));
+        assert_eq!(Instruction::JNZ(Operand::Literal(4)), interpret_opcode_and_operand(3, 4));
+        assert_eq!(Instruction::BXC(Operand::Literal(5)), interpret_opcode_and_operand(4, 5));
+        assert_eq!(Instruction::OUT(Operand::Combo(6)), interpret_opcode_and_operand(5, 6));
+        assert_eq!(Instruction::BDV(Operand::Combo(7)), interpret_opcode_and_operand(6, 7));
+        assert_eq!(Instruction::CDV(Operand::Combo(0)), interpret_opcode_and_operand(7, 0));
+    }
+
+    #[test]
+    fn interpreted_program_creation() {
+        assert_eq!(vec![Instruction::ADV(Operand::Combo(2))], interpret_program(&[0, 2]));
+        assert_eq!(vec![Instruction::BXL(Operand::Literal(2)), Instruction::BST(Operand::Combo(3))], interpret_program(&[1, 2, 2, 3]));
+    }
+
+    #[test]
+    fn computer_interprets_adv_correctly() {
+        let computer = Computer::new(vec![Instruction::ADV(Operand::Combo(2))], 0, 4, 0, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(1, computer.a.get());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_bxl_correctly() {
+        let computer = Computer::new(vec![Instruction::BXL(Operand::Literal(7))], 0, 0, 5, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(2, computer.b.get());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_bst_correctly() {
+        let computer = Computer::new(vec![Instruction::BST(Operand::Combo(4))], 0, 19, 0, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(3, computer.b.get());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_jnz_with_jump_correctly() {
+        let computer = Computer::new(vec![Instruction::JNZ(Operand::Literal(0))], 0, 1, 0, 0);
+        assert_eq!((true, None), computer.step());
+        assert_eq!(0, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_jnz_without_jump_correctly() {
+        let computer = Computer::new(vec![Instruction::JNZ(Operand::Literal(0))], 0, 0, 0, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_bxc_correctly() {
+        let computer = Computer::new(vec![Instruction::BXC(Operand::Literal(0))], 0, 0, 4, 3);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(1, computer.ip.get());
+        assert_eq!(7, computer.b.get());
+    }
+
+    #[test]
+    fn computer_interprets_bdv_correctly() {
+        let computer = Computer::new(vec![Instruction::BDV(Operand::Combo(2))], 0, 12, 0, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(3, computer.b.get());
+        assert_eq!(12, computer.a.get());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_cdv_correctly() {
+        let computer = Computer::new(vec![Instruction::CDV(Operand::Combo(2))], 0, 16, 0, 0);
+        assert_eq!((false, None), computer.step());
+        assert_eq!(4, computer.c.get());
+        assert_eq!(16, computer.a.get());
+        assert_eq!(1, computer.ip.get());
+    }
+
+    #[test]
+    fn computer_interprets_out_correctly() {
+        let computer = Computer::new(vec![Instruction::OUT(Operand::Combo(4))], 0, 21, 0, 0);
+        let step_response = computer.step();
+        assert!(!step_response.0);
+        if let Some(output) = step_response.1 {
+            assert_eq!("5", &output);
+        }
+        assert_eq!(21, computer.a.get());
+    }
+}
@@ -0,0 +1,39 @@
+//! Made-up inputs for day 18: bytes falling onto a memory space.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// Bytes falling on a memory space `size` wide. The first batch leaves the top row and right
+/// column clear so there's a way out for part 1; the rest eventually fill everything.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let side = size.max(7);
+    let exit = (side - 1, side - 1);
+    let (mut first, mut rest): (Vec<_>, Vec<_>) = (0..side).flat_map(|y| (0..side).map(move |x| (x, y)))
+        .filter(|pos| *pos != (0, 0) && *pos != exit)
+        .partition(|(x, y)| *y != 0 && *x != side - 1);
+    rng.shuffle(&mut first);
+    let bytes = first.len() / 2;
+    rest.extend(first.drain(bytes..));
+    rng.shuffle(&mut rest);
+    first.extend(rest);
+    Synthetic{
+        input: lines(first.into_iter().map(|(x, y)| format!("{x},{y}"))),
+        params: vec![("size", (side - 1).to_string()), ("bytes", bytes.to_string())],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day18;
+    use common::{Answer, Solution};
+
+    #[test]
+    fn exit_is_reachable_after_the_first_batch() {
+        let synthetic = generate(&mut Rng::new(18), 10);
+        let mut day = Day18::parse(&synthetic.input).unwrap();
+        for (name, value) in &synthetic.params {
+            day.configure(name, value).unwrap();
+        }
+        assert_eq!(Answer::Int(18), Solution::part1(&day));
+    }
+}
@@ -0,0 +1,311 @@
+pub mod generate;
+
+use common::{for_each_line, parse_param, Answer, FromReader, InputLines, Line, ParamError, ParseError, ReadError, Solution};
+use grid::{Grid, Point};
+use log::trace;
+use render::{frame_interval, Colour, Frame, Render};
+use search::{astar, Path};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Day18 {
+    falling_data: Vec<Point>,
+    height: usize,
+    width: usize,
+    // how many bytes have fallen when part 1 looks for the exit
+    bytes: usize,
+}
+
+impl Day18 {
+    pub fn new(lines: std::str::Lines<'_>, height: usize, width: usize) -> Result<Self, ParseError> {
+        let mut falling_data = Vec::new();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            falling_data.push(Self::byte(&line)?);
+        }
+        Ok(Day18{falling_data, height: height + 1, width: width + 1, bytes: 1024})
+    }
+
+    fn byte(line: &Line<'_>) -> Result<Point, ParseError> {
+        let mut pos = line.text.split(",").map(str::trim);
+        let x = line.field(&mut pos, "an x coordinate")?;
+        let y = line.field(&mut pos, "a y coordinate")?;
+        line.end(&mut pos)?;
+        Ok(Point::new(x, y))
+    }
+
+    fn exit(&self) -> Point {
+        Point::new(self.width - 1, self.height - 1)
+    }
+
+    fn corrupt(memory: &Grid<bool>, to_add: &[Point]) -> Grid<bool> {
+        let mut memory = memory.clone();
+        for item in to_add {
+            // bytes falling outside the memory space never block the way
+            if let Some(sector) = memory.get_mut(*item) {
+                *sector = true;
+            }
+        }
+        memory
+    }
+
+    fn find_shortest_path(&self, start: Point, end: Point, corrupted: &Grid<bool>) -> Option<Path<Point, usize>> {
+        let open_neighbors = |pos: &Point| -> Vec<(Point, usize)> {
+            corrupted.neighbours(*pos).filter(|(_, neighbor)| !corrupted[*neighbor]).map(|(_, neighbor)| (neighbor, 1)).collect()
+        };
+        astar(start, open_neighbors, |pos| pos.manhattan_distance(&end), |pos| *pos == end)
+    }
+
+    pub fn find_shortest_path_steps(&self, start: Point, end: Point, corrupted: &Grid<bool>) -> Option<usize> {
+        self.find_shortest_path(start, end, corrupted).map(|path| path.cost)
+    }
+
+    /// The fewest steps to the exit once `time` bytes have fallen, or `None` if they've cut it
+    /// off. At most every byte can have fallen.
+    pub fn steps_after(&self, time: usize) -> Option<usize> {
+        assert!(time <= self.falling_data.len());
+
+        let memory = Grid::new(self.width, self.height, false);
+        let corrupted_sectors = Self::corrupt(&memory, &self.falling_data[0..time]);
+
+        self.find_shortest_path_steps(Point::new(0, 0), self.exit(), &corrupted_sectors)
+    }
+
+    pub fn first_blocking_byte(&self, known_good: usize) -> Option<Point> {
+        self.first_blocking_index(known_good).map(|index| self.falling_data[index])
+    }
+
+    /// Index of the first byte to cut off the exit, searching from after the first `known_good`
+    /// bytes if they leave a way through, or `None` if the exit is never cut off.
+    fn first_blocking_index(&self, known_good: usize) -> Option<usize> {
+        let memory = Grid::new(self.width, self.height, false);
+        if self.find_shortest_path_steps(Point::new(0, 0), self.exit(), &Self::corrupt(&memory, &self.falling_data)).is_some() {
+            return None;
+        }
+        let known_good = known_good.min(self.falling_data.len());
+        let known_good = if self.steps_after(known_good).is_some() { known_good } else { 0 };
+
+        let mut min = known_good;
+        let mut max = self.falling_data.len();
+        let mut min_memory = Self::corrupt(&Grid::new(self.width, self.height, false), &self.falling_data[0..min]);
+
+        while min + 1 < max {
+            let mid = min + ((max - min) / 2);
+            trace!("{min} {mid} {max}");
+            let mid_memory = Self::corrupt(&min_memory, &self.falling_data[min..mid]);
+            match self.find_shortest_path_steps(Point::new(0, 0), self.exit(), &mid_memory) {
+                Some(_) => {
+                    min = mid;
+                    min_memory = mid_memory;
+                },
+                None => {
+                    max = mid;
+                }
+            }
+        }
+        Some(min)
+    }
+
+    pub fn part1(&self) -> Option<usize> {
+        self.steps_after(self.bytes)
+    }
+
+    pub fn part2(&self) -> Option<Point> {
+        self.first_blocking_byte(self.bytes)
+    }
+}
+
+impl Render for Day18 {
+    /// Bytes falling into memory, then part 1's shortest way to the exit, or for part 2 the last way
+    /// out before the byte that cuts it off.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let (fallen, blocking) = if part == 2 {
+            match self.first_blocking_index(self.bytes) {
+                Some(index) => (index, Some(self.falling_data[index])),
+                None => (self.falling_data.len(), None),
+            }
+        } else {
+            (self.bytes.min(self.falling_data.len()), None)
+        };
+        let mut frame = Frame::new(self.width, self.height, Colour::OPEN);
+        let mut frames = Vec::new();
+        let interval = frame_interval(fallen);
+        for (count, byte) in self.falling_data[..fallen].iter().enumerate() {
+            frame.set(*byte, Colour::WALL);
+            if (count + 1) % interval == 0 {
+                frames.push(frame.clone());
+            }
+        }
+        let corrupted = Self::corrupt(&Grid::new(self.width, self.height, false), &self.falling_data[..fallen]);
+        if let Some(path) = self.find_shortest_path(Point::new(0, 0), self.exit(), &corrupted) {
+            path.states.into_iter().for_each(|step| frame.set(step, Colour::PATH));
+        }
+        if let Some(byte) = blocking {
+            frame.set(byte, Colour::HIGHLIGHT);
+        }
+        frames.push(frame);
+        frames
+    }
+}
+
+/// Reads the real puzzle's 70 by 70 memory space, like `Solution::parse`.
+impl FromReader for Day18 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut falling_data = Vec::new();
+        for_each_line(reader, |line| {
+            falling_data.push(Self::byte(&line)?);
+            Ok(())
+        })?;
+        Ok(Day18{falling_data, height: 71, width: 71, bytes: 1024})
+    }
+}
+
+impl Solution<'_> for Day18 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day18::new(input.lines(), 70, 70)
+    }
+
+    fn part1(&self) -> Answer {
+        if self.bytes > self.falling_data.len() {
+            return Answer::Error(format!("only {} bytes fall, not {}", self.falling_data.len(), self.bytes));
+        }
+        Day18::part1(self).map_or_else(|| Answer::Error(format!("no way to the exit after {} bytes", self.bytes)), Answer::from)
+    }
+
+    fn part2(&self) -> Answer {
+        match Day18::part2(self) {
+            Some(byte) => Answer::Point(byte.x as i64, byte.y as i64),
+            None => Answer::Error("no byte cuts off the exit".to_string()),
+        }
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "size" => {
+                let size: usize = parse_param(name, value)?;
+                self.height = size + 1;
+                self.width = size + 1;
+            },
+            "bytes" => { self.bytes = parse_param(name, value)?; },
+            _ => { return Err(ParamError::Unknown(name.to_string())); }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str =
+"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_sample_input_part1_is_22_at_time_12() {
+        let day = Day18::new(SAMPLE_INPUT.lines(), 6, 6).unwrap();
+        assert_eq!(Some(22), day.steps_after(12));
+    }
+
+    #[test]
+    fn test_sample_input_part2_is_6_1() {
+        let day = Day18::new(SAMPLE_INPUT.lines(), 6, 6).unwrap();
+        assert_eq!(Some(Point::new(6, 1)), day.first_blocking_byte(12));
+    }
+
+    #[test]
+    fn configured_sample_answers_both_parts() {
+        let mut day = Day18::parse(SAMPLE_INPUT).unwrap();
+        day.configure("size", "6").unwrap();
+        day.configure("bytes", "12").unwrap();
+        assert_eq!(Answer::Int(22), Solution::part1(&day));
+        assert_eq!(Answer::Point(6, 1), Solution::part2(&day));
+        assert_eq!(Err(ParamError::Unknown("saves".to_string())), day.configure("saves", "100"));
+    }
+
+    #[test]
+    fn render_shows_the_way_out_and_the_byte_that_blocks_it() {
+        let mut day = Day18::new(SAMPLE_INPUT.lines(), 6, 6).unwrap();
+        day.configure("bytes", "12").unwrap();
+        let count = |frame: &Frame, colour: Colour| frame.rows().flatten().filter(|cell| **cell == colour).count();
+        let part1 = day.render(1);
+        assert_eq!(13, part1.len());
+        assert_eq!(12, count(&part1[11], Colour::WALL));
+        assert_eq!(23, count(&part1[12], Colour::PATH));
+        let part2 = day.render(2);
+        assert_eq!(Some(Colour::HIGHLIGHT), part2.last().unwrap().get(Point::new(6, 1)));
+    }
+
+    #[test]
+    fn spaces_around_the_comma_are_ignored() {
+        let day = Day18::new("5 ,4\n4, 2".lines(), 6, 6).unwrap();
+        assert_eq!(vec![Point::new(5, 4), Point::new(4, 2)], day.falling_data);
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 3, "a y coordinate", "")), Day18::new("5,4\n4,".lines(), 6, 6).err());
+        assert_eq!(Some(ParseError::new(2, 2, "a y coordinate", "")), Day18::new("5,4\n4".lines(), 6, 6).err());
+        assert_eq!(Some(ParseError::new(1, 1, "an x coordinate", "-5")), Day18::new("-5,4".lines(), 6, 6).err());
+        assert_eq!(Some(ParseError::new(1, 5, "end of line", "1")), Day18::new("5,4,1".lines(), 6, 6).err());
+    }
+
+    #[test]
+    fn streams_the_real_memory_space_from_a_reader() {
+        let mut day = Day18::from_reader(SAMPLE_INPUT.as_bytes()).unwrap();
+        assert_eq!(Day18::new(SAMPLE_INPUT.lines(), 70, 70).unwrap().falling_data, day.falling_data);
+        day.configure("size", "6").unwrap();
+        day.configure("bytes", "12").unwrap();
+        assert_eq!(Some(22), day.part1());
+    }
+
+    #[test]
+    fn every_byte_can_fall_before_part1() {
+        // the two bytes leave the way along the top and down the right
+        let mut day = Day18::parse("0,1\n1,1\n").unwrap();
+        day.configure("size", "2").unwrap();
+        day.configure("bytes", "2").unwrap();
+        assert_eq!(Answer::Int(4), Solution::part1(&day));
+        day.configure("bytes", "3").unwrap();
+        assert_eq!(Answer::Error("only 2 bytes fall, not 3".to_string()), Solution::part1(&day));
+    }
+
+    #[test]
+    fn an_exit_cut_off_or_never_cut_off_is_an_error() {
+        let mut day = Day18::parse("1,0\n1,1\n0,1\n").unwrap();
+        day.configure("size", "2").unwrap();
+        day.configure("bytes", "3").unwrap();
+        assert_eq!(Answer::Error("no way to the exit after 3 bytes".to_string()), Solution::part1(&day));
+        // the exit is cut off by the third byte, even though the search starts after it
+        assert_eq!(Answer::Point(0, 1), Solution::part2(&day));
+
+        let mut day = Day18::parse("2,0\n0,2\n").unwrap();
+        day.configure("size", "2").unwrap();
+        day.configure("bytes", "1").unwrap();
+        assert_eq!(Answer::Error("no byte cuts off the exit".to_string()), Solution::part2(&day));
+        let last = day.render(2).pop().unwrap();
+        assert!(!last.rows().flatten().any(|cell| *cell == Colour::HIGHLIGHT));
+    }
+}
@@ -0,0 +1,36 @@
+//! Made-up inputs for day 19: towel patterns, and designs to make from them.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A hundred patterns, and `size` designs that either split into them or start with a stripe
+/// no pattern has.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    const COLOURS: [char; 4] = ['w', 'u', 'r', 'g'];
+    let mut patterns: Vec<String> = COLOURS.iter().map(|colour| colour.to_string()).collect();
+    while patterns.len() < 100 {
+        let length = rng.range(2, 8) as usize;
+        let pattern: String = (0..length).map(|index| if index > 0 && rng.chance(15) { 'b' } else { rng.pick(&COLOURS) }).collect();
+        if !patterns.contains(&pattern) { patterns.push(pattern); }
+    }
+    let designs = (0..size.max(1)).map(|_| {
+        let mut design = if rng.chance(25) { "bb".to_string() } else { String::new() };
+        while design.len() < 40 {
+            design += &patterns[rng.index(patterns.len())];
+        }
+        design
+    });
+    (patterns.join(", ") + "\n\n" + &lines(designs)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day19;
+
+    #[test]
+    fn some_designs_are_impossible() {
+        let day = Day19::new(generate(&mut Rng::new(19), 40).input.lines()).unwrap();
+        assert!(day.part1() > 0);
+        assert!(day.part1() < 40);
+    }
+}
@@ -1,7 +1,11 @@
+pub mod generate;
+
+use common::{read_input, Answer, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use log::{debug, trace};
 use std::collections::HashMap;
 use std::collections::VecDeque;
-use std::collections::HashSet;
 use std::collections::BinaryHeap;
+use std::io::BufRead;
 
 use std::cmp::Reverse;
 
@@ -67,72 +71,76 @@ pub struct Day19 {
 }
 
 impl Day19 {
-    pub fn new(lines: std::str::Lines<'_>) -> Self {
-        let mut towel_patterns_line: Option<&str> = None;
+    fn check_stripes(line: &Line<'_>, stripes: &str, expected: &str) -> Result<(), ParseError> {
+        if stripes.is_empty() {
+            return Err(line.error(stripes, expected));
+        }
+        match stripes.char_indices().find(|(_, colour)| !"wubrg".contains(*colour)) {
+            Some((index, colour)) => Err(line.error(&stripes[index..index + colour.len_utf8()], "a stripe colour `w`, `u`, `b`, `r` or `g`")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut lines = InputLines::new(lines);
+        let patterns = lines.expect_non_blank("the towel patterns")?;
         let mut displays: Vec<String> = Vec::new();
 
-        for line in lines {
-            if let None = towel_patterns_line {
-                towel_patterns_line = Some(line);
-            }
-            else if line != "" {
-                displays.push(line.to_string());
-            }
+        for line in lines.filter(|line| !line.is_blank()) {
+            let display = line.text.trim_start();
+            Self::check_stripes(&line, display, "a design")?;
+            displays.push(display.to_string());
         }
 
         let mut available_towel_patterns = Trie::new();
 
-        if let Some(patterns) = towel_patterns_line {
-            for pattern in patterns.split(", ") {
-                available_towel_patterns.insert(pattern);
-            }
+        for pattern in patterns.text.split(",").map(str::trim) {
+            Self::check_stripes(&patterns, pattern, "a towel pattern")?;
+            available_towel_patterns.insert(pattern);
         }
-        Day19{available_towel_patterns, desired_displays: displays}
+        Ok(Day19{available_towel_patterns, desired_displays: displays})
     }
 
     pub fn part1(&self) -> usize {
-        const DEBUG: bool = false;
         let mut possible_displays = 0;
 
         for desired_display in &self.desired_displays {
-            if DEBUG { println!("Searching for available towel patterns in {desired_display}"); }
+            debug!("Searching for available towel patterns in {desired_display}");
             let mut more_to_consume = VecDeque::<(Vec<&str>, usize)>::new(); // patterns found, position in desired
             let mut fully_built_display = Vec::<Vec<&str>>::new();
 
             more_to_consume.push_back((Vec::<&str>::new(), 0));
-            while more_to_consume.len() > 0 {
+            while !more_to_consume.is_empty() {
                 let (found_patterns, cur_len) = more_to_consume.pop_back().unwrap();
                 if cur_len == desired_display.len() {
-                    if DEBUG { println!("\tFound: {:?}", found_patterns); }
+                    trace!("\tFound: {:?}", found_patterns);
                     fully_built_display.push(found_patterns);
                     break;
                 } else {
                     for next_pattern in self.available_towel_patterns.find_available_patterns(&desired_display[cur_len..]) {
                         let mut found_patterns = found_patterns.clone();
                         found_patterns.push(next_pattern);
-                        if DEBUG { println!("\t\tFound so far: {:?}", found_patterns); }
+                        trace!("\t\tFound so far: {:?}", found_patterns);
                         more_to_consume.push_back((found_patterns, cur_len + next_pattern.len()));
                     }
                 }
             }
-            if fully_built_display.len() > 0 { possible_displays += 1; }
+            if !fully_built_display.is_empty() { possible_displays += 1; }
         }
         possible_displays
     }
 
     pub fn part2(&self) -> usize {
-        const DEBUG: bool = false;
         let mut possible_displays = 0;
 
         for desired_display in &self.desired_displays {
-            if DEBUG { println!("Searching for available towel patterns in {desired_display}"); }
+            debug!("Searching for available towel patterns in {desired_display}");
             let mut count_ways_to_get_to_prefix_length = HashMap::<usize, usize>::new();
             let mut find_matches_from = BinaryHeap::<Reverse<usize>>::new();
-            let mut last_size_checked = 0;
             find_matches_from.push(Reverse(0));
             count_ways_to_get_to_prefix_length.insert(0, 1);
 
-            while find_matches_from.len() > 0 {
+            while !find_matches_from.is_empty() {
                 if let Some(Reverse(length_to_find)) = find_matches_from.pop() {
                     if let Some(counts_to_now) = count_ways_to_get_to_prefix_length.remove(&length_to_find) {
                         let found_patterns = self.available_towel_patterns.find_available_patterns(&desired_display[length_to_find..]);
@@ -154,6 +162,26 @@ impl Day19 {
     }
 }
 
+impl FromReader for Day19 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day19::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day19 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day19::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day19::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day19::part2(self).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,13 +200,26 @@ bbrgwb
 
     #[test]
     fn part1_sample_input_is_6() {
-        let day = Day19::new(SAMPLE_INPUT.lines());
+        let day = Day19::new(SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(6, day.part1());
     }
 
     #[test]
     fn part2_sample_input_is_16() {
-        let day = Day19::new(SAMPLE_INPUT.lines());
+        let day = Day19::new(SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(16, day.part2());
     }
+
+    #[test]
+    fn spaces_around_patterns_and_displays_are_ignored() {
+        let day = Day19::new("r,  wr, b , g\n\n  brwrr\nbggr".lines()).unwrap();
+        assert_eq!(2, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(2, "the towel patterns")), Day19::new("\n".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 3, "a towel pattern", "")), Day19::new("r, , b\n\nbrwrr".lines()).err());
+        assert_eq!(Some(ParseError::new(3, 3, "a stripe colour `w`, `u`, `b`, `r` or `g`", "7")), Day19::new("r, wr, b\n\nbr7rr".lines()).err());
+    }
 }
@@ -0,0 +1,42 @@
+//! Made-up inputs for day 20: a racetrack snaking back and forth across the map.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A track `size` tiles square, rounded up to odd, with cheats through every wall between its
+/// rows. There's only one track of each size, so nothing is drawn from `rng`.
+pub fn generate(_rng: &mut Rng, size: usize) -> Synthetic {
+    let side = size.max(5) | 1;
+    let mut track = vec![vec!['#'; side]; side];
+    for y in (1..side - 1).step_by(2) {
+        for row in track.iter_mut().skip(y).take(1) {
+            row[1..side - 1].fill('.');
+        }
+        if y + 2 < side - 1 {
+            let turn = if (y / 2) % 2 == 0 { side - 2 } else { 1 };
+            track[y + 1][turn] = '.';
+        }
+    }
+    let last_row = (side - 1) / 2 * 2 - 1;
+    track[1][1] = 'S';
+    track[last_row][if (last_row / 2).is_multiple_of(2) { side - 2 } else { 1 }] = 'E';
+    Synthetic{
+        input: lines(track.into_iter().map(|row| row.into_iter().collect())),
+        params: vec![("saves", (side / 2).to_string())],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day20;
+    use common::{Answer, Solution};
+
+    #[test]
+    fn cheats_cut_through_the_walls() {
+        let synthetic = generate(&mut Rng::new(20), 11);
+        assert_eq!(vec![("saves", "5".to_string())], synthetic.params);
+        let mut day = Day20::parse(&synthetic.input).unwrap();
+        day.configure("saves", "5").unwrap();
+        assert!(matches!(Solution::part1(&day), Answer::Int(cheats) if cheats > 0));
+    }
+}
@@ -0,0 +1,379 @@
+pub mod generate;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{parse_param, read_input, Answer, Explain, Explanation, FromReader, ParamError, ParseError, ReadError, Solution};
+use grid::{Grid, Point};
+use render::{Colour, Frame, Render};
+use search::bfs_distances;
+
+#[derive(Debug)]
+pub struct Day20 {
+    maze: Grid<char>,
+    start: Point,
+    end: Point,
+    // only cheats saving at least this many picoseconds are counted
+    saves_at_least: usize,
+}
+
+impl Day20 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut start_pos: Option<Point> = None;
+        let mut end_pos: Option<Point> = None;
+
+        let map = Grid::parse(lines, "one of `#`, `.` or a single `S` and `E`", |position, pos| {
+            match pos {
+                '#' | '.' => {},
+                'S' if start_pos.is_none() => { start_pos = Some(position); },
+                'E' if end_pos.is_none() => { end_pos = Some(position); },
+                _ => { return None; }
+            }
+            Some(pos)
+        })?;
+        let (Some(start), Some(end)) = (start_pos, end_pos) else {
+            return Err(ParseError::end_of_input(map.height() + 1, "a start `S` and an end `E` on the racetrack"));
+        };
+        Ok(Day20{maze: map, start, end, saves_at_least: 100})
+    }
+
+    fn is_wall(&self, pos: Point) -> bool {
+        self.maze[pos] == '#'
+    }
+
+    fn is_valid_neighbor(&self, pos: &Point, adjust: (isize, isize)) -> Option<Point> {
+        self.maze.offset(*pos, adjust.0, adjust.1).filter(|neighbor| !self.is_wall(*neighbor))
+    }
+    fn find_valid_neighbors(&self, pos: &Point) -> Vec<Point> {
+        self.maze.neighbours(*pos).map(|(_, neighbor)| neighbor).filter(|neighbor| !self.is_wall(*neighbor)).collect()
+    }
+
+    fn find_valid_2space_neighbors(&self, pos: &Point) -> Vec<Point> {
+        let mut neighbors = Vec::<Point>::new();
+        for adjustment in [(0, -2), (0, 2), (-2, 0), (2, 0)] {
+            if let Some(neighbor) = self.is_valid_neighbor(pos, adjustment) { neighbors.push(neighbor); }
+        }
+        neighbors
+    }
+
+    fn find_valid_ends_within_20manhattan_distance(&self, pos: &Point) -> Vec<(Point, usize)> {
+        let mut possible_ends = Vec::<(Point, usize)>::new();
+
+        for neighbor in self.find_valid_2space_neighbors(pos) {
+            possible_ends.push((neighbor, 2));
+        }
+        for distance in 3..21 {
+            for x in 0..distance+1 {
+                let y = distance - x;
+                if let Some(neighbor) = self.is_valid_neighbor(pos, (x, y)) { possible_ends.push((neighbor, distance as usize)); }
+                if x > 0 { if let Some(neighbor) = self.is_valid_neighbor(pos, (-x, y)) { possible_ends.push((neighbor, distance as usize)); } }
+                if x > 0 && y > 0 { if let Some(neighbor) = self.is_valid_neighbor(pos, (-x, -y)) { possible_ends.push((neighbor, distance as usize)); } }
+                if y > 0 { if let Some(neighbor) = self.is_valid_neighbor(pos, (x, -y)) { possible_ends.push((neighbor, distance as usize)); } }
+            }
+        }
+        possible_ends
+    }
+
+    fn walk_the_maze(&self) -> HashMap<Point, usize> {
+        bfs_distances(self.start, |pos| if *pos == self.end { Vec::new() } else { self.find_valid_neighbors(pos) })
+    }
+
+    fn get_2ps_cheats_for_at_least(&self, saves_at_least: usize) -> HashMap<usize, HashSet<(Point, Point)>> {
+        let steps_along_path = self.walk_the_maze();
+        let mut cheats_found = HashMap::<usize, HashSet<(Point, Point)>>::new();
+        for (step_along_path, time) in &steps_along_path {
+            for neighbor in self.find_valid_2space_neighbors(step_along_path) {
+                if let Some(time2) = steps_along_path.get(&neighbor) {
+                    if *time2 > *time && *time2 - *time > 2 {
+                        let time_saved = *time2 - *time - 2;
+                        if time_saved >= saves_at_least {
+                            cheats_found.entry(*time2 - *time - 2).or_default().insert((*step_along_path, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+        cheats_found
+    }
+
+    fn get_20ps_cheats_for_at_least(&self, saves_at_least: usize) -> HashMap<usize, HashSet<(Point, Point)>> {
+        let steps_along_path = self.walk_the_maze();
+        let mut cheats_found = HashMap::<usize, HashSet<(Point, Point)>>::new();
+        for (step_along_path, time) in &steps_along_path {
+            for (neighbor, dist) in self.find_valid_ends_within_20manhattan_distance(step_along_path) {
+                if let Some(time2) = steps_along_path.get(&neighbor) {
+                    if *time2 > *time && *time2 - *time > dist {
+                        let time_saved = *time2 - *time - dist;
+                        if time_saved >= saves_at_least {
+                            cheats_found.entry(time_saved).or_default().insert((*step_along_path, neighbor));
+                        }
+                    }
+                }
+            }
+        }
+        cheats_found
+    }
+    pub fn short_cheats_saving(&self, saves_at_least: usize) -> usize {
+        let cheat_savings_and_locs = self.get_2ps_cheats_for_at_least(saves_at_least);
+        let mut count_of_cheats_for_at_least = 0;
+        for (k,v) in cheat_savings_and_locs {
+            assert!(k >= saves_at_least);
+            count_of_cheats_for_at_least += v.len();
+        }
+        count_of_cheats_for_at_least
+    }
+
+    pub fn long_cheats_saving(&self, saves_at_least: usize) -> usize {
+        let cheat_savings_and_locs = self.get_20ps_cheats_for_at_least(saves_at_least);
+        let mut count_of_cheats_for_at_least = 0;
+        for (k,v) in cheat_savings_and_locs {
+            assert!(k >= saves_at_least);
+            count_of_cheats_for_at_least += v.len();
+        }
+        count_of_cheats_for_at_least
+    }
+
+    pub fn part1(&self) -> usize {
+        self.short_cheats_saving(self.saves_at_least)
+    }
+
+    pub fn part2(&self) -> usize {
+        self.long_cheats_saving(self.saves_at_least)
+    }
+}
+
+impl Render for Day20 {
+    /// The track shaded by how far along it each tile is, with the start and end of every cheat
+    /// saving at least `saves` picoseconds, and for part 1 the wall each one skips through.
+    fn render(&self, part: u8) -> Vec<Frame> {
+        let steps_along_path = self.walk_the_maze();
+        let length = steps_along_path.get(&self.end).copied().unwrap_or(1).max(1);
+        let mut frame = Frame::from_grid(&self.maze, |tile| if *tile == '#' { Colour::WALL } else { Colour::OPEN });
+        for (step, time) in &steps_along_path {
+            // a few dozen shades keeps the whole picture within a GIF palette
+            let shade = (time * 32 / length) as f64 / 32.0;
+            frame.set(*step, Colour::VISITED.blend(Colour::PATH, shade));
+        }
+        let cheats = if part == 2 { self.get_20ps_cheats_for_at_least(self.saves_at_least) } else { self.get_2ps_cheats_for_at_least(self.saves_at_least) };
+        for (cheat_start, cheat_end) in cheats.values().flatten() {
+            if part != 2 {
+                frame.set(Point::new((cheat_start.x + cheat_end.x) / 2, (cheat_start.y + cheat_end.y) / 2), Colour::BOX);
+            }
+            frame.set(*cheat_start, Colour::ROBOT);
+            frame.set(*cheat_end, Colour::HIGHLIGHT);
+        }
+        vec![frame]
+    }
+}
+
+impl Explain for Day20 {
+    /// Every cheat saving at least `saves` picoseconds, the biggest savings first, so there's a
+    /// row for each cheat the answer counts.
+    fn explain(&self, part: u8) -> Explanation {
+        let cheats = if part == 2 { self.get_20ps_cheats_for_at_least(self.saves_at_least) } else { self.get_2ps_cheats_for_at_least(self.saves_at_least) };
+        let mut savings: Vec<(usize, Vec<(Point, Point)>)> = cheats.into_iter().map(|(saved, cheats)| {
+            let mut cheats: Vec<(Point, Point)> = cheats.into_iter().collect();
+            cheats.sort();
+            (saved, cheats)
+        }).collect();
+        savings.sort_by_key(|(saved, _)| std::cmp::Reverse(*saved));
+        let mut explanation = Explanation::new(&["picoseconds saved", "cheat start", "cheat end"]);
+        for (saved, cheats) in savings {
+            for (cheat_start, cheat_end) in cheats {
+                explanation.row(vec![saved.to_string(), cheat_start.to_string(), cheat_end.to_string()]);
+            }
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day20 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day20::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day20 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day20::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day20::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day20::part2(self).into()
+    }
+
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match name {
+            "saves" => { self.saves_at_least = parse_param(name, value)?; },
+            _ => { return Err(ParamError::Unknown(name.to_string())); }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_INPUT: &str =
+"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn sample_input_has_1_cheat_at_least_64() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(1, day.short_cheats_saving(64));
+    }
+
+    #[test]
+    fn sample_input_has_2_cheats_at_least_40() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(2, day.short_cheats_saving(40));
+    }
+
+    #[test]
+    fn sample_input_has_3_cheats_at_least_38() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(3, day.short_cheats_saving(38));
+    }
+
+    #[test]
+    fn sample_input_has_4_cheats_at_least_36() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(4, day.short_cheats_saving(36));
+    }
+
+    #[test]
+    fn sample_input_has_5_cheats_at_least_20() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(5, day.short_cheats_saving(20));
+    }
+
+    #[test]
+    fn sample_input_p2_has_3_cheats_at_least_76() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(3, day.long_cheats_saving(76));
+    }
+
+    #[test]
+    fn sample_input_p2_has_7_cheats_at_least_74() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(7, day.long_cheats_saving(74));
+    }
+
+    #[test]
+    fn sample_input_p2_has_29_cheats_at_least_72() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(29, day.long_cheats_saving(72));
+    }
+
+    #[test]
+    fn sample_input_p2_has_41_cheats_at_least_70() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(41, day.long_cheats_saving(70));
+    }
+
+    #[test]
+    fn sample_input_p2_has_55_cheats_at_least_68() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(55, day.long_cheats_saving(68));
+    }
+
+    #[test]
+    fn sample_input_p2_has_67_cheats_at_least_66() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(67, day.long_cheats_saving(66));
+    }
+
+    #[test]
+    fn sample_input_p2_has_86_cheats_at_least_64() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(86, day.long_cheats_saving(64));
+    }
+
+    #[test]
+    fn sample_input_p2_has_106_cheats_at_least_62() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(106, day.long_cheats_saving(62));
+    }
+
+    #[test]
+    fn sample_input_p2_has_129_cheats_at_least_60() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(129, day.long_cheats_saving(60));
+    }
+    #[test]
+    fn sample_input_p2_has_154_cheats_at_least_58() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(154, day.long_cheats_saving(58));
+    }
+    #[test]
+    fn sample_input_p2_has_193_cheats_at_least_56() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(193, day.long_cheats_saving(56));
+    }
+    #[test]
+    fn sample_input_p2_has_222_cheats_at_least_54() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(222, day.long_cheats_saving(54));
+    }
+    #[test]
+    fn sample_input_p2_has_253_cheats_at_least_52() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(253, day.long_cheats_saving(52));
+    }
+    #[test]
+    fn sample_input_p2_has_285_cheats_at_least_50() {
+        let day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(285, day.long_cheats_saving(50));
+    }
+
+    #[test]
+    fn render_marks_the_cheats_on_the_shaded_track() {
+        let mut day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        day.configure("saves", "64").unwrap();
+        let count = |frame: &Frame, colour: Colour| frame.rows().flatten().filter(|cell| **cell == colour).count();
+        let frame = &day.render(1)[0];
+        assert_eq!(Some(Colour::VISITED), frame.get(day.start));
+        assert_eq!((1, 1, 1), (count(frame, Colour::ROBOT), count(frame, Colour::BOX), count(frame, Colour::HIGHLIGHT)));
+        day.configure("saves", "74").unwrap();
+        // all seven cheats saving 74 start from one of two tiles
+        assert_eq!(2, count(&day.render(2)[0], Colour::ROBOT));
+    }
+
+    #[test]
+    fn explains_every_cheat_by_savings() {
+        let mut day = Day20::new(SAMPLE_INPUT.lines()).unwrap();
+        day.configure("saves", "20").unwrap();
+        let explanation = day.explain(1);
+        assert_eq!(vec!["64", "7,7", "5,7"], explanation.rows[0]);
+        let saved: Vec<&str> = explanation.rows.iter().map(|row| row[0].as_str()).collect();
+        assert_eq!(vec!["64", "40", "38", "36", "20"], saved);
+        day.configure("saves", "74").unwrap();
+        assert_eq!(day.part2(), day.explain(2).rows.len());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 4, "a row 5 wide", "")), Day20::new("#####\n#S.\n#####".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "one of `#`, `.` or a single `S` and `E`", "S")), Day20::new("#####\n#SSE#\n#####".lines()).err());
+        assert_eq!(Some(ParseError::end_of_input(4, "a start `S` and an end `E` on the racetrack")), Day20::new("#####\n#..E#\n#####".lines()).err());
+    }
+}
@@ -0,0 +1,21 @@
+//! Made-up inputs for day 21: door codes to type through the chain of keypads.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` three digit codes.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    lines((0..size.max(1)).map(|_| format!("{:03}A", rng.range(0, 999)))).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day21;
+
+    #[test]
+    fn codes_end_in_activate() {
+        let synthetic = generate(&mut Rng::new(21), 5);
+        assert!(synthetic.input.lines().all(|code| code.len() == 4 && code.ends_with('A')));
+        assert!(Day21::new(synthetic.input.lines()).unwrap().part1() > 0);
+    }
+}
@@ -1,6 +1,14 @@
+pub mod generate;
+
+use common::{for_each_line, Answer, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use log::debug;
 use regex::Regex;
 use std::fmt;
 use std::collections::HashMap;
+use std::io::BufRead;
+
+/// How many times each pair of controls, one pressed after the other, comes up in a sequence.
+type ControlCounts = HashMap<(RobotControl, RobotControl), usize>;
 
 pub struct Day21 {
     codes: Vec<String>,
@@ -157,8 +165,8 @@ impl Day21 {
     fn robot_control_sequence(init: &RobotControl, dest: &RobotControl) -> Vec<RobotControl> {
         let start_pos = get_robot_control_position(init);
         let end_pos   = get_robot_control_position(dest);
-        let controls = Self::move_and_activate_robot_arm(start_pos, end_pos, (2, 1));
-        controls
+
+        Self::move_and_activate_robot_arm(start_pos, end_pos, (2, 1))
     }
 
 
@@ -173,7 +181,7 @@ impl Day21 {
         robot_controls
     }
 
-    fn robot_sequence(robot_controls: &Vec<RobotControl>) -> Vec<RobotControl> {
+    fn robot_sequence(robot_controls: &[RobotControl]) -> Vec<RobotControl> {
         let mut output_controls = Vec::<RobotControl>::new();
         let mut last_position = RobotControl::Activate;
         for control in robot_controls {
@@ -190,7 +198,7 @@ impl Day21 {
     }
 
     fn complexity_length(code: &str) -> usize {
-        let user_sequence = Self::robot_sequence(&mut Self::robot_sequence(&mut Self::numpad_sequence(code)));
+        let user_sequence = Self::robot_sequence(&Self::robot_sequence(&Self::numpad_sequence(code)));
         user_sequence.len()
     }
 
@@ -203,8 +211,8 @@ impl Day21 {
         result
     }
 
-    fn build_control_counts(sequence: &Vec<RobotControl>) -> HashMap<(RobotControl, RobotControl), usize> {
-        let mut control_pattern_counts: HashMap<(RobotControl, RobotControl), usize> = HashMap::new();
+    fn build_control_counts(sequence: &[RobotControl]) -> ControlCounts {
+        let mut control_pattern_counts: ControlCounts = HashMap::new();
         let mut last_control = RobotControl::Activate;
         for control in sequence {
             *control_pattern_counts.entry((last_control, *control)).or_insert(0) += 1;
@@ -213,9 +221,9 @@ impl Day21 {
         control_pattern_counts
     }
 
-    fn calculate_next_5(lower_count: &HashMap<(RobotControl, RobotControl), usize>,
-                        cache_of_5_levels: &mut HashMap<(RobotControl, RobotControl), HashMap<(RobotControl, RobotControl), usize>>)
-            -> HashMap<(RobotControl, RobotControl), usize> {
+    fn calculate_next_5(lower_count: &ControlCounts,
+                        cache_of_5_levels: &mut HashMap<(RobotControl, RobotControl), ControlCounts>)
+            -> ControlCounts {
         let mut new_count_of_sequences = HashMap::new();
         for (pattern, count) in lower_count {
             let result = cache_of_5_levels.entry(*pattern).or_insert(Self::build_control_counts(&Self::robot_control_sequence_n(&pattern.0, &pattern.1, 5)));
@@ -227,9 +235,9 @@ impl Day21 {
         new_count_of_sequences
     }
 
-    fn calculate_next(lower_count: &HashMap<(RobotControl, RobotControl), usize>,
-                    cache_of_controls: &mut HashMap<(RobotControl, RobotControl), HashMap<(RobotControl, RobotControl), usize>>)
-            -> HashMap<(RobotControl, RobotControl), usize> {
+    fn calculate_next(lower_count: &ControlCounts,
+                    cache_of_controls: &mut HashMap<(RobotControl, RobotControl), ControlCounts>)
+            -> ControlCounts {
         let mut new_count_of_sequences = HashMap::new();
         for (pattern, count) in lower_count {
             let result = cache_of_controls.entry(*pattern).or_insert(Self::build_control_counts(&Self::robot_control_sequence_n(&pattern.0, &pattern.1, 1)));
@@ -244,7 +252,7 @@ impl Day21 {
     fn pt2_complexity_length_n_dir_robots(code: &str, n: usize) -> usize {
         assert!(n > 1);
         let first_control_sequence = Self::numpad_sequence(code);
-        let mut cache_for_dir: HashMap<(RobotControl, RobotControl), HashMap<(RobotControl, RobotControl), usize>> = HashMap::new();
+        let mut cache_for_dir: HashMap<(RobotControl, RobotControl), ControlCounts> = HashMap::new();
 
         let mut sequence_for_first_control = Vec::<RobotControl>::new();
         let mut last_control = RobotControl::Activate;
@@ -269,7 +277,7 @@ impl Day21 {
 
     fn pt2_complexity_length(code: &str) -> usize {
         let numpad_control_sequence = Self::numpad_sequence(code);
-        let mut cache_of_5_levels: HashMap<(RobotControl, RobotControl), HashMap<(RobotControl, RobotControl), usize>> = HashMap::new();
+        let mut cache_of_5_levels: HashMap<(RobotControl, RobotControl), ControlCounts> = HashMap::new();
 
         let mut last_control = RobotControl::Activate;
         let mut sequence_for_5_for_all = Vec::<RobotControl>::new();
@@ -292,12 +300,26 @@ impl Day21 {
     }
 
 
-    pub fn new(lines: std::str::Lines<'_>) -> Self {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
         let mut codes = Vec::<String>::new();
-        for line in lines {
-            codes.push(line.to_string());
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            codes.push(Self::code(&line)?);
         }
-        Day21{codes}
+        Ok(Day21{codes})
+    }
+
+    fn code(line: &Line<'_>) -> Result<String, ParseError> {
+        let code = line.text.trim_start();
+        if let Some((index, _)) = code.char_indices().find(|(_, key)| !key.is_ascii_digit() && *key != 'A') {
+            return Err(line.error(&code[index..index + 1], "a numeric keypad key"));
+        }
+        if !code.ends_with('A') {
+            return Err(line.missing("`A` to end the code"));
+        }
+        if !code.contains(|key: char| key.is_ascii_digit()) {
+            return Err(line.error(code, "a door code like `029A`"));
+        }
+        Ok(code.to_string())
     }
 
     pub fn part1(&self) -> usize {
@@ -305,7 +327,7 @@ impl Day21 {
         for code in &self.codes {
             let pt1_complexity_via_pt2 = Self::pt2_complexity_length_n_dir_robots(code, 2);
             let pt1_complexity_len = Self::complexity_length(code);
-            println!("{code}: {pt1_complexity_len} {pt1_complexity_via_pt2}");
+            debug!("{code}: {pt1_complexity_len} {pt1_complexity_via_pt2}");
             sum_of_complexities += pt1_complexity_len * Self::code_as_number(code);
         }
         sum_of_complexities
@@ -316,23 +338,132 @@ impl Day21 {
 
         for code in &self.codes {
             let pt2_complexity_len = Self::pt2_complexity_length_n_dir_robots(code, 25);
-            println!("{code}: {pt2_complexity_len}");
+            debug!("{code}: {pt2_complexity_len}");
             sum_of_complexities += pt2_complexity_len * Self::code_as_number(code);
         }
-        println!("pt2, first: {sum_of_complexities}");
+        debug!("pt2, first: {sum_of_complexities}");
         sum_of_complexities = 0;
         for code in &self.codes {
             let pt2_complexity_len= Self::pt2_complexity_length(code);
-            println!("{code}: {pt2_complexity_len}");
+            debug!("{code}: {pt2_complexity_len}");
             sum_of_complexities += pt2_complexity_len * Self::code_as_number(code);
         }
         sum_of_complexities
     }
 }
 
+impl FromReader for Day21 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut codes = Vec::<String>::new();
+        for_each_line(reader, |line| {
+            codes.push(Self::code(&line)?);
+            Ok(())
+        })?;
+        Ok(Day21{codes})
+    }
+}
+
+impl Solution<'_> for Day21 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day21::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day21::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day21::part2(self).into()
+    }
+}
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use std::collections::{HashSet, VecDeque};
+
+    const NUMPAD: [&str; 4] = ["789", "456", "123", " 0A"];
+    const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+    /// Presses `button` on the keypad of the arm at `level`, where level 0 is the numeric keypad's
+    /// arm. Returns false if the arm would point at the gap or off the keypad, or types a key the
+    /// code doesn't want next.
+    fn press(arms: &mut Vec<(usize, usize)>, level: usize, button: char, code: &str, typed: &mut usize) -> bool {
+        let keypad: &[&str] = if level == 0 { &NUMPAD } else { &DIRECTIONAL };
+        let (row, column) = arms[level];
+        let (row, column) = match button {
+            '^' if row > 0 => (row - 1, column),
+            'v' if row + 1 < keypad.len() => (row + 1, column),
+            '<' if column > 0 => (row, column - 1),
+            '>' if column + 1 < 3 => (row, column + 1),
+            'A' => {
+                let key = keypad[row].as_bytes()[column] as char;
+                if level > 0 { return press(arms, level - 1, key, code, typed); }
+                if code.as_bytes().get(*typed) != Some(&(key as u8)) { return false; }
+                *typed += 1;
+                return true;
+            },
+            _ => { return false; }
+        };
+        arms[level] = (row, column);
+        keypad[row].as_bytes()[column] != b' '
+    }
+
+    /// The fewest presses to type `code` through `robots` directional keypads, found by trying
+    /// every press in every state the arms can be in.
+    fn fewest_presses(code: &str, robots: usize) -> usize {
+        let mut start = vec![(0, 2); robots + 1];
+        start[0] = (3, 2);
+        let mut seen = HashSet::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([(start, 0, 0)]);
+        while let Some((arms, typed, presses)) = queue.pop_front() {
+            if typed == code.len() { return presses; }
+            for button in ['^', 'v', '<', '>', 'A'] {
+                let (mut arms, mut typed) = (arms.clone(), typed);
+                if press(&mut arms, robots, button, code, &mut typed) && seen.insert((arms.clone(), typed)) {
+                    queue.push_back((arms, typed, presses + 1));
+                }
+            }
+        }
+        panic!("{code} can't be typed")
+    }
+
+    fn controls() -> impl Strategy<Value = Vec<RobotControl>> {
+        let control = prop::sample::select(vec![RobotControl::Up, RobotControl::Down, RobotControl::Left, RobotControl::Right, RobotControl::Activate]);
+        prop::collection::vec(control, 1..12)
+    }
+
+    proptest! {
+        // searching every arm position, and expanding 25 robots, are slow in a debug build
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn chosen_moves_are_the_fewest(code in "[0-9]{3}A", robots in 2..4usize) {
+            prop_assert_eq!(fewest_presses(&code, robots), Day21::pt2_complexity_length_n_dir_robots(&code, robots));
+            if robots == 2 {
+                prop_assert_eq!(fewest_presses(&code, robots), Day21::complexity_length(&code));
+            }
+        }
+
+        #[test]
+        fn both_ways_through_25_robots_agree(code in "[0-9]{3}A") {
+            prop_assert_eq!(Day21::pt2_complexity_length_n_dir_robots(&code, 25), Day21::pt2_complexity_length(&code));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn pair_counts_skip_five_robots_at_a_time(controls in controls()) {
+            let mut expanded = controls.clone();
+            for _ in 0..5 {
+                expanded = Day21::robot_sequence(&expanded);
+            }
+            let mut cache = HashMap::new();
+            prop_assert_eq!(Day21::build_control_counts(&expanded), Day21::calculate_next_5(&Day21::build_control_counts(&controls), &mut cache));
+        }
+    }
     const SAMPLE_INPUT: &str =
 "029A
 980A
@@ -348,20 +479,20 @@ mod tests {
 
     #[test]
     fn validate_sequence_029A_through_two_robots_length() {
-        let result_robot_controls = Day21::robot_sequence(&mut Day21::numpad_sequence("029A"));
+        let result_robot_controls = Day21::robot_sequence(&Day21::numpad_sequence("029A"));
         assert_eq!(28, result_robot_controls.len());
     }
 
     #[test]
     fn validate_sequence_029A_through_three_robots_length() {
-        let robot3_controls = Day21::robot_sequence(&mut Day21::robot_sequence(&mut Day21::numpad_sequence("029A")));
+        let robot3_controls = Day21::robot_sequence(&Day21::robot_sequence(&Day21::numpad_sequence("029A")));
 
         assert_eq!(68, robot3_controls.len());
     }
 
     #[test]
     fn part1_sample_input_results_in_126384() {
-        let day = Day21::new(SAMPLE_INPUT.lines());
+        let day = Day21::new(SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(126384, day.part1());
     }
 
@@ -400,4 +531,17 @@ mod tests {
         //    <  v  <    A  >>   ^  A   v   A  ^  A  <vA   <AA >>^AA vA <^A >AA vA ^A <vA >^AA <A >A <v<A >A >^AAA vA <^A >A
         // <v<A >A <A >>^A vAA <^A >A <vA >^A <A >A
     }
+
+    #[test]
+    fn leading_spaces_are_ignored() {
+        let day = Day21::new("  029A\n980A".lines()).unwrap();
+        assert_eq!(vec!["029A".to_string(), "980A".to_string()], day.codes);
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 4, "`A` to end the code", "")), Day21::new("029A\n980".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 2, "a numeric keypad key", "x")), Day21::new("0x9A".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 1, "a door code like `029A`", "A")), Day21::new("A".lines()).err());
+    }
 }
@@ -0,0 +1,21 @@
+//! Made-up inputs for day 22: the buyers' starting secret numbers.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` secret numbers, each fitting the 24 bits the pseudorandom sequence keeps.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    lines((0..size.max(1)).map(|_| rng.range(1, 16_777_215).to_string())).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day22;
+
+    #[test]
+    fn one_buyer_per_line() {
+        let synthetic = generate(&mut Rng::new(22), 10);
+        assert_eq!(10, synthetic.input.lines().count());
+        assert!(Day22::new(synthetic.input.lines()).unwrap().part2() > 0);
+    }
+}
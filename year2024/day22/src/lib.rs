@@ -0,0 +1,237 @@
+pub mod generate;
+
+use common::parallel::par_fold;
+use common::{for_each_line, Answer, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+pub struct Day22 {
+    initial: Vec<u64>
+}
+
+impl Day22 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut initial = Vec::new();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            initial.push(Self::initial_secret(&line)?);
+        }
+        Ok(Day22{initial})
+    }
+
+    fn initial_secret(line: &Line<'_>) -> Result<u64, ParseError> {
+        line.parse::<u64>(line.text.trim_start(), "an initial secret number")
+    }
+
+    fn next_secret_number(secret: u64) -> u64 {
+        const PRUNE: u64 = 16777216;
+        let secret = ((secret * 64) ^ secret)   % PRUNE;
+        let secret = (secret / 32) ^ secret;
+
+        ((secret * 2048) ^ secret) % PRUNE
+    }
+
+    fn get_nth_secret(secret: u64, n: usize) -> u64 {
+        let mut secret = secret;
+        for _ in 0..n {
+            secret = Self::next_secret_number(secret);
+        }
+        secret
+    }
+    pub fn part1(&self) -> u64 {
+        let mut sum_of_2000th_secrets = 0;
+        for val in &self.initial {
+            sum_of_2000th_secrets += Self::get_nth_secret(*val, 2000);
+        }
+        sum_of_2000th_secrets
+    }
+
+    fn calculate_bucket(bananas: &VecDeque<u8>) -> usize {
+        let bananas: Vec<usize> = bananas.iter().map(|v| *v as usize).collect();
+        assert_eq!(5, bananas.len());
+        ((((((9 + bananas[1]- bananas[0]) * 19) + (9 + bananas[2] - bananas[1])) * 19) + (9 + bananas[3] - bananas[2])) * 19) + (9 + bananas[4] - bananas[3])
+    }
+
+    fn walk_through_secrets_to_setup_options(secret: u64, n: usize, options: &mut [Option<u8>]) {
+        let mut banana_sequence = VecDeque::<u8>::new();
+        assert!(n > 5);
+
+        let mut secret = secret;
+        for _ in 0..5 {
+            banana_sequence.push_back((secret % 10) as u8);
+            secret = Self::next_secret_number(secret);
+        }
+
+        for _ in 5..n {
+            let pos = Self::calculate_bucket(&banana_sequence);
+            if options[pos].is_none() {
+                options[pos] = Some(*banana_sequence.back().unwrap());
+            }
+            banana_sequence.pop_front();
+            banana_sequence.push_back((secret % 10) as u8);
+            secret = Self::next_secret_number(secret);
+        }
+    }
+
+    pub fn part2(&self) -> u32 {
+        let max_bananas_for_sequence = par_fold(&self.initial, || vec![0u32; 130322], |mut max_bananas_for_sequence, val| {
+            let mut this_individuals_options: Vec<Option<u8>> = vec![None; 130322];
+
+            Self::walk_through_secrets_to_setup_options(*val, 2000, &mut this_individuals_options);
+            for i in 0..130322 {
+                if let Some(val) = this_individuals_options[i] {
+                    max_bananas_for_sequence[i] += val as u32;
+                }
+            }
+            max_bananas_for_sequence
+        }, |mut totals, other_totals| {
+            totals.iter_mut().zip(other_totals).for_each(|(total, other)| *total += other);
+            totals
+        });
+
+        let mut max_bananas = 0;
+        for num_bananas in max_bananas_for_sequence {
+            if num_bananas > max_bananas { max_bananas = num_bananas; }
+        }
+        max_bananas
+    }
+}
+
+impl FromReader for Day22 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut initial = Vec::new();
+        for_each_line(reader, |line| {
+            initial.push(Self::initial_secret(&line)?);
+            Ok(())
+        })?;
+        Ok(Day22{initial})
+    }
+}
+
+impl Solution<'_> for Day22 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day22::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day22::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day22::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    /// The most bananas any four price changes get, keeping every buyer's price for every
+    /// sequence of changes in a map.
+    fn most_bananas_by_map(initial: &[u64]) -> u32 {
+        let mut totals = HashMap::<[i8; 4], u32>::new();
+        for secret in initial {
+            let prices: Vec<i8> = (0..2000).map(|n| (Day22::get_nth_secret(*secret, n) % 10) as i8).collect();
+            let mut seen = HashMap::new();
+            for window in prices.windows(5) {
+                let changes = [window[1] - window[0], window[2] - window[1], window[3] - window[2], window[4] - window[3]];
+                seen.entry(changes).or_insert(window[4]);
+            }
+            for (changes, price) in seen {
+                *totals.entry(changes).or_insert(0) += price as u32;
+            }
+        }
+        totals.into_values().max().unwrap_or(0)
+    }
+
+    proptest! {
+        #[test]
+        fn buckets_tell_changes_apart(first in prop::array::uniform5(0..10u8), second in prop::array::uniform5(0..10u8)) {
+            let changes = |prices: &[u8; 5]| prices.windows(2).map(|pair| pair[1] as i8 - pair[0] as i8).collect::<Vec<_>>();
+            let bucket = |prices: &[u8; 5]| Day22::calculate_bucket(&prices.iter().copied().collect());
+            prop_assert!(bucket(&first) < 130322);
+            prop_assert_eq!(changes(&first) == changes(&second), bucket(&first) == bucket(&second));
+        }
+    }
+
+    proptest! {
+        // every buyer walks through 2000 secrets, which is slow in a debug build
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn buckets_find_the_most_bananas(initial in prop::collection::vec(1..16777216u64, 1..4)) {
+            let input = initial.iter().map(u64::to_string).collect::<Vec<_>>().join("\n");
+            prop_assert_eq!(most_bananas_by_map(&initial), Day22::new(input.lines()).unwrap().part2());
+        }
+    }
+    const SAMPLE_INPUT: &str =
+"1
+10
+100
+2024";
+
+    #[test]
+    fn part1_with_sample_input() {
+        let day = Day22::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(37327623, day.part1());
+    }
+
+    #[test]
+    fn first_secret_from_123() {
+        assert_eq!(15887950, Day22::next_secret_number(123));
+    }
+
+    #[test]
+    fn second_secret_from_123() {
+        assert_eq!(16495136, Day22::next_secret_number(15887950));
+        assert_eq!(16495136, Day22::get_nth_secret(123, 2));
+    }
+
+    #[test]
+    fn third_secret_from_123() {
+        assert_eq!(527345, Day22::next_secret_number(16495136));
+        assert_eq!(527345, Day22::get_nth_secret(123, 3));
+    }
+
+    #[test]
+    fn test_bucket_maker() {
+        let mut bananas = VecDeque::<u8>::new();
+        bananas.push_back(0);
+        bananas.push_back(0);
+        bananas.push_back(0);
+        bananas.push_back(0);
+        bananas.push_back(0);
+        assert_eq!((((((9 * 19) + 9) * 19) + 9) * 19) + 9, Day22::calculate_bucket(&bananas));
+    }
+
+    #[test]
+    fn test_part2_small_sample() {
+        const SMALL_SAMPLE: &str =
+"1
+2
+3
+2024";
+        let day = Day22::new(SMALL_SAMPLE.lines()).unwrap();
+        assert_eq!(23, day.part2());
+    }
+
+    #[test]
+    fn leading_spaces_are_ignored() {
+        let day = Day22::new(" 1\n10\n  100\n2024".lines()).unwrap();
+        assert_eq!(37327623, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 1, "an initial secret number", "1O")), Day22::new("1\n1O\n100".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 1, "an initial secret number", "-1")), Day22::new("-1".lines()).err());
+    }
+
+    #[test]
+    fn streams_from_a_reader() {
+        let day = Day22::from_reader(SAMPLE_INPUT.as_bytes()).unwrap();
+        assert_eq!(37327623, day.part1());
+    }
+}
@@ -0,0 +1,49 @@
+//! Made-up inputs for day 23: the LAN party's network map.
+
+use std::collections::HashSet;
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` computers, up to 676, each linked to a few others, with one group of up to 13 all
+/// linked together.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let size = size.clamp(8, 26 * 26);
+    let mut names: Vec<String> = (0..26 * 26).map(|index| {
+        let letter = |value: usize| (b'a' + value as u8) as char;
+        format!("{}{}", letter(index / 26), letter(index % 26))
+    }).collect();
+    rng.shuffle(&mut names);
+    names.truncate(size);
+    let mut links = HashSet::new();
+    let group = &names[..(size / 8).clamp(3, 13)];
+    for (index, first) in group.iter().enumerate() {
+        for second in &group[index + 1..] {
+            links.insert((first.clone(), second.clone()));
+        }
+    }
+    for first in &names {
+        for _ in 0..4 {
+            let second = names[rng.index(names.len())].clone();
+            if *first != second && !links.contains(&(second.clone(), first.clone())) {
+                links.insert((first.clone(), second));
+            }
+        }
+    }
+    let mut links: Vec<String> = links.into_iter().map(|(first, second)| format!("{first}-{second}")).collect();
+    links.sort();
+    rng.shuffle(&mut links);
+    lines(links).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day23;
+
+    #[test]
+    fn party_is_at_least_the_planted_group() {
+        let synthetic = generate(&mut Rng::new(23), 40);
+        let day = Day23::new(synthetic.input.lines()).unwrap();
+        assert!(day.part2().split(',').count() >= 5);
+    }
+}
@@ -0,0 +1,299 @@
+pub mod generate;
+
+use common::{for_each_line, Answer, Explain, Explanation, FromReader, InputLines, Line, ParseError, ReadError, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+#[derive(Default, Debug)]
+struct Graph {
+    // every computer's connections, both ways round
+    links: HashMap<String, HashSet<String>>,
+}
+
+impl Graph {
+    fn connect(&mut self, first: &str, second: &str) {
+        self.links.entry(first.to_string()).or_default().insert(second.to_string());
+        self.links.entry(second.to_string()).or_default().insert(first.to_string());
+    }
+
+    fn connected(&self, first: &str, second: &str) -> bool {
+        self.links.get(first).is_some_and(|links| links.contains(second))
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct Day23 {
+    network_map: Graph,
+}
+
+impl Day23 {
+    fn computer_name<'a>(line: &Line<'a>, name: &'a str) -> Result<&'a str, ParseError> {
+        let name = name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase()) {
+            return Err(line.error(name, "a computer name"));
+        }
+        Ok(name)
+    }
+
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut day = Day23::default();
+        for line in InputLines::new(lines).filter(|line| !line.is_blank()) {
+            day.add_connection(&line)?;
+        }
+        Ok(day)
+    }
+
+    fn add_connection(&mut self, line: &Line<'_>) -> Result<(), ParseError> {
+        let Some((first, second)) = line.text.split_once("-") else {
+            return Err(line.missing("`-` between two computers"));
+        };
+        self.network_map.connect(Self::computer_name(line, first)?, Self::computer_name(line, second)?);
+        Ok(())
+    }
+
+    /// Every set of three computers all connected to each other, where one starts with `t`.
+    fn three_way_connections(&self) -> HashSet<(&str, &str, &str)> {
+        let mut three_way_connections = HashSet::<(&str, &str, &str)>::new();
+        for (node, links) in &self.network_map.links {
+            if node.starts_with('t') {
+                for second in links {
+                    for third in &self.network_map.links[second] {
+                        if third != node && self.network_map.connected(third, node) {
+                            let mut computer_set = [node.as_str(), second.as_str(), third.as_str()];
+                            computer_set.sort();
+                            three_way_connections.insert((computer_set[0], computer_set[1], computer_set[2]));
+                        }
+                    }
+                }
+            }
+        }
+
+        three_way_connections
+    }
+
+    pub fn part1(&self) -> usize {
+        self.three_way_connections().len()
+    }
+
+    fn node_connected_to_set(&self, node: &str, set_so_far: &HashSet<&str>) -> bool {
+        for entry in set_so_far {
+            if !self.network_map.connected(node, entry) { return false; }
+        }
+        true
+    }
+
+    fn find_set_in_progress(sets_in_progress: &[HashSet<&str>], set_to_test: &HashSet<&str>) -> bool {
+        for set_in_progress in sets_in_progress {
+            if set_to_test.len() == set_in_progress.intersection(set_to_test).count() { return true; }
+        }
+        false
+    }
+
+    fn get_largest_connected_subgraph<'s>(&'s self, node: &'s str, largest_so_far: usize) -> HashSet<&'s str> {
+        let mut attempt_to_add_to = VecDeque::<(HashSet<&str>, HashSet<&str>)>::new();
+        let mut connected_nodes = HashSet::<&str>::new();
+
+        for linked in &self.network_map.links[node] {
+            connected_nodes.insert(linked);
+            let mut partial_connections = HashSet::<&str>::new();
+            partial_connections.insert(node);
+            partial_connections.insert(linked);
+            attempt_to_add_to.push_back((partial_connections, HashSet::<&str>::new()));
+        }
+        if connected_nodes.len() > largest_so_far {
+            let mut sets_in_progress = HashMap::<usize, Vec<HashSet<&str>>>::new();
+
+            while !attempt_to_add_to.is_empty() {
+                let (partial_connections, mut known_bad) = attempt_to_add_to.pop_front().unwrap();
+                let sets_of_this_size = sets_in_progress.entry(partial_connections.len()).or_default();
+                if !Self::find_set_in_progress(sets_of_this_size, &partial_connections) {
+                    sets_of_this_size.push(partial_connections.clone());
+                    let mut next_partials = Vec::<HashSet<&str>>::new();
+
+                    trace!("Testing {:?}, known bad: {:?}", partial_connections, known_bad);
+                    for node in &connected_nodes {
+                        if !partial_connections.contains(node) && !known_bad.contains(node) {
+                            if self.node_connected_to_set(node, &partial_connections) {
+                                let mut more_work = partial_connections.clone();
+                                more_work.insert(node);
+                                next_partials.push(more_work);
+                            } else {
+                                known_bad.insert(node);
+                            }
+                        }
+                    }
+                    if !next_partials.is_empty() {
+                        for partial in next_partials {
+                            attempt_to_add_to.push_back((partial, known_bad.clone()));
+                        }
+                    }
+                }
+                if attempt_to_add_to.is_empty() {
+                    return partial_connections;
+                }
+            }
+        }
+        HashSet::new()
+    }
+
+    fn order_display_output(connected_set: &HashSet<&str>) -> String {
+        let mut nodelist: Vec<&str> = connected_set.iter().copied().collect();
+        nodelist.sort();
+        let mut output = nodelist[0].to_string();
+        for node in &nodelist[1..] {
+            output = format!("{},{}", output, node);
+        }
+        output
+    }
+
+    fn largest_connected_subgraph(&self) -> HashSet<&str> {
+        let mut largest_connected_subgraph = HashSet::new();
+        debug!("Number of Nodes: {}", self.network_map.links.len());
+        for node in self.network_map.links.keys() {
+            trace!("Checking with node: {}", node);
+            let node_list = self.get_largest_connected_subgraph(node, largest_connected_subgraph.len());
+            if node_list.len() > largest_connected_subgraph.len() {
+                debug!("Largest so far: {:?}", node_list);
+                largest_connected_subgraph = node_list;
+            }
+        }
+
+        largest_connected_subgraph
+    }
+
+    pub fn part2(&self) -> String {
+        Self::order_display_output(&self.largest_connected_subgraph())
+    }
+}
+
+impl Explain for Day23 {
+    /// For part 1 each set of three, and for part 2 each member of the LAN party with how many of
+    /// the others it's linked to, which is all of them.
+    fn explain(&self, part: u8) -> Explanation {
+        if part == 1 {
+            let mut sets: Vec<(&str, &str, &str)> = self.three_way_connections().into_iter().collect();
+            sets.sort();
+            let mut explanation = Explanation::new(&["computers"]);
+            for (first, second, third) in sets {
+                explanation.row(vec![format!("{first},{second},{third}")]);
+            }
+            return explanation;
+        }
+        let clique = self.largest_connected_subgraph();
+        let mut members: Vec<&str> = clique.iter().copied().collect();
+        members.sort();
+        let mut explanation = Explanation::new(&["member", "linked to"]);
+        for member in &members {
+            let linked = members.iter().filter(|other| self.network_map.connected(member, other)).count();
+            explanation.row(vec![member.to_string(), format!("{linked} of {}", members.len() - 1)]);
+        }
+        explanation
+    }
+}
+
+impl FromReader for Day23 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        let mut day = Day23::default();
+        for_each_line(reader, |line| day.add_connection(&line))?;
+        Ok(day)
+    }
+}
+
+impl Solution<'_> for Day23 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day23::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day23::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        Day23::part2(self).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_INPUT: &str =
+"kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn part1_with_sample_yields_7_networks() {
+        let day = Day23::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!(7, day.part1());
+    }
+
+    #[test]
+    fn part2_with_sample_yields_password() {
+        let day = Day23::new(SAMPLE_INPUT.lines()).unwrap();
+        assert_eq!("co,de,ka,ta", day.part2());
+    }
+
+    #[test]
+    fn explains_the_sets_and_the_clique() {
+        let day = Day23::new(SAMPLE_INPUT.lines()).unwrap();
+        let explanation = day.explain(1);
+        assert_eq!(7, explanation.rows.len());
+        assert_eq!(vec!["co,de,ta"], explanation.rows[0]);
+        let explanation = day.explain(2);
+        let members: Vec<&str> = explanation.rows.iter().map(|row| row[0].as_str()).collect();
+        assert_eq!(vec!["co", "de", "ka", "ta"], members);
+        assert!(explanation.rows.iter().all(|row| row[1] == "3 of 3"));
+    }
+
+    #[test]
+    fn spaces_around_the_dash_are_ignored() {
+        let day = Day23::new("kh-tc\nqp - kh\ntc -qp".lines()).unwrap();
+        assert_eq!(1, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::new(2, 3, "`-` between two computers", "")), Day23::new("kh-tc\nqp".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 4, "a computer name", "")), Day23::new("kh-tc\nqp-".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 4, "a computer name", "t1")), Day23::new("kh-t1".lines()).err());
+    }
+
+    #[test]
+    fn streams_from_a_reader() {
+        let day = Day23::from_reader(SAMPLE_INPUT.as_bytes()).unwrap();
+        assert_eq!(7, day.part1());
+        assert_eq!("co,de,ka,ta", day.part2());
+    }
+}
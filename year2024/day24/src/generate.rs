@@ -0,0 +1,85 @@
+//! Made-up inputs for day 24: a ripple carry adder, with some pairs of gate outputs swapped.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// A ripple carry adder for `bits` bit numbers with `swaps` pairs of gate outputs swapped, its
+/// gates in a random order.
+///
+/// Each swap is inside one bit's slice of the adder, between wires whose gates do different
+/// jobs, as in the real puzzle: the two half-adder outputs, or the sum bit with either the
+/// carry or the AND feeding it. The first and last bits are left alone, so `bits` has to be at
+/// least two more than `swaps`.
+pub fn adder(rng: &mut Rng, bits: usize, swaps: usize) -> String {
+    assert!(bits >= swaps + 2, "{bits} bits only has room for {} swaps", bits.saturating_sub(2));
+    let mut names = (0..).map(|index: usize| {
+        let letter = |value: usize| (b'a' + value as u8) as char;
+        format!("{}{}{}", letter(index / 400 % 23), letter(index / 20 % 20), letter(index % 20))
+    });
+    let mut wire = || names.next().unwrap();
+    let mut inputs = Vec::new();
+    for name in ['x', 'y'] {
+        for bit in 0..bits {
+            inputs.push(format!("{name}{bit:02}: {}", rng.range(0, 1)));
+        }
+    }
+
+    // gates as their inputs and output, so outputs can be swapped before they're written out
+    let mut gates = Vec::new();
+    let mut carry = wire();
+    gates.push(("x00 XOR y00".to_string(), "z00".to_string()));
+    gates.push(("x00 AND y00".to_string(), carry.clone()));
+    let mut slices = Vec::new();
+    for bit in 1..bits {
+        let (sum, both, carried) = (wire(), wire(), wire());
+        let carry_out = if bit == bits - 1 { format!("z{bits:02}") } else { wire() };
+        slices.push(gates.len());
+        gates.push((format!("x{bit:02} XOR y{bit:02}"), sum.clone()));
+        gates.push((format!("{sum} XOR {carry}"), format!("z{bit:02}")));
+        gates.push((format!("y{bit:02} AND x{bit:02}"), both.clone()));
+        gates.push((format!("{carry} AND {sum}"), carried.clone()));
+        gates.push((format!("{both} OR {carried}"), carry_out.clone()));
+        carry = carry_out;
+    }
+    slices.pop();
+    rng.shuffle(&mut slices);
+    for first in slices.into_iter().take(swaps) {
+        // offsets into the slice: the half-adder sum and AND, or the z bit and the carry AND or OR
+        let (a, b) = rng.pick(&[(0, 2), (1, 3), (1, 4)]);
+        let target = gates[first + a].1.clone();
+        gates[first + a].1 = std::mem::replace(&mut gates[first + b].1, target);
+    }
+
+    rng.shuffle(&mut gates);
+    lines(inputs) + "\n" + &lines(gates.into_iter().map(|(gate, target)| format!("{gate} -> {target}")))
+}
+
+/// An adder for `size` bit numbers, up to 44, with four swaps when there are bits enough.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let bits = size.clamp(2, 44);
+    adder(rng, bits, (bits - 2).min(4)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day24;
+
+    #[test]
+    fn part2_finds_every_swap() {
+        for seed in 1..10 {
+            let day = Day24::new(generate(&mut Rng::new(seed), 44).input.lines()).unwrap();
+            assert_eq!(8, day.part2().split(',').count());
+        }
+    }
+
+    #[test]
+    fn adder_without_swaps_adds() {
+        let input = adder(&mut Rng::new(24), 10, 0);
+        let bits = |name: char| (0..10).rev().fold(0, |number, bit| {
+            number * 2 + usize::from(input.contains(&format!("{name}{bit:02}: 1")))
+        });
+        let day = Day24::new(input.lines()).unwrap();
+        assert_eq!(bits('x') + bits('y'), day.part1());
+        assert_eq!("", day.part2());
+    }
+}
@@ -1,6 +1,11 @@
+pub mod generate;
+
 use regex::Regex;
 use std::collections::HashSet;
 use std::collections::HashMap;
+use std::io::BufRead;
+
+use common::{read_input, Answer, FromReader, InputLines, Line, ParseError, ReadError, Solution};
 
 pub struct Day24 {
     wires: HashSet<String>,
@@ -25,42 +30,60 @@ pub struct Gate {
 
 impl Day24 {
 
-    pub fn new(lines: std::str::Lines<'_>) -> Self {
-        let init_values_re = Regex::new(r"^([a-z0-9]+): (0|1)$").unwrap();
-        let gate_connections_re = Regex::new(r"^([a-z0-9]+) (AND|OR|XOR) ([a-z0-9]+) -> ([a-z0-9]+)$").unwrap();
+    fn wire_name<'a>(line: &Line<'a>, fields: &mut impl Iterator<Item = &'a str>) -> Result<String, ParseError> {
+        match fields.next() {
+            Some(name) if name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) => Ok(name.to_string()),
+            Some(name) => Err(line.error(name, "a wire name")),
+            None => Err(line.missing("a wire name")),
+        }
+    }
 
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
         let mut wires = HashSet::<String>::new();
         let mut init_values = HashMap::<String, bool>::new();
         let mut gates       = HashMap::<String, Gate>::new();
-        let mut init_values_done = false;
-
-        for line in lines {
-            if !init_values_done && line == "" {
-                init_values_done = true;
-            } else if !init_values_done {
-                let init_values_caps = init_values_re.captures(line).unwrap();
-                let target = init_values_caps[1].to_string();
-                wires.insert(target.clone());
-                init_values.insert(target, &init_values_caps[2] == "1");
-            } else {
-                assert!(init_values_done);
-                let gates_cap = gate_connections_re.captures(line).unwrap();
-                let target = gates_cap[4].to_string();
-                let left   = gates_cap[1].to_string();
-                let right  = gates_cap[3].to_string();
-                let gate   = &gates_cap[2];
-                wires.insert(target.clone());
-                wires.insert(left.clone());
-                wires.insert(right.clone());
-                match gate {
-                    "AND" => gates.insert(target.clone(), Gate{op: GateOp::AND, left, right, target}),
-                    "OR" =>  gates.insert(target.clone(), Gate{op: GateOp::OR, left, right, target}),
-                    "XOR" => gates.insert(target.clone(), Gate{op: GateOp::XOR, left, right, target}),
-                    _ => todo!()
-                };
+        let mut lines = InputLines::new(lines);
+
+        loop {
+            let line = lines.expect("a blank line before the gates")?;
+            if line.is_blank() { break; }
+            let Some((target, value)) = line.text.split_once(':') else {
+                return Err(line.missing("`:` after the wire name"));
+            };
+            let target = Self::wire_name(&line, &mut target.split_whitespace())?;
+            let value = match value.trim() {
+                "0" => false,
+                "1" => true,
+                other => { return Err(line.error(other, "`0` or `1`")); }
+            };
+            wires.insert(target.clone());
+            init_values.insert(target, value);
+        }
+
+        for line in lines.filter(|line| !line.is_blank()) {
+            let mut fields = line.text.split_whitespace();
+            let left = Self::wire_name(&line, &mut fields)?;
+            let op = match fields.next() {
+                Some("AND") => GateOp::AND,
+                Some("OR") => GateOp::OR,
+                Some("XOR") => GateOp::XOR,
+                Some(other) => { return Err(line.error(other, "`AND`, `OR` or `XOR`")); },
+                None => { return Err(line.missing("`AND`, `OR` or `XOR`")); }
+            };
+            let right = Self::wire_name(&line, &mut fields)?;
+            match fields.next() {
+                Some("->") => {},
+                Some(other) => { return Err(line.error(other, "`->`")); },
+                None => { return Err(line.missing("`->`")); }
             }
+            let target = Self::wire_name(&line, &mut fields)?;
+            line.end(&mut fields)?;
+            wires.insert(target.clone());
+            wires.insert(left.clone());
+            wires.insert(right.clone());
+            gates.insert(target.clone(), Gate{op, left, right, target});
         }
-        Day24{wires, init_values, gates}
+        Ok(Day24{wires, init_values, gates})
     }
 
     pub fn part1(&self) -> usize {
@@ -70,27 +93,27 @@ impl Day24 {
         let mut z_value_wires = Vec::<&str>::new();
 
         for wire in &self.wires {
-            if z_wire_re.is_match(&wire) {
-                let z_wire = self.wires.get(&wire as &str).unwrap();
-                z_value_wires.push(&z_wire);
+            if z_wire_re.is_match(wire) {
+                let z_wire = self.wires.get(wire as &str).unwrap();
+                z_value_wires.push(z_wire);
             }
         }
         z_value_wires.sort();
 
         let mut wire_values = HashMap::<&str, bool>::new();
         for (wire, initial_value) in &self.init_values {
-            wire_values.insert(&wire, *initial_value);
+            wire_values.insert(wire, *initial_value);
         }
 
         for z_value_wire in &z_value_wires {
-            if let None = wire_values.get(z_value_wire) {
+            if !wire_values.contains_key(z_value_wire) {
                 stack_of_wires.push(z_value_wire);
             }
         }
 
-        while stack_of_wires.len() > 0 {
-            let wire_to_find = stack_of_wires.pop().unwrap();
-            if let None = wire_values.get(wire_to_find) {
+        while let Some(wire_to_find) = stack_of_wires.pop() {
+
+            if !wire_values.contains_key(wire_to_find) {
                 if let Some(gate) = self.gates.get(wire_to_find) {
                     match (wire_values.get(&gate.left as &str), wire_values.get(&gate.right as &str)) {
                         (Some(a), Some(b)) => {
@@ -100,8 +123,8 @@ impl Day24 {
                                 GateOp::OR  => wire_values.insert(wire_to_find, *a || *b),
                             };
                         },
-                        (Some(a), None) => { stack_of_wires.push(wire_to_find); stack_of_wires.push(&gate.right); },
-                        (None, Some(b)) => { stack_of_wires.push(wire_to_find); stack_of_wires.push(&gate.left); },
+                        (Some(_), None) => { stack_of_wires.push(wire_to_find); stack_of_wires.push(&gate.right); },
+                        (None, Some(_)) => { stack_of_wires.push(wire_to_find); stack_of_wires.push(&gate.left); },
                         (None, None) => { stack_of_wires.push(wire_to_find); stack_of_wires.push(&gate.right); stack_of_wires.push(&gate.left); },
                     }
                 }
@@ -110,10 +133,10 @@ impl Day24 {
 
         let mut value: usize = 0;
 
-        for z_wire in (&z_value_wires).into_iter().rev() {
-            match wire_values.get(&z_wire as &str) {
+        for z_wire in z_value_wires.iter().rev() {
+            match wire_values.get(z_wire as &str) {
                 Some(true) => value = (value << 1) | 1,
-                Some(false) => value = (value << 1) | 0,
+                Some(false) => value <<= 1,
                 None => panic!("Missing z_wire {} value still!", z_wire),
             }
         }
@@ -167,122 +190,59 @@ impl Day24 {
 gbf,hdt,jgt,mht,nbf,z05,z09,z30
      */
 
-    fn get_expected_x_and_y_wires_from_z(z_value_wire: &str) -> (String, String) {
-        let value_wire_id = Regex::new(r"^z(\d+)$").unwrap();
-        let caps = value_wire_id.captures(z_value_wire).unwrap();
-        let x_wire = format!("x{}", &caps[1]);
-        let y_wire = format!("y{}", &caps[1]);
-        (x_wire, y_wire)
+    fn is_input_wire(wire: &str) -> bool {
+        wire.starts_with('x') || wire.starts_with('y')
     }
 
-    fn get_expected_xy_combine_gates(&self, expected_x_wire: &str, expected_y_wire: &str) -> (&Gate, &Gate) {
-        let mut xor_gate: Option<&Gate> = None;
-        let mut and_gate: Option<&Gate> = None;
-        for (result_wire, gate) in &self.gates {
-            if (gate.left == expected_x_wire && gate.right == expected_y_wire) || (gate.left == expected_y_wire && gate.right == expected_x_wire) {
-                if gate.op == GateOp::XOR { xor_gate = Some(gate); }
-                else if gate.op == GateOp::AND { and_gate = Some(gate); }
-            }
-        }
-        let (Some(xor), Some(and)) = (xor_gate, and_gate) else { panic!("Failed to find gates"); };
-        (xor, and)
+    fn feeds_gate(&self, wire: &str, op: &GateOp) -> bool {
+        self.gates.values().any(|gate| gate.op == *op && (gate.left == wire || gate.right == wire))
     }
 
-/*
-    fn part2(&self) -> String {
-
-        let value_wire_re = Regex::new(r"^(x|y|z)\d+").unwrap();
-        let mut z_value_wires = Vec::<&str>::new();
-        let mut y_value_wires = HashSet::<&str>::new();
-        let mut x_value_wires = HashSet::<&str>::new();
-
-        for wire in &self.wires {
-            if let Some(caps) = value_wire_re.captures(wire) {
-                if &caps[1] == "x" { x_value_wires.insert(wire); }
-                else if &caps[1] == "y" { y_value_wires.insert(wire); }
-                else { assert_eq!("z", &caps[1]); z_value_wires.push(wire); }
-            }
-        }
-
-        let mut and_gates = HashSet::<&Gate>::new();
-        let mut xor_gates = HashSet::<&Gate>::new();
-        let mut or_gates  = HashSet::<&Gate>::new();
-
-        for (result, gate) in &self.gates {
-            match gate.op {
-                GateOp::AND => and_gates.insert(&gate),
-                GateOp::XOR => xor_gates.insert(&gate),
-                GateOp::OR  => or_gates.insert(&gate),
-            };
+    fn is_miswired(&self, gate: &Gate, last_z_wire: &str) -> bool {
+        let from_inputs = Self::is_input_wire(&gate.left) && Self::is_input_wire(&gate.right);
+        // x00 and y00 have no carry in, so z00 is their XOR and their AND is the first carry out
+        let from_first_inputs = from_inputs && gate.left.ends_with("00") && gate.right.ends_with("00");
+        match gate.op {
+            // every z wire is the sum bit XOR, except the last, which is the final carry OR
+            _ if gate.target.starts_with('z') && gate.target != last_z_wire && gate.op != GateOp::XOR => true,
+            GateOp::XOR if !from_inputs => !gate.target.starts_with('z'),
+            GateOp::XOR if !from_first_inputs => !self.feeds_gate(&gate.target, &GateOp::XOR),
+            GateOp::AND if !from_first_inputs => !self.feeds_gate(&gate.target, &GateOp::OR),
+            _ => false,
         }
+    }
 
-        z_value_wires.sort();
-        y_value_wires.sort();
-        x_value_wires.sort();
-
-        let bad_gate_list: Vec::<&Gate, z_value_wire>::new();
-
-        struct AdderGates<'a> {
-            z_value_wire: String,
-            x_xor_y: Option<&'a Gate>,
-            xy_xor_prev_overflow: Option<&'a Gate>,
-            overflow_x_and_y: Option<&'a Gate>,
-            overflow_prev_overflow_and_x_xor_y: Option<&'a Gate>,
-            overflow_gate: Option<&'a Gate>,
-        }
-
-        for (index, z_value_wire) in (&z_value_wires).into_iter().enumerate() {
-            let (expected_x_wire, expected_y_wire) = Self::get_expected_x_and_y_wires_from_z(z_value_wire);
-            let (expected_x_xor_y_gate, expected_overflow_calc_x_and_y_gate) = self.get_expected_xy_combine_gates(&expected_x_wire, &expected_y_wire);
-            if index == 0 {
-
-            }
-        }
-        /*
-        let overflow_wire: &str = &z_value_wires.last().unwrap();
-
-        for (index, z_value_wire) in (&z_value_wires).into_iter().rev().enumerate() {
-            if index > 0 {
-                let (expected_x_wire, expected_y_wire) = Self::get_expected_x_and_y_wires_from_z(z_value_wire);
-                // unvalidated result wires
-                let (expected_x_xor_y_gate, expected_overflow_calc_x_and_y_gate) = self.get_expected_xy_combine_gates(&expected_x_wire, &expected_y_wire);
-                let expected_overflow_gate = (&self.gates).get(overflow_wire).unwrap();             
-                let z_result_gate = (&self.gates).get(z_value_wire as &str).unwrap();
-
-                if expected_x_xor_y_gate.op != GateOp::XOR {
-                    bad_gate_list
-                }
-                if expected_overflow_calc_x_and_y_gate.op != GateOp::AND {
-
-                }
-
-                let mut xy_xor_prev_overflow: Option<&Gate> = None;  // 
-                let mut overflow_from_prev_overflow_and_x_xor_y: Option<&Gate> = None;  // wdq & ggg (vkm)
-                let mut overflow_gate: Option<&Gate> = None;         // qhs | vkm -> (z45)
-
-
-                
-                if z_result_gate.left != expected_x_xor_y_gate.target || z_result_gate.right != expected_x_xor_y_gate.target {
-                    // expected_x_xor_y_gate has wrong result wire
-                    // One of the wires should be the previous overflow wire, the other x_xor_y
-                }
+    pub fn part2(&self) -> String {
+        // Following the analysis above, a ripple carry adder gate is miswired when its output is used by the wrong
+        // kind of gate, no need to find which wires were swapped with which.
+        let Some(last_z_wire) = self.gates.keys().filter(|wire| wire.starts_with('z')).max() else { return "".to_string(); };
+        let mut miswired: Vec<&str> = self.gates.values()
+            .filter(|gate| self.is_miswired(gate, last_z_wire))
+            .map(|gate| gate.target.as_str())
+            .collect();
+        miswired.sort();
+        miswired.join(",")
+    }
+}
 
+impl FromReader for Day24 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day24::new(read_input(reader)?.lines())?)
+    }
+}
 
-                if let Some(_(left_wire, right_wire, target_wire)) = (&self.gates).get(overflow_wire) {
-                    overflow_gate = Some(&gate);
-                    match ((&self.gates).get(gate.0), (&self.gates).get(gate.1)) {
+impl Solution<'_> for Day24 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day24::new(input.lines())
+    }
 
-                    }
-                }
-            }
-            else {
+    fn part1(&self) -> Answer {
+        Day24::part1(self).into()
+    }
 
-            }
-        }
-        */
-        "".to_string()
+    fn part2(&self) -> Answer {
+        Day24::part2(self).into()
     }
-    */
 }
 
 #[cfg(test)]
@@ -351,13 +311,64 @@ tnw OR pbm -> gnj";
 
     #[test]
     fn small_sample_input_part1() {
-        let day = Day24::new(SMALL_SAMPLE_INPUT.lines());
+        let day = Day24::new(SMALL_SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(4, day.part1());
     }
 
     #[test]
     fn large_sample_input_part2() {
-        let day = Day24::new(LARGE_SAMPLE_INPUT.lines());
+        let day = Day24::new(LARGE_SAMPLE_INPUT.lines()).unwrap();
         assert_eq!(2024, day.part1());
     }
+
+    const SWAPPED_ADDER_INPUT: &str =
+"x00: 1
+x01: 0
+x02: 1
+y00: 1
+y01: 1
+y02: 0
+
+x00 XOR y00 -> z00
+x00 AND y00 -> c00
+x01 XOR y01 -> s01
+s01 XOR c00 -> c01
+x01 AND y01 -> a01
+s01 AND c00 -> b01
+a01 OR b01 -> z01
+x02 XOR y02 -> a02
+s02 XOR c01 -> z02
+x02 AND y02 -> s02
+s02 AND c01 -> b02
+a02 OR b02 -> z03";
+
+    #[test]
+    fn swapped_adder_part2_finds_miswired_outputs() {
+        let day = Day24::new(SWAPPED_ADDER_INPUT.lines()).unwrap();
+        assert_eq!("a02,c01,s02,z01", day.part2());
+    }
+
+    #[test]
+    fn working_adder_part2_finds_nothing() {
+        let fixed = SWAPPED_ADDER_INPUT
+            .replace("-> c01\n", "-> tmp\n").replace("-> z01", "-> c01").replace("-> tmp\n", "-> z01\n")
+            .replace("-> a02\n", "-> tmp\n").replace("-> s02\n", "-> a02\n").replace("-> tmp\n", "-> s02\n");
+        let day = Day24::new(fixed.lines()).unwrap();
+        assert_eq!("", day.part2());
+        assert_eq!(0b101 + 0b011, day.part1());
+    }
+
+    #[test]
+    fn spaces_around_values_and_gates_are_ignored() {
+        let day = Day24::new("x00: 1\ny00:0\n\nx00  XOR y00 -> z00".lines()).unwrap();
+        assert_eq!(1, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(3, "a blank line before the gates")), Day24::new("x00: 1\ny00: 0".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 6, "`0` or `1`", "2")), Day24::new("x00: 1\ny00: 2\n\nx00 XOR y00 -> z00".lines()).err());
+        assert_eq!(Some(ParseError::new(4, 5, "`AND`, `OR` or `XOR`", "NAND")), Day24::new("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00".lines()).err());
+        assert_eq!(Some(ParseError::new(4, 15, "a wire name", "")), Day24::new("x00: 1\ny00: 0\n\nx00 XOR y00 ->".lines()).err());
+    }
 }
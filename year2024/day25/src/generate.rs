@@ -0,0 +1,38 @@
+//! Made-up inputs for day 25: lock and key schematics.
+
+use common::synthetic::{lines, Rng, Synthetic};
+
+/// `size` schematics, about half locks and half keys, with random pin heights.
+pub fn generate(rng: &mut Rng, size: usize) -> Synthetic {
+    let schematics = (0..size.max(1)).map(|_| {
+        let is_lock = rng.chance(50);
+        let heights: Vec<usize> = (0..5).map(|_| rng.range(0, 5) as usize).collect();
+        let rows: Vec<String> = (0..7).map(|row| {
+            heights.iter().map(|height| {
+                let filled = if is_lock { row <= *height } else { 6 - row <= *height };
+                if filled { '#' } else { '.' }
+            }).collect()
+        }).collect();
+        lines(rows)
+    });
+    schematics.collect::<Vec<_>>().join("\n").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day25;
+
+    #[test]
+    fn schematics_are_locks_or_keys() {
+        let synthetic = generate(&mut Rng::new(25), 30);
+        let schematics: Vec<&str> = synthetic.input.split("\n\n").collect();
+        assert_eq!(30, schematics.len());
+        for schematic in schematics {
+            let rows: Vec<&str> = schematic.lines().collect();
+            assert_eq!(7, rows.len());
+            assert!(rows[0] == "#####" || rows[6] == "#####");
+        }
+        assert!(Day25::new(synthetic.input.lines()).unwrap().part1() > 0);
+    }
+}
@@ -0,0 +1,149 @@
+pub mod generate;
+
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use common::{read_input, Answer, FromReader, InputLines, ParseError, ReadError, Solution};
+use grid::Grid;
+
+pub struct Day25 {
+    locks: Vec<[i8; 5]>,
+    keys:  Vec<[i8; 5]>,
+}
+
+impl Day25 {
+    pub fn new(lines: std::str::Lines<'_>) -> Result<Self, ParseError> {
+        let mut locks = Vec::<[i8; 5]>::new();
+        let mut keys  = Vec::<[i8; 5]>::new();
+
+        let mut line_iter = InputLines::new(lines);
+        while line_iter.has_more() {
+            let first_line = line_iter.next_number();
+            let schematic = Grid::read(&mut line_iter, "`#` or `.`", |position, val| {
+                (position.x < 5 && (val == '#' || val == '.')).then_some(val)
+            })?;
+            if schematic.width() < 5 {
+                return Err(ParseError::new(first_line, schematic.width() + 1, "a row 5 wide", ""));
+            }
+            if schematic.height() < 7 {
+                return Err(ParseError::end_of_input(first_line + schematic.height(), "a schematic row"));
+            }
+            let row_text = |y: usize| -> String { schematic.row(y).iter().collect() };
+            if schematic.height() > 7 {
+                return Err(ParseError::new(first_line + 7, 1, "a blank line between schematics", row_text(7)));
+            }
+
+            let is_lock = match (row_text(0).as_str(), row_text(6).as_str()) {
+                ("#####", _) => true,
+                (".....", "#####") => false,
+                (".....", bottom) => { return Err(ParseError::new(first_line + 6, 1, "`#####` at the bottom of a key", bottom)); },
+                (top, _) => { return Err(ParseError::new(first_line, 1, "`#####` or `.....`", top)); }
+            };
+            let mut array: [i8; 5] = [0; 5];
+            for row in schematic.rows().skip(1).take(5) {
+                for (index, val) in row.iter().enumerate() {
+                    if *val == '#' { array[index] += 1; }
+                }
+            }
+            if is_lock { locks.push(array); } else { keys.push(array); }
+        }
+        Ok(Day25{locks, keys})
+    }
+
+    pub fn part1(&self) -> usize {
+        let mut fitting_key_lock_pairs = HashSet::<(usize, usize)>::new();
+
+        for (lock_index, lock) in self.locks.iter().enumerate() {
+            for (key_index, key) in self.keys.iter().enumerate() {
+                let mut fits = true;
+                for i in 0..5 {
+                    if lock[i] + key[i] > 5 { fits = false; }
+                }
+                if fits { fitting_key_lock_pairs.insert((key_index, lock_index)); }
+            }
+        }
+        fitting_key_lock_pairs.len()
+    }
+}
+
+impl FromReader for Day25 {
+    fn from_reader(reader: impl BufRead) -> Result<Self, ReadError> {
+        Ok(Day25::new(read_input(reader)?.lines())?)
+    }
+}
+
+impl Solution<'_> for Day25 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Day25::new(input.lines())
+    }
+
+    fn part1(&self) -> Answer {
+        Day25::part1(self).into()
+    }
+
+    fn part2(&self) -> Answer {
+        // The last day only has the one puzzle
+        Answer::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SAMPLE_LINES: &str =
+"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn sample_input_part1_counts_3() {
+        let day = Day25::new(SAMPLE_LINES.lines()).unwrap();
+        assert_eq!(3, day.part1());
+    }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        assert_eq!(Some(ParseError::end_of_input(4, "a schematic row")), Day25::new("#####\n.####\n.####".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 3, "`#` or `.`", "x")), Day25::new("#####\n.#x##\n.####\n.####\n.#.#.\n.#...\n.....".lines()).err());
+        assert_eq!(Some(ParseError::new(7, 1, "`#####` at the bottom of a key", "....#")), Day25::new(".....\n#....\n#....\n#...#\n#.#.#\n#.###\n....#".lines()).err());
+        assert_eq!(Some(ParseError::new(2, 5, "a row 5 wide", "")), Day25::new("#####\n.###\n.####\n.####\n.#.#.\n.#...\n.....".lines()).err());
+        assert_eq!(Some(ParseError::new(1, 6, "`#` or `.`", "#")), Day25::new("######\n.####\n.####\n.####\n.#.#.\n.#...\n.....".lines()).err());
+        assert_eq!(Some(ParseError::new(8, 1, "a blank line between schematics", ".....")), Day25::new("#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n.....".lines()).err());
+    }
+}
@@ -0,0 +1,158 @@
+//! The 2024 puzzles, a crate each under `dayNN/`, gathered so a runner can pick one by its day.
+
+pub use day01;
+pub use day02;
+pub use day03;
+pub use day04;
+pub use day05;
+pub use day06;
+pub use day07;
+pub use day08;
+pub use day09;
+pub use day10;
+pub use day11;
+pub use day12;
+pub use day13;
+pub use day14;
+pub use day15;
+pub use day16;
+pub use day17;
+pub use day18;
+pub use day19;
+pub use day20;
+pub use day21;
+pub use day22;
+pub use day23;
+pub use day24;
+pub use day25;
+
+/// Calls `$f::<DayNN>($args)` for the day's solution type, or gives an error for a day with no
+/// solution.
+#[macro_export]
+macro_rules! with_day {
+    ($day:expr, $f:ident($($args:expr),*)) => {
+        match $day {
+            1 => $f::<$crate::day01::Day01>($($args),*),
+            2 => $f::<$crate::day02::Day02>($($args),*),
+            3 => $f::<$crate::day03::Day03>($($args),*),
+            4 => $f::<$crate::day04::Day04>($($args),*),
+            5 => $f::<$crate::day05::Day05>($($args),*),
+            6 => $f::<$crate::day06::Day06>($($args),*),
+            7 => $f::<$crate::day07::Day07>($($args),*),
+            8 => $f::<$crate::day08::Day08>($($args),*),
+            9 => $f::<$crate::day09::Day09>($($args),*),
+            10 => $f::<$crate::day10::Day10>($($args),*),
+            11 => $f::<$crate::day11::Day11>($($args),*),
+            12 => $f::<$crate::day12::Day12>($($args),*),
+            13 => $f::<$crate::day13::Day13>($($args),*),
+            14 => $f::<$crate::day14::Day14>($($args),*),
+            15 => $f::<$crate::day15::Day15>($($args),*),
+            16 => $f::<$crate::day16::Day16>($($args),*),
+            17 => $f::<$crate::day17::Day17>($($args),*),
+            18 => $f::<$crate::day18::Day18>($($args),*),
+            19 => $f::<$crate::day19::Day19>($($args),*),
+            20 => $f::<$crate::day20::Day20>($($args),*),
+            21 => $f::<$crate::day21::Day21>($($args),*),
+            22 => $f::<$crate::day22::Day22>($($args),*),
+            23 => $f::<$crate::day23::Day23>($($args),*),
+            24 => $f::<$crate::day24::Day24>($($args),*),
+            25 => $f::<$crate::day25::Day25>($($args),*),
+            day => Err(format!("No solution for day {day}")),
+        }
+    };
+}
+
+/// The days that can draw their map, with `render::Render`.
+pub const RENDER_DAYS: [u8; 6] = [6, 14, 15, 16, 18, 20];
+
+/// The days that can show how they got their answers, with `common::Explain`.
+pub const EXPLAIN_DAYS: [u8; 7] = [1, 2, 5, 7, 13, 20, 23];
+
+/// The days that can be stepped through, with `render::Simulation`.
+pub const STEP_DAYS: [u8; 4] = [6, 14, 15, 17];
+
+/// `days` written out for an error message, like "6, 14 and 15".
+pub fn list_days(days: &[u8]) -> String {
+    let days: Vec<String> = days.iter().map(u8::to_string).collect();
+    match days.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => days.concat(),
+    }
+}
+
+/// As `with_day!`, for the days in `RENDER_DAYS`.
+#[macro_export]
+macro_rules! with_render_day {
+    ($day:expr, $f:ident($($args:expr),*)) => {
+        match $day {
+            6 => $f::<$crate::day06::Day06>($($args),*),
+            14 => $f::<$crate::day14::Day14>($($args),*),
+            15 => $f::<$crate::day15::Day15>($($args),*),
+            16 => $f::<$crate::day16::Day16>($($args),*),
+            18 => $f::<$crate::day18::Day18>($($args),*),
+            20 => $f::<$crate::day20::Day20>($($args),*),
+            day => Err(format!("No render mode for day {day}, only for days {}", $crate::list_days(&$crate::RENDER_DAYS))),
+        }
+    };
+}
+
+/// As `with_day!`, for the days in `EXPLAIN_DAYS`.
+#[macro_export]
+macro_rules! with_explain_day {
+    ($day:expr, $f:ident($($args:expr),*)) => {
+        match $day {
+            1 => $f::<$crate::day01::Day01>($($args),*),
+            2 => $f::<$crate::day02::Day02>($($args),*),
+            5 => $f::<$crate::day05::Day05>($($args),*),
+            7 => $f::<$crate::day07::Day07>($($args),*),
+            13 => $f::<$crate::day13::Day13>($($args),*),
+            20 => $f::<$crate::day20::Day20>($($args),*),
+            23 => $f::<$crate::day23::Day23>($($args),*),
+            day => Err(format!("Day {day} can't explain its answers, only days {}", $crate::list_days(&$crate::EXPLAIN_DAYS))),
+        }
+    };
+}
+
+/// As `with_day!`, for the days in `STEP_DAYS`.
+#[macro_export]
+macro_rules! with_step_day {
+    ($day:expr, $f:ident($($args:expr),*)) => {
+        match $day {
+            6 => $f::<$crate::day06::Day06>($($args),*),
+            14 => $f::<$crate::day14::Day14>($($args),*),
+            15 => $f::<$crate::day15::Day15>($($args),*),
+            17 => $f::<$crate::day17::Day17>($($args),*),
+            day => Err(format!("Day {day} can't be stepped through, only days {}", $crate::list_days(&$crate::STEP_DAYS))),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The name of the solution type a day was dispatched to.
+    fn dispatched<S>() -> Result<&'static str, String> {
+        Ok(std::any::type_name::<S>())
+    }
+
+    #[test]
+    fn day_lists_match_the_dispatch() {
+        for day in 0..=26 {
+            assert_eq!((1..=25).contains(&day), with_day!(day, dispatched()).is_ok(), "day {day}");
+            assert_eq!(RENDER_DAYS.contains(&day), with_render_day!(day, dispatched()).is_ok(), "day {day}");
+            assert_eq!(EXPLAIN_DAYS.contains(&day), with_explain_day!(day, dispatched()).is_ok(), "day {day}");
+            assert_eq!(STEP_DAYS.contains(&day), with_step_day!(day, dispatched()).is_ok(), "day {day}");
+        }
+        assert_eq!(Ok("year2024_day17::Day17"), with_step_day!(17, dispatched()));
+        assert_eq!(Err("Day 3 can't be stepped through, only days 6, 14, 15 and 17".to_string()), with_step_day!(3, dispatched()));
+    }
+
+    #[test]
+    fn lists_days_for_people() {
+        assert_eq!("", list_days(&[]));
+        assert_eq!("6", list_days(&[6]));
+        assert_eq!("6 and 14", list_days(&[6, 14]));
+        assert_eq!("1, 2 and 5", list_days(&[1, 2, 5]));
+    }
+}